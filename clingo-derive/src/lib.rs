@@ -0,0 +1,243 @@
+//! Derive macros for the [clingo](https://docs.rs/clingo) crate.
+//!
+//! `#[derive(ToSymbol)]` and `#[derive(FromSymbol)]` use the same encoding:
+//!
+//! * a unit struct or unit variant `Test2` is the constant `test_2`,
+//! * a struct `Bla { a: u32, b: String }` or tuple struct `Bla(u32, String)` is the function `bla(1,"x")`,
+//! * an enum variant is encoded like a struct named after the variant; the enum name is not part of the symbol.
+//!
+//! Field names are not part of the symbol, only their order matters.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Generics, Ident,
+};
+
+/// Derive `clingo::ToSymbol` for structs, tuple structs and enums.
+#[proc_macro_derive(ToSymbol)]
+pub fn derive_to_symbol(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        |param| parse_quote!(#param: ::clingo::ToSymbol),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let arms: Vec<TokenStream2> = match &input.data {
+        Data::Struct(data) => vec![to_symbol_arm(quote!(Self), name, &data.fields)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                to_symbol_arm(quote!(Self::#ident), ident, &variant.fields)
+            })
+            .collect(),
+        Data::Union(_) => {
+            return syn::Error::new(Span::call_site(), "ToSymbol can not be derived for unions")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let body = if arms.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(match self { #(#arms)* })
+    };
+
+    quote! {
+        impl #impl_generics ::clingo::ToSymbol for #name #ty_generics #where_clause {
+            fn symbol(&self) -> ::std::result::Result<::clingo::Symbol, ::clingo::ClingoError> {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Derive `clingo::FromSymbol` for structs, tuple structs and enums.
+///
+/// The derived implementation uses `clingo::ClingoError` as error type and reports mismatching
+/// names and arities as well as the position of arguments that could not be decoded.
+#[proc_macro_derive(FromSymbol)]
+pub fn derive_from_symbol(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        |param| parse_quote!(#param: ::clingo::FromSymbol),
+    );
+    let generics = add_trait_bounds(
+        generics,
+        |param| parse_quote!(<#param as ::clingo::FromSymbol>::Error: ::std::fmt::Display),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let predicate = predicate_name(name);
+            let arity = data.fields.len();
+            if data.fields.is_empty() {
+                quote! {
+                    symbol.expect_function(#predicate, 0)?;
+                    Ok(Self)
+                }
+            } else {
+                let value = construct(quote!(Self), &predicate, &data.fields);
+                quote! {
+                    let arguments = symbol.expect_function(#predicate, #arity)?;
+                    Ok(#value)
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let mut signatures = vec![];
+            let checks: Vec<TokenStream2> = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let predicate = predicate_name(ident);
+                    let arity = variant.fields.len();
+                    signatures.push(format!("{predicate}/{arity}"));
+                    if variant.fields.is_empty() {
+                        quote! {
+                            if symbol.match_function(#predicate, 0)?.is_some() {
+                                return Ok(Self::#ident);
+                            }
+                        }
+                    } else {
+                        let value = construct(quote!(Self::#ident), &predicate, &variant.fields);
+                        quote! {
+                            if let Some(arguments) = symbol.match_function(#predicate, #arity)? {
+                                return Ok(#value);
+                            }
+                        }
+                    }
+                })
+                .collect();
+            let expected = format!("expected one of {}", signatures.join(", "));
+            quote! {
+                #(#checks)*
+                Err(::clingo::FromSymbolError {
+                    msg: ::std::format!("{} but found `{}`", #expected, symbol),
+                }
+                .into())
+            }
+        }
+        Data::Union(_) => {
+            return syn::Error::new(
+                Span::call_site(),
+                "FromSymbol can not be derived for unions",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    quote! {
+        impl #impl_generics ::clingo::FromSymbol for #name #ty_generics #where_clause {
+            type Error = ::clingo::ClingoError;
+
+            fn from_symbol(
+                symbol: ::clingo::Symbol,
+            ) -> ::std::result::Result<Self, ::clingo::ClingoError> {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Add a bound created by `bound` for every type parameter.
+fn add_trait_bounds(
+    mut generics: Generics,
+    bound: impl Fn(&Ident) -> syn::WherePredicate,
+) -> Generics {
+    let params: Vec<Ident> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(param.ident.clone()),
+            _ => None,
+        })
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in &params {
+        where_clause.predicates.push(bound(param));
+    }
+    generics
+}
+
+/// Match arm of `ToSymbol::symbol()` turning the struct or variant `path` into a symbol.
+fn to_symbol_arm(path: TokenStream2, ident: &Ident, fields: &Fields) -> TokenStream2 {
+    let predicate = predicate_name(ident);
+    let bindings: Vec<Ident> = (0..fields.len())
+        .map(|i| format_ident!("__field{}", i))
+        .collect();
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => quote!(#path),
+    };
+    if fields.is_empty() {
+        quote!(#pattern => ::clingo::Symbol::create_id(#predicate, true),)
+    } else {
+        quote! {
+            #pattern => ::clingo::Symbol::create_function(
+                #predicate,
+                &[#(::clingo::ToSymbol::symbol(#bindings)?),*],
+                true,
+            ),
+        }
+    }
+}
+
+/// Expression building the struct or variant `path` from the decoded `arguments`.
+fn construct(path: TokenStream2, predicate: &str, fields: &Fields) -> TokenStream2 {
+    let values: Vec<TokenStream2> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let ty = &field.ty;
+            quote!(::clingo::from_symbol_argument::<#ty>(&arguments, #i, #predicate)?)
+        })
+        .collect();
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// Convert a type or variant name into a predicate name, e.g. `HTTPServer2` becomes `http_server_2`.
+fn predicate_name(ident: &Ident) -> String {
+    let ident = ident.to_string();
+    let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+    let chars: Vec<char> = ident.chars().collect();
+    let mut name = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            let boundary = (c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lowercase)))
+                || (c.is_ascii_digit() && prev.is_alphabetic());
+            if boundary {
+                name.push('_');
+            }
+        }
+        name.extend(c.to_lowercase());
+    }
+    name
+}
@@ -31,7 +31,7 @@ impl<'a, 'b> ast::StatementHandler for OnStatementData<'a, 'b> {
                 extended_body.push(lit.into());
 
                 // initialize the rule
-                let head = stm.head();
+                let head = stm.head().unwrap();
                 let rule = ast::rule(&loc, head, &extended_body).unwrap();
 
                 // add the rewritten rule to the program builder
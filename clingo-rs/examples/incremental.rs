@@ -0,0 +1,95 @@
+use clingo::*;
+use std::env;
+
+// a counter that has to reach the value 3, each step may increment it by one
+const PROGRAM: &str = "
+#program base.
+value(0,0).
+
+#program step(t).
+{ inc(t) }.
+value(t,V+1) :- value(t-1,V), inc(t).
+value(t,V) :- value(t-1,V), not inc(t).
+
+#program check(t).
+#external query(t).
+:- query(t), not value(t,3).
+";
+
+fn query_literal(ctl: &Control, step: i32) -> SolverLiteral {
+    let query = Symbol::create_function("query", &[Symbol::create_number(step)], true).unwrap();
+    let atoms = ctl
+        .symbolic_atoms()
+        .expect("Failed to retrieve symbolic atoms.");
+    let mut atoms_it = atoms
+        .iter()
+        .expect("Failed to iterate over symbolic atoms.");
+    let item = atoms_it
+        .find(|atom| atom.symbol().unwrap() == query)
+        .expect("Failed to find the query atom.");
+    item.literal().unwrap()
+}
+
+fn solve(ctl: &mut Control) -> bool {
+    // the solve handle borrows the control object
+    let mut handle = ctl
+        .solve_mut(SolveMode::YIELD, &[])
+        .expect("Failed retrieving solve handle.");
+
+    // print the first model
+    handle.resume().expect("Failed resume on solve handle.");
+    if let Some(model) = handle.model().expect("Failed to retrieve model.") {
+        let atoms = model
+            .symbols(ShowType::SHOWN)
+            .expect("Failed to retrieve symbols in the model.");
+        print!("Model:");
+        for symbol in atoms {
+            print!(" {}", symbol);
+        }
+        println!();
+    }
+
+    let result = handle
+        .get()
+        .expect("Failed to get result from solve handle.");
+    // closing the handle makes the control object available again
+    handle.close().expect("Failed to close solve handle.");
+    result.contains(SolveResult::SATISFIABLE)
+}
+
+fn main() {
+    // collect clingo options from the command line
+    let options = env::args().skip(1).collect();
+
+    let mut ctl = control(options).expect("Failed creating Control.");
+    ctl.add("base", &[], PROGRAM)
+        .expect("Failed to add a logic program.");
+
+    let mut parts = vec![Part::new("base", vec![]).unwrap()];
+    let mut step = 0;
+    loop {
+        if step > 0 {
+            let t = Symbol::create_number(step);
+            parts.push(Part::new("step", vec![t]).unwrap());
+        }
+        parts.push(Part::new("check", vec![Symbol::create_number(step)]).unwrap());
+        ctl.ground(&parts)
+            .expect("Failed to ground a logic program.");
+        parts.clear();
+
+        // only the query of the current step is enforced
+        let query = query_literal(&ctl, step);
+        ctl.assign_external(query, TruthValue::True)
+            .expect("Failed to assign the query.");
+
+        println!("Solving step {}...", step);
+        if solve(&mut ctl) {
+            break;
+        }
+
+        // the query of this step is no longer needed
+        ctl.release_external(query)
+            .expect("Failed to release the query.");
+        step += 1;
+    }
+}
@@ -2,36 +2,46 @@ use clingo::*;
 use rand::distributions::Distribution;
 use rand::distributions::Uniform;
 use std::env;
-use std::rc::Rc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::Duration;
 
-struct MySEHandler {
-    atom: Rc<AtomicBool>,
-}
-impl SolveEventHandler for MySEHandler {
-    fn on_solve_event(&mut self, event: SolveEvent, _goon: &mut bool) -> bool {
-        if let SolveEvent::Finish(_result) = event {
-            self.atom.store(false, Ordering::SeqCst);
+// minimal executor that parks the current thread until the future is woken
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(thread::Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
         }
-        true
     }
 }
 
 fn main() {
-    // collect clingo options from the command line
-    let options = env::args().skip(1).collect();
+    // enumerate all models, further clingo options are taken from the command line
+    let options = std::iter::once("0".to_string())
+        .chain(env::args().skip(1))
+        .collect();
 
     // create a control object and pass command line arguments
     let mut ctl = control(options).expect("Failed creating Control.");
 
-    // add a logic program to the base part
+    // add a logic program with 2^17 models to the base part
     ctl.add(
         "base",
         &[],
         "#const n = 17.\
-         1 { p(X); q(X) } 1 :- X = 1..n.\
-         :- not n+1 { p(1..n); \
-         q(1..n) }.",
+         1 { p(X); q(X) } 1 :- X = 1..n.",
     )
     .expect("Failed to add a logic program.");
 
@@ -41,39 +51,60 @@ fn main() {
     ctl.ground(&parts)
         .expect("Failed to ground a logic program.");
 
-    let running = Rc::new(AtomicBool::new(true));
-
-    // create a solve handle with an attached event handler
+    // solve in the background, the models are requested one by one
     let mut handle = ctl
-        .solve_with_event_handler(
-            SolveMode::ASYNC | SolveMode::YIELD,
-            &[],
-            MySEHandler {
-                atom: running.clone(),
-            },
-        )
+        .solve_async(&[])
         .expect("Failed to retrieve solve handle.");
 
-    // let's approximate pi
+    // cancel the search after a second from another thread
+    let interrupter = handle.interrupter();
+    let timer = thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        interrupter.interrupt();
+    });
+
+    // let's approximate pi while waiting for the models
     let mut samples = 0.;
     let mut in_circle = 0.;
     let mut rng = rand::thread_rng();
     let between = Uniform::new_inclusive(-1f64, 1.);
-    while running.load(Ordering::SeqCst) {
-        samples += 1.;
-        let x = between.sample(&mut rng);
-        let y = between.sample(&mut rng);
-        if x * x + y * y <= 1. {
-            in_circle += 1.;
+    let models = block_on(async {
+        let mut models = 0;
+        // the future resolves to `None` once the search is exhausted or interrupted
+        while let Some(model) = handle.next_model().await? {
+            models += 1;
+            if models <= 3 {
+                let symbols: Vec<String> = model.symbols.iter().map(|s| s.to_string()).collect();
+                println!("Model {}: {}", models, symbols.join(" "));
+            }
+            // the search is paused until the next model is requested
+            for _ in 0..1000 {
+                samples += 1.;
+                let x = between.sample(&mut rng);
+                let y = between.sample(&mut rng);
+                if x * x + y * y <= 1. {
+                    in_circle += 1.;
+                }
+            }
         }
-    }
+        Ok::<_, ClingoError>(models)
+    })
+    .expect("Failed to get the next model.");
     println!("pi = {}", 4. * in_circle / samples);
 
     // get the solve result
-    handle
-        .get()
-        .expect("Failed to get result from solve handle.");
+    let result = handle.result().expect("The search has not finished.");
+    println!(
+        "{} models, {}",
+        models,
+        if result.contains(SolveResult::INTERRUPTED) {
+            "interrupted"
+        } else {
+            "exhausted"
+        }
+    );
 
     // close the handle
     handle.close().expect("Failed to close solve handle.");
+    timer.join().unwrap();
 }
@@ -1,68 +1,6 @@
 use clingo::*;
 use std::env;
 
-fn print_prefix(depth: u8) {
-    println!();
-    for _ in 0..depth {
-        print!("  ");
-    }
-}
-
-// recursively print the statistics object
-fn print_statistics(stats: &Statistics, key: u64, depth: u8) {
-    // get the type of an entry and switch over its various values
-    let statistics_type = stats.statistics_type(key).unwrap();
-    match statistics_type {
-        // print values
-        StatisticsType::Value => {
-            let value = stats
-                .value_get(key)
-                .expect("Failed to retrieve statistics value.");
-            print!(" {}", value);
-        }
-
-        // print arrays
-        StatisticsType::Array => {
-            // loop over array elements
-            let size = stats
-                .array_size(key)
-                .expect("Failed to retrieve statistics array size.");
-            for i in 0..size {
-                // print array offset (with prefix for readability)
-                let subkey = stats
-                    .array_at(key, i)
-                    .expect("Failed to retrieve statistics array.");
-                print_prefix(depth);
-                print!("{} zu:", i);
-
-                // recursively print subentry
-                print_statistics(stats, subkey, depth + 1);
-            }
-        }
-
-        // print maps
-        StatisticsType::Map => {
-            // loop over map elements
-            let size = stats.map_size(key).unwrap();
-            for i in 0..size {
-                // get and print map name (with prefix for readability)
-                let name = stats.map_subkey_name(key, i).unwrap();
-                let subkey = stats.map_at(key, name).unwrap();
-                print_prefix(depth);
-                print!("{}:", name);
-
-                // recursively print subentry
-                print_statistics(stats, subkey, depth + 1);
-            }
-        }
-
-        // this case won't occur if the statistics are traversed like this
-        StatisticsType::Empty => {
-            println!("StatisticsType::Empty");
-        }
-    }
-}
-
 fn print_model(model: &Model) {
     // retrieve the symbols in the model
     let atoms = model
@@ -131,8 +69,25 @@ fn main() {
     // solve
     let ctl = solve(ctl);
 
-    // get the statistics object, get the root key, then print the statistics recursively
-    let stats = ctl.statistics().unwrap();
-    let stats_key = stats.root().unwrap();
-    print_statistics(stats, stats_key, 0);
+    // get an owned copy of the statistics tree
+    let stats = ctl
+        .statistics()
+        .unwrap()
+        .snapshot()
+        .expect("Failed to retrieve statistics.");
+
+    // look up single values by their path
+    for path in [
+        "summary.times.total",
+        "summary.models.enumerated",
+        "solving.solvers.choices",
+        "solving.solvers.conflicts",
+    ] {
+        if let Some(value) = stats.value(path) {
+            println!("{}: {}", path, value);
+        }
+    }
+
+    // print the whole tree
+    println!("{}", stats.to_json());
 }
@@ -0,0 +1,489 @@
+//! Reading and writing ground programs in the [aspif] text format.
+//!
+//! [`AspifWriter`] is a [`GroundProgramObserver`] that writes the ground program passed to the solver,
+//! e.g., to cache it on disk or to compare the groundings of different runs.
+//! [`read_aspif()`] adds such a program to another control object via its [`Backend`].
+//!
+//! ```ignore
+//! // ground with a control object whose context returns an AspifWriter as observer
+//! let mut ctl = control_with_context(vec![], ctx)?;
+//! ctl.add("base", &[], "a :- not b. b :- not a.")?;
+//! ctl.ground(&[Part::new("base", vec![])?])?;
+//!
+//! // and read the written program back
+//! let mut ctl = control(vec![])?;
+//! read_aspif(&program, &mut ctl.backend()?)?;
+//! ```
+//!
+//! [aspif]: https://potassco.org/clingo/c-api/current/
+use crate::{
+    set_internal_error, Atom, Backend, ClingoError, ErrorType, ExternalType, GroundProgramObserver,
+    HeuristicType, Id, SolverLiteral, Symbol, WeightedLiteral,
+};
+use clingo_sys::clingo_weighted_literal;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use thiserror::Error;
+
+/// Error returned by [`read_aspif()`] for malformed or unsupported input.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("AspifError: line {line}: {msg}")]
+pub struct AspifError {
+    /// the line of the statement starting with 1
+    pub line: usize,
+    pub msg: String,
+}
+
+/// Observer writing the ground program in aspif format.
+///
+/// A step ends with a `0` line when solving starts, then the underlying writer is flushed.
+/// If writing fails, grounding is stopped and the error can be retrieved with
+/// [`AspifWriter::take_error()`].
+///
+/// **Note:** Shown csp variables are not part of the aspif format and are skipped.
+#[derive(Debug)]
+pub struct AspifWriter<W: Write> {
+    out: W,
+    error: Option<io::Error>,
+}
+impl<W: Write> AspifWriter<W> {
+    /// Create a writer emitting the ground program to `out`.
+    pub fn new(out: W) -> AspifWriter<W> {
+        AspifWriter { out, error: None }
+    }
+    /// Get a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.out
+    }
+    /// Get the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+    /// Take the error that stopped grounding, if any.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+    fn write_line(&mut self, line: &str) -> bool {
+        let result = writeln!(self.out, "{}", line);
+        self.finish(result)
+    }
+    fn finish(&mut self, result: io::Result<()>) -> bool {
+        match result {
+            Ok(()) => true,
+            Err(error) => {
+                self.error = Some(error);
+                set_internal_error(ErrorType::Runtime, "AspifWriter failed to write.");
+                false
+            }
+        }
+    }
+}
+
+// append the size of the slice followed by its elements
+fn push_all<T>(line: &mut String, items: &[T], value: impl Fn(&T) -> i64) {
+    let _ = write!(line, " {}", items.len());
+    for item in items {
+        let _ = write!(line, " {}", value(item));
+    }
+}
+fn push_weighted(line: &mut String, literals: &[WeightedLiteral]) {
+    let _ = write!(line, " {}", literals.len());
+    for literal in literals {
+        let _ = write!(line, " {} {}", literal.literal().0, literal.weight());
+    }
+}
+fn atom(atom: &Atom) -> i64 {
+    atom.0 as i64
+}
+fn literal(literal: &SolverLiteral) -> i64 {
+    literal.0 as i64
+}
+fn id(id: &Id) -> i64 {
+    id.0 as i64
+}
+fn rule_head(choice: bool, head: &[Atom]) -> String {
+    let mut line = format!("1 {}", choice as u8);
+    push_all(&mut line, head, atom);
+    line
+}
+fn output(symbol: &str, condition: &[SolverLiteral]) -> String {
+    let mut line = format!("4 {} {}", symbol.len(), symbol);
+    push_all(&mut line, condition, literal);
+    line
+}
+
+impl<W: Write> GroundProgramObserver for AspifWriter<W> {
+    fn init_program(&mut self, incremental: bool) -> bool {
+        if incremental {
+            self.write_line("asp 1 0 0 incremental")
+        } else {
+            self.write_line("asp 1 0 0")
+        }
+    }
+    fn end_step(&mut self) -> bool {
+        let result = writeln!(self.out, "0").and_then(|_| self.out.flush());
+        self.finish(result)
+    }
+    fn rule(&mut self, choice: bool, head: &[Atom], body: &[SolverLiteral]) -> bool {
+        let mut line = rule_head(choice, head);
+        line.push_str(" 0");
+        push_all(&mut line, body, literal);
+        self.write_line(&line)
+    }
+    fn weight_rule(
+        &mut self,
+        choice: bool,
+        head: &[Atom],
+        lower_bound: i32,
+        body: &[WeightedLiteral],
+    ) -> bool {
+        let mut line = rule_head(choice, head);
+        let _ = write!(line, " 1 {}", lower_bound);
+        push_weighted(&mut line, body);
+        self.write_line(&line)
+    }
+    fn minimize(&mut self, priority: i32, literals: &[WeightedLiteral]) -> bool {
+        let mut line = format!("2 {}", priority);
+        push_weighted(&mut line, literals);
+        self.write_line(&line)
+    }
+    fn project(&mut self, atoms: &[Atom]) -> bool {
+        let mut line = String::from("3");
+        push_all(&mut line, atoms, atom);
+        self.write_line(&line)
+    }
+    fn output_atom(&mut self, symbol: Symbol, atom: Atom) -> bool {
+        let condition = if atom.0 == 0 {
+            vec![]
+        } else {
+            vec![SolverLiteral(atom.0 as i32)]
+        };
+        self.write_line(&output(&symbol.to_string(), &condition))
+    }
+    fn output_term(&mut self, symbol: Symbol, condition: &[SolverLiteral]) -> bool {
+        self.write_line(&output(&symbol.to_string(), condition))
+    }
+    fn external(&mut self, atom: Atom, type_: ExternalType) -> bool {
+        self.write_line(&format!("5 {} {}", atom.0, type_ as u32))
+    }
+    fn assume(&mut self, literals: &[SolverLiteral]) -> bool {
+        let mut line = String::from("6");
+        push_all(&mut line, literals, literal);
+        self.write_line(&line)
+    }
+    fn heuristic(
+        &mut self,
+        atom: Atom,
+        type_: HeuristicType,
+        bias: i32,
+        priority: u32,
+        condition: &[SolverLiteral],
+    ) -> bool {
+        let mut line = format!("7 {} {} {} {}", type_ as u32, atom.0, bias, priority);
+        push_all(&mut line, condition, literal);
+        self.write_line(&line)
+    }
+    fn acyc_edge(&mut self, node_u: i32, node_v: i32, condition: &[SolverLiteral]) -> bool {
+        let mut line = format!("8 {} {}", node_u, node_v);
+        push_all(&mut line, condition, literal);
+        self.write_line(&line)
+    }
+    fn theory_term_number(&mut self, term_id: Id, number: i32) -> bool {
+        self.write_line(&format!("9 0 {} {}", term_id.0, number))
+    }
+    fn theory_term_string(&mut self, term_id: Id, name: &str) -> bool {
+        self.write_line(&format!("9 1 {} {} {}", term_id.0, name.len(), name))
+    }
+    fn theory_term_compound(
+        &mut self,
+        term_id: Id,
+        name_id_or_type: i32,
+        arguments: &[Id],
+    ) -> bool {
+        let mut line = format!("9 2 {} {}", term_id.0, name_id_or_type);
+        push_all(&mut line, arguments, id);
+        self.write_line(&line)
+    }
+    fn theory_element(
+        &mut self,
+        element_id: Id,
+        terms: &[Id],
+        condition: &[SolverLiteral],
+    ) -> bool {
+        let mut line = format!("9 4 {}", element_id.0);
+        push_all(&mut line, terms, id);
+        push_all(&mut line, condition, literal);
+        self.write_line(&line)
+    }
+    fn theory_atom(&mut self, atom_id_or_zero: Id, term_id: Id, elements: &[Id]) -> bool {
+        let mut line = format!("9 5 {} {}", atom_id_or_zero.0, term_id.0);
+        push_all(&mut line, elements, id);
+        self.write_line(&line)
+    }
+    fn theory_atom_with_guard(
+        &mut self,
+        atom_id_or_zero: Id,
+        term_id: Id,
+        elements: &[Id],
+        operator_id: Id,
+        right_hand_side_id: Id,
+    ) -> bool {
+        let mut line = format!("9 6 {} {}", atom_id_or_zero.0, term_id.0);
+        push_all(&mut line, elements, id);
+        let _ = write!(line, " {} {}", operator_id.0, right_hand_side_id.0);
+        self.write_line(&line)
+    }
+}
+
+// Cursor over the space separated fields of a statement.
+#[derive(Clone, Copy)]
+struct Statement<'a> {
+    line: usize,
+    rest: &'a str,
+}
+impl<'a> Statement<'a> {
+    fn error(&self, msg: impl Into<String>) -> ClingoError {
+        AspifError {
+            line: self.line,
+            msg: msg.into(),
+        }
+        .into()
+    }
+    fn field(&mut self) -> Result<&'a str, ClingoError> {
+        let rest = self.rest.trim_start_matches(' ');
+        let end = rest.find(' ').unwrap_or(rest.len());
+        if end == 0 {
+            return Err(self.error("unexpected end of statement"));
+        }
+        self.rest = &rest[end..];
+        Ok(&rest[..end])
+    }
+    fn number<T: std::str::FromStr>(&mut self) -> Result<T, ClingoError> {
+        let field = self.field()?;
+        field
+            .parse()
+            .map_err(|_| self.error(format!("expected a number but found `{}`", field)))
+    }
+    // a string of the given length in bytes preceded by a single space
+    fn string(&mut self, len: usize) -> Result<&'a str, ClingoError> {
+        match self.rest.get(1..len + 1) {
+            Some(string) if self.rest.starts_with(' ') => {
+                self.rest = &self.rest[len + 1..];
+                Ok(string)
+            }
+            _ => Err(self.error("string is shorter than its length")),
+        }
+    }
+    fn numbers<T: std::str::FromStr>(&mut self) -> Result<Vec<T>, ClingoError> {
+        let size: usize = self.number()?;
+        (0..size).map(|_| self.number()).collect()
+    }
+    fn weighted(&mut self) -> Result<Vec<(i32, i32)>, ClingoError> {
+        let size: usize = self.number()?;
+        (0..size)
+            .map(|_| Ok((self.number()?, self.number()?)))
+            .collect()
+    }
+    fn end(&self) -> Result<(), ClingoError> {
+        if self.rest.trim().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected `{}`", self.rest.trim())))
+        }
+    }
+}
+
+// Atoms of the aspif program and the corresponding atoms of the backend.
+struct Atoms<'b, 'a> {
+    backend: &'b mut Backend<'a>,
+    atoms: HashMap<u32, Atom>,
+    symbols: HashMap<u32, Symbol>,
+}
+impl Atoms<'_, '_> {
+    fn atom(&mut self, atom: u32) -> Result<Atom, ClingoError> {
+        if let Some(atom) = self.atoms.get(&atom) {
+            return Ok(*atom);
+        }
+        let new = self.backend.add_atom(self.symbols.get(&atom).copied())?;
+        self.atoms.insert(atom, new);
+        Ok(new)
+    }
+    fn atoms(&mut self, atoms: &[u32]) -> Result<Vec<Atom>, ClingoError> {
+        atoms.iter().map(|atom| self.atom(*atom)).collect()
+    }
+    fn literal(&mut self, literal: i32) -> Result<SolverLiteral, ClingoError> {
+        let atom = self.atom(literal.unsigned_abs())?;
+        Ok(SolverLiteral(atom.0 as i32 * literal.signum()))
+    }
+    fn literals(&mut self, literals: &[i32]) -> Result<Vec<SolverLiteral>, ClingoError> {
+        literals
+            .iter()
+            .map(|literal| self.literal(*literal))
+            .collect()
+    }
+    fn weighted(&mut self, literals: &[(i32, i32)]) -> Result<Vec<WeightedLiteral>, ClingoError> {
+        literals
+            .iter()
+            .map(|(literal, weight)| {
+                Ok(WeightedLiteral(clingo_weighted_literal {
+                    literal: self.literal(*literal)?.0,
+                    weight: *weight,
+                }))
+            })
+            .collect()
+    }
+}
+
+/// Add a ground program in aspif format to the backend.
+///
+/// The atoms of the program are replaced by fresh atoms of the backend.
+/// Atoms with an output statement are associated with their symbol.
+/// Other output statements, i.e., facts and terms shown under a condition,
+/// are added as rules deriving the symbol as an atom.
+/// The statements of all steps are added at once.
+///
+/// **Note:** Theory statements are not supported by the [`Backend`].
+///
+/// # Errors
+///
+/// - [`ClingoError::AspifError`] if the program is malformed or contains theory statements
+/// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`](crate::ErrorCode::BadAlloc)
+///   or [`ErrorCode::Runtime`](crate::ErrorCode::Runtime) if a statement could not be added
+pub fn read_aspif(program: &str, backend: &mut Backend) -> Result<(), ClingoError> {
+    let mut lines = program
+        .lines()
+        .enumerate()
+        .map(|(i, rest)| Statement { line: i + 1, rest });
+    let mut header = match lines.next() {
+        Some(header) => header,
+        None => {
+            return Err(AspifError {
+                line: 1,
+                msg: "missing header".to_string(),
+            }
+            .into())
+        }
+    };
+    if header.field()? != "asp" || header.field()? != "1" {
+        return Err(header.error("expected header `asp 1 m p [tags]`"));
+    }
+    let statements: Vec<Statement> = lines.filter(|line| !line.rest.trim().is_empty()).collect();
+
+    // output statements whose condition is a single atom associate the atom with the symbol,
+    // unless the symbol is shown more than once
+    let mut outputs = vec![];
+    for statement in &statements {
+        let mut statement = *statement;
+        if statement.field()? == "4" {
+            let len = statement.number()?;
+            let symbol = crate::parse_term(statement.string(len)?).map_err(|_| {
+                statement.error("could not parse the symbol of an output statement")
+            })?;
+            let condition: Vec<i32> = statement.numbers()?;
+            statement.end()?;
+            outputs.push((symbol, condition));
+        }
+    }
+    let mut shown = HashMap::new();
+    for (symbol, _) in &outputs {
+        *shown.entry(*symbol).or_insert(0) += 1;
+    }
+    let mut atoms = Atoms {
+        backend,
+        atoms: HashMap::new(),
+        symbols: HashMap::new(),
+    };
+    let mut derived = vec![];
+    for (symbol, condition) in outputs {
+        match condition[..] {
+            [atom]
+                if atom > 0
+                    && shown[&symbol] == 1
+                    && !atoms.symbols.contains_key(&(atom as u32)) =>
+            {
+                atoms.symbols.insert(atom as u32, symbol);
+            }
+            _ => derived.push((symbol, condition)),
+        }
+    }
+    for (symbol, condition) in derived {
+        let head = atoms.backend.add_atom(Some(symbol))?;
+        let body = atoms.literals(&condition)?;
+        atoms.backend.rule(false, &[head], &body)?;
+    }
+
+    for mut statement in statements {
+        match statement.field()? {
+            "0" | "4" | "10" => continue,
+            "1" => {
+                let choice = statement.number::<u8>()? == 1;
+                let head: Vec<u32> = statement.numbers()?;
+                let head = atoms.atoms(&head)?;
+                match statement.number::<u8>()? {
+                    0 => {
+                        let body: Vec<i32> = statement.numbers()?;
+                        let body = atoms.literals(&body)?;
+                        atoms.backend.rule(choice, &head, &body)?;
+                    }
+                    1 => {
+                        let lower_bound = statement.number()?;
+                        let body = statement.weighted()?;
+                        let body = atoms.weighted(&body)?;
+                        atoms
+                            .backend
+                            .weight_rule(choice, &head, lower_bound, &body)?;
+                    }
+                    _ => return Err(statement.error("unknown body type")),
+                }
+            }
+            "2" => {
+                let priority = statement.number()?;
+                let literals = statement.weighted()?;
+                let literals = atoms.weighted(&literals)?;
+                atoms.backend.minimize(priority, &literals)?;
+            }
+            "3" => {
+                let projected: Vec<u32> = statement.numbers()?;
+                let projected = atoms.atoms(&projected)?;
+                atoms.backend.project(&projected)?;
+            }
+            "5" => {
+                let atom = statement.number()?;
+                let atom = atoms.atom(atom)?;
+                let type_ = ExternalType::try_from(statement.number()?)
+                    .map_err(|_| statement.error("unknown external type"))?;
+                atoms.backend.external(atom, type_)?;
+            }
+            "6" => {
+                let literals: Vec<i32> = statement.numbers()?;
+                let literals = atoms.literals(&literals)?;
+                atoms.backend.assume(&literals)?;
+            }
+            "7" => {
+                let type_ = HeuristicType::try_from(statement.number()?)
+                    .map_err(|_| statement.error("unknown heuristic type"))?;
+                let atom = statement.number()?;
+                let atom = atoms.atom(atom)?;
+                let bias = statement.number()?;
+                let priority = statement.number()?;
+                let condition: Vec<i32> = statement.numbers()?;
+                let condition = atoms.literals(&condition)?;
+                atoms
+                    .backend
+                    .heuristic(atom, type_, bias, priority, &condition)?;
+            }
+            "8" => {
+                let node_u = statement.number()?;
+                let node_v = statement.number()?;
+                let condition: Vec<i32> = statement.numbers()?;
+                let condition = atoms.literals(&condition)?;
+                atoms.backend.acyc_edge(node_u, node_v, &condition)?;
+            }
+            "9" => return Err(statement.error("theory statements are not supported")),
+            other => return Err(statement.error(format!("unknown statement type `{}`", other))),
+        }
+        statement.end()?;
+    }
+    Ok(())
+}
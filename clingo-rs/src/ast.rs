@@ -1,13 +1,13 @@
 use crate::{
-    internalize_string, set_internal_error, ClingoError, ControlCtx, ErrorType, ExternalType,
-    GenericControl, Symbol,
+    internalize_string, set_internal_error, unsafe_logging_callback, ClingoError, ControlCtx,
+    ErrorType, ExternalType, GenericControl, Logger, LoggingCallback, Symbol,
 };
 
 use crate::ast_internals::Body;
-use crate::ast_internals::{ASTType, AST};
+use crate::ast_internals::{ASTAttribute, ASTAttributeType, ASTType, AST};
 use clingo_sys::*;
+use std::convert::TryFrom;
 use std::ffi::CStr;
-use std::ffi::NulError;
 use std::str::Utf8Error;
 use std::{
     ffi::CString,
@@ -27,14 +27,14 @@ pub struct Location(pub(crate) clingo_location);
 impl Default for Location {
     /// Create a default location.
     fn default() -> Self {
-        let file = CString::new("").unwrap();
+        let file = c"".as_ptr();
         Location(clingo_location {
             begin_line: 0,
             end_line: 0,
             begin_column: 0,
             end_column: 0,
-            begin_file: file.as_ptr(),
-            end_file: file.as_ptr(),
+            begin_file: file,
+            end_file: file,
         })
     }
 }
@@ -53,6 +53,7 @@ impl Location {
     /// # Errors
     ///
     /// - [`ClingoError::NulError`](enum.ClingoError.html#variant.NulError) - if `begin_file` `end_file` or contain a nul byte
+    /// - [`ClingoError::InternalError`](enum.ClingoError.html#variant.InternalError) - if the file names could not be internalized
     pub fn new(
         begin_file: &str,
         end_file: &str,
@@ -60,16 +61,16 @@ impl Location {
         end_line: usize,
         begin_column: usize,
         end_column: usize,
-    ) -> Result<Location, NulError> {
-        let begin_file = CString::new(begin_file)?;
-        let end_file = CString::new(end_file)?;
+    ) -> Result<Location, ClingoError> {
+        let begin_file = internalize_string(begin_file)?;
+        let end_file = internalize_string(end_file)?;
         let loc = clingo_location {
             begin_line,
             end_line,
             begin_column,
             end_column,
-            begin_file: begin_file.as_ptr(),
-            end_file: end_file.as_ptr(),
+            begin_file,
+            end_file,
         };
         Ok(Location(loc))
     }
@@ -231,9 +232,56 @@ pub fn parse_string_with_statement_handler<
     }
     Ok(())
 }
+/// Parse the given program and pass each statement to the handler like
+/// [`parse_string_with_statement_handler()`], reporting messages such as syntax errors to the
+/// logger instead of printing them.
+///
+/// Messages have the form `<string>:line:column-column: error: message`.
+///
+/// # Arguments
+///
+/// * `program` - the program in gringo syntax
+/// * `handler` - implementing the trait [`StatementHandler`]
+/// * `logger` - logger to report messages during parsing
+/// * `message_limit` - maximum number of times to call the logger
+///
+/// # Errors
+///
+/// - [`ClingoError::NulError`] - if `program` contains a nul byte
+/// - [`ClingoError::InternalError`] with [`ErrorCode::Runtime`](crate::ErrorCode::Runtime) for
+///   statements of invalid form or [`ErrorCode::BadAlloc`](crate::ErrorCode::BadAlloc)
+pub fn parse_string_with_logger<T: StatementHandler, L: Logger>(
+    program: &str,
+    handler: &mut T,
+    logger: &mut L,
+    message_limit: u32,
+) -> Result<(), ClingoError> {
+    let program = CString::new(program)?;
+    let handler = handler as *mut T;
+    let logger = logger as *mut L;
+    if !unsafe {
+        clingo_ast_parse_string(
+            program.as_ptr(),
+            Some(unsafe_ast_callback::<T> as ASTCallback),
+            handler as *mut c_void,
+            std::ptr::null_mut(),
+            Some(unsafe_logging_callback::<L> as LoggingCallback),
+            logger as *mut c_void,
+            message_limit,
+        )
+    } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_ast_parse_string() failed",
+        ));
+    }
+    Ok(())
+}
 pub trait StatementHandler {
     /// Callback function called on an ast statement while traversing the ast.
     ///
+    /// If the callback fails, parsing is aborted and
+    /// [`parse_string_with_statement_handler()`](fn.parse_string_with_statement_handler.html) returns an error.
+    ///
     /// **Returns** whether the call was successful
     fn on_statement(&mut self, ast: &Statement) -> bool;
 }
@@ -242,41 +290,79 @@ unsafe extern "C" fn unsafe_ast_callback<T: StatementHandler>(
     event_handler: *mut c_void,
 ) -> bool {
     // check for null pointers
-    if ast.is_null() | event_handler.is_null() {
+    let ptr = match NonNull::new(ast) {
+        Some(ptr) if !event_handler.is_null() => ptr,
+        _ => {
+            set_internal_error(
+                ErrorType::Runtime,
+                "unsafe_ast_callback() got a null pointer.",
+            );
+            return false;
+        }
+    };
+
+    let event_handler = &mut *(event_handler as *mut T);
+
+    let ast = AST {
+        ptr,
+        _lifetime: PhantomData,
+    };
+    ast.acquire();
+    match ast.get_type() {
+        Ok(ASTType::Rule)
+        | Ok(ASTType::Definition)
+        | Ok(ASTType::ShowSignature)
+        | Ok(ASTType::Defined)
+        | Ok(ASTType::ShowTerm)
+        | Ok(ASTType::Minimize)
+        | Ok(ASTType::Script)
+        | Ok(ASTType::Program)
+        | Ok(ASTType::External)
+        | Ok(ASTType::Edge)
+        | Ok(ASTType::Heuristic)
+        | Ok(ASTType::ProjectAtom)
+        | Ok(ASTType::ProjectSignature)
+        | Ok(ASTType::TheoryDefinition)
+        | Ok(ASTType::Comment) => (),
+        _ => {
+            set_internal_error(
+                ErrorType::Runtime,
+                "unsafe_ast_callback() got an AST that is not a statement.",
+            );
+            return false;
+        }
+    }
+    let stm = Statement { ast };
+    if !event_handler.on_statement(&stm) {
         set_internal_error(
             ErrorType::Runtime,
-            "unsafe_ast_callback() got a null pointer.",
+            "StatementHandler::on_statement() failed.",
         );
         return false;
     }
+    true
+}
 
-    let event_handler = &mut *(event_handler as *mut T);
-
-    let ast = match NonNull::new(ast) {
-        Some(x) => AST {
-            ptr: x,
-            _lifetime: PhantomData,
-        },
-        None => panic!("NonNull::new(ast) returned None"),
-    };
-    ast.acquire();
-    let stm = match ast.get_type() {
-        Ok(ASTType::Rule) => Statement { ast },
-        Ok(ASTType::Definition) => Statement { ast },
-        Ok(ASTType::ShowSignature) => Statement { ast },
-        Ok(ASTType::Defined) => Statement { ast },
-        Ok(ASTType::ShowTerm) => Statement { ast },
-        Ok(ASTType::Minimize) => Statement { ast },
-        Ok(ASTType::Script) => Statement { ast },
-        Ok(ASTType::Program) => Statement { ast },
-        Ok(ASTType::External) => Statement { ast },
-        Ok(ASTType::Edge) => Statement { ast },
-        Ok(ASTType::Heuristic) => Statement { ast },
-        Ok(ASTType::ProjectAtom) => Statement { ast },
-        Ok(ASTType::ProjectSignature) => Statement { ast },
-        x => panic!("unexpected ASTType: {:?}", x),
-    };
-    event_handler.on_statement(&stm)
+/// Unpool the given statement.
+///
+/// Pools like `p(1;2)` are expanded into separate statements,
+/// pools in conditions of conditional literals are expanded into separate conditional literals.
+/// If there is nothing to unpool, the result contains the statement itself.
+///
+/// # Arguments
+///
+/// * `statement` - the statement to unpool
+/// * `unpool_type` - what to unpool
+///
+/// # Errors
+///
+/// - [`ClingoError`](enum.ClingoError.html) with [`ErrorCode::BadAlloc`](enum.ErrorCode.html#variant.BadAlloc)
+pub fn unpool<'a>(
+    statement: &Statement<'a>,
+    unpool_type: UnpoolType,
+) -> Result<Vec<Statement<'a>>, ClingoError> {
+    let asts = statement.ast.unpool(unpool_type)?;
+    Ok(asts.into_iter().map(|ast| Statement { ast }).collect())
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -288,7 +374,28 @@ pub enum Sign {
     /// For double negated literals (prefix `not not`).
     DoubleNegation = clingo_ast_sign_e_clingo_ast_sign_double_negation as isize,
 }
-#[derive(Debug, Copy, Clone)]
+impl Sign {
+    fn try_from(code: u32) -> Result<Sign, ClingoError> {
+        match code {
+            clingo_ast_sign_e_clingo_ast_sign_no_sign => Ok(Sign::NoSign),
+            clingo_ast_sign_e_clingo_ast_sign_negation => Ok(Sign::Negation),
+            clingo_ast_sign_e_clingo_ast_sign_double_negation => Ok(Sign::DoubleNegation),
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_sign {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_sign.",
+                })
+            }
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, PartialEq)]
 /// Enumeration of comparison relations
 pub enum ComparisonOperator {
     /// Operator `>`.
@@ -306,7 +413,43 @@ pub enum ComparisonOperator {
     /// Operator `==`.
     Equal = clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_equal as isize,
 }
-#[derive(Debug, Copy, Clone)]
+impl ComparisonOperator {
+    fn try_from(code: u32) -> Result<ComparisonOperator, ClingoError> {
+        match code {
+            clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_greater_than => {
+                Ok(ComparisonOperator::GreaterThan)
+            }
+            clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_less_than => {
+                Ok(ComparisonOperator::LessThan)
+            }
+            clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_less_equal => {
+                Ok(ComparisonOperator::LessEqual)
+            }
+            clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_greater_equal => {
+                Ok(ComparisonOperator::GreaterEqual)
+            }
+            clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_not_equal => {
+                Ok(ComparisonOperator::NotEqual)
+            }
+            clingo_ast_comparison_operator_e_clingo_ast_comparison_operator_equal => {
+                Ok(ComparisonOperator::Equal)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_comparison_operator {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_comparison_operator.",
+                })
+            }
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, PartialEq)]
 /// Enumeration of unary operators.
 pub enum UnaryOperator {
     /// Operator `-`.
@@ -316,7 +459,32 @@ pub enum UnaryOperator {
     /// Operator `|.|`.
     Absolute = clingo_ast_unary_operator_e_clingo_ast_unary_operator_absolute as isize,
 }
-#[derive(Debug, Copy, Clone)]
+impl UnaryOperator {
+    fn try_from(code: u32) -> Result<UnaryOperator, ClingoError> {
+        match code {
+            clingo_ast_unary_operator_e_clingo_ast_unary_operator_minus => Ok(UnaryOperator::Minus),
+            clingo_ast_unary_operator_e_clingo_ast_unary_operator_negation => {
+                Ok(UnaryOperator::Negation)
+            }
+            clingo_ast_unary_operator_e_clingo_ast_unary_operator_absolute => {
+                Ok(UnaryOperator::Absolute)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_unary_operator {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_unary_operator.",
+                })
+            }
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, PartialEq)]
 /// Enumeration of binary operators.
 pub enum BinaryOperator {
     /// Operator `^`.
@@ -339,7 +507,46 @@ pub enum BinaryOperator {
     /// Operator `**`.
     Power = clingo_ast_binary_operator_e_clingo_ast_binary_operator_power as isize,
 }
-#[derive(Debug, Copy, Clone)]
+impl BinaryOperator {
+    fn try_from(code: u32) -> Result<BinaryOperator, ClingoError> {
+        match code {
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_xor => Ok(BinaryOperator::Xor),
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_or => Ok(BinaryOperator::Or),
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_and => Ok(BinaryOperator::And),
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_plus => {
+                Ok(BinaryOperator::Plus)
+            }
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_minus => {
+                Ok(BinaryOperator::Minus)
+            }
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_multiplication => {
+                Ok(BinaryOperator::Multiplication)
+            }
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_division => {
+                Ok(BinaryOperator::Division)
+            }
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_modulo => {
+                Ok(BinaryOperator::Modulo)
+            }
+            clingo_ast_binary_operator_e_clingo_ast_binary_operator_power => {
+                Ok(BinaryOperator::Power)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_binary_operator {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_binary_operator.",
+                })
+            }
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, PartialEq)]
 /// Enumeration of aggregate functions.
 pub enum AggregateFunction {
     Count = clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_count as isize,
@@ -348,8 +555,41 @@ pub enum AggregateFunction {
     Min = clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_min as isize,
     Max = clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_max as isize,
 }
+impl AggregateFunction {
+    fn try_from(code: u32) -> Result<AggregateFunction, ClingoError> {
+        match code {
+            clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_count => {
+                Ok(AggregateFunction::Count)
+            }
+            clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_sum => {
+                Ok(AggregateFunction::Sum)
+            }
+            clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_sump => {
+                Ok(AggregateFunction::Sump)
+            }
+            clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_min => {
+                Ok(AggregateFunction::Min)
+            }
+            clingo_ast_aggregate_function_e_clingo_ast_aggregate_function_max => {
+                Ok(AggregateFunction::Max)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_aggregate_function {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_aggregate_function.",
+                })
+            }
+        }
+    }
+}
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TheoryTermSequenceType {
     /// For theory tuples `(t1,...,tn)`.
     Tuple = clingo_ast_theory_sequence_type_e_clingo_ast_theory_sequence_type_tuple as isize,
@@ -358,7 +598,34 @@ pub enum TheoryTermSequenceType {
     /// for theory sets `{t1,...,tn}`.
     Set = clingo_ast_theory_sequence_type_e_clingo_ast_theory_sequence_type_set as isize,
 }
-#[derive(Debug, Copy, Clone)]
+impl TheoryTermSequenceType {
+    fn try_from(code: u32) -> Result<TheoryTermSequenceType, ClingoError> {
+        match code {
+            clingo_ast_theory_sequence_type_e_clingo_ast_theory_sequence_type_tuple => {
+                Ok(TheoryTermSequenceType::Tuple)
+            }
+            clingo_ast_theory_sequence_type_e_clingo_ast_theory_sequence_type_list => {
+                Ok(TheoryTermSequenceType::List)
+            }
+            clingo_ast_theory_sequence_type_e_clingo_ast_theory_sequence_type_set => {
+                Ok(TheoryTermSequenceType::Set)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_theory_sequence_type {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_theory_sequence_type.",
+                })
+            }
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TheoryOperatorType {
     /// A left associative binary operator.
     BinaryLeft =
@@ -369,8 +636,35 @@ pub enum TheoryOperatorType {
     /// An unary theory operator.
     Unary = clingo_ast_theory_operator_type_e_clingo_ast_theory_operator_type_unary as isize,
 }
+impl TheoryOperatorType {
+    fn try_from(code: u32) -> Result<TheoryOperatorType, ClingoError> {
+        match code {
+            clingo_ast_theory_operator_type_e_clingo_ast_theory_operator_type_unary => {
+                Ok(TheoryOperatorType::Unary)
+            }
+            clingo_ast_theory_operator_type_e_clingo_ast_theory_operator_type_binary_left => {
+                Ok(TheoryOperatorType::BinaryLeft)
+            }
+            clingo_ast_theory_operator_type_e_clingo_ast_theory_operator_type_binary_right => {
+                Ok(TheoryOperatorType::BinaryRight)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_theory_operator_type {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_theory_operator_type.",
+                })
+            }
+        }
+    }
+}
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 /// Enumeration of the theory atom types.
 pub enum TheoryAtomType {
     /// For theory atoms that can appear in the head.
@@ -387,6 +681,76 @@ pub enum TheoryAtomType {
         clingo_ast_theory_atom_definition_type_e_clingo_ast_theory_atom_definition_type_directive
             as isize,
 }
+impl TheoryAtomType {
+    fn try_from(code: u32) -> Result<TheoryAtomType, ClingoError> {
+        match code {
+            clingo_ast_theory_atom_definition_type_e_clingo_ast_theory_atom_definition_type_head => {
+                Ok(TheoryAtomType::Head)
+            }
+            clingo_ast_theory_atom_definition_type_e_clingo_ast_theory_atom_definition_type_body => {
+                Ok(TheoryAtomType::Body)
+            }
+            clingo_ast_theory_atom_definition_type_e_clingo_ast_theory_atom_definition_type_any => {
+                Ok(TheoryAtomType::Any)
+            }
+            clingo_ast_theory_atom_definition_type_e_clingo_ast_theory_atom_definition_type_directive => {
+                Ok(TheoryAtomType::Directive)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_theory_atom_definition_type {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_theory_atom_definition_type.",
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// Enumeration of comment types.
+pub enum CommentType {
+    /// For line comments `% ...`.
+    Line = clingo_comment_type_e_clingo_comment_type_line as isize,
+    /// For block comments `%* ... *%`.
+    Block = clingo_comment_type_e_clingo_comment_type_block as isize,
+}
+impl CommentType {
+    fn try_from(code: u32) -> Result<CommentType, ClingoError> {
+        match code {
+            clingo_comment_type_e_clingo_comment_type_line => Ok(CommentType::Line),
+            clingo_comment_type_e_clingo_comment_type_block => Ok(CommentType::Block),
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_comment_type {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_comment_type.",
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// Enum to configure unpooling.
+pub enum UnpoolType {
+    /// To only unpool conditions of conditional literals.
+    Condition = clingo_ast_unpool_type_e_clingo_ast_unpool_type_condition as isize,
+    /// To unpool everything except conditions of conditional literals.
+    Other = clingo_ast_unpool_type_e_clingo_ast_unpool_type_other as isize,
+    /// To unpool everything.
+    All = clingo_ast_unpool_type_e_clingo_ast_unpool_type_all as isize,
+}
 
 // Here start the ASTTypes
 
@@ -408,14 +772,22 @@ impl<'a> Term<'a> {
             ASTType::Interval => Ok(TermIsA::Interval(Interval { ast: self.ast })),
             ASTType::Function => Ok(TermIsA::Function(Function { ast: self.ast })),
             ASTType::Pool => Ok(TermIsA::Pool(Pool { ast: self.ast })),
-            x => panic!("unexpected ASTType: {:?}", x),
+            x => {
+                eprintln!("unexpected ASTType for Term: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for Term.",
+                })
+            }
         }
     }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
-    pub fn location(&self) -> Location {
-        self.ast.location().unwrap()
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
     }
 }
 impl<'a> From<Variable<'a>> for Term<'a> {
@@ -468,10 +840,36 @@ pub struct Literal<'a> {
     ast: AST<'a>,
 }
 impl<'a> Literal<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn sign(&self) -> Result<Sign, ClingoError> {
+        Sign::try_from(self.ast.get_attribute_number(ASTAttribute::Sign)? as u32)
+    }
+    pub fn set_sign(&mut self, sign: Sign) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Sign, sign as i32)
+    }
+    pub fn atom(&self) -> Result<Atom<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Atom)?;
+        Ok(Atom { ast })
+    }
+    pub fn set_atom<T: Into<Atom<'a>>>(&mut self, atom: T) -> Result<(), ClingoError> {
+        let atom: Atom = atom.into();
+        self.ast.set_attribute_ast(ASTAttribute::Atom, &atom.ast)
+    }
     pub fn is_a(self) -> Result<BasicLiteral<'a>, ClingoError> {
         match self.ast.get_type()? {
             ASTType::Literal => Ok(BasicLiteral { ast: self.ast }),
-            x => panic!("unexpected ASTType: {:?}", x),
+            x => {
+                eprintln!("unexpected ASTType for Literal: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for Literal.",
+                })
+            }
         }
     }
     pub fn to_string(&self) -> Result<String, ClingoError> {
@@ -591,7 +989,12 @@ impl<'a> Head<'a> {
             ASTType::Disjunction => Ok(HeadIsA::Disjunction(Disjunction { ast: self.ast })),
             ASTType::TheoryAtom => Ok(HeadIsA::TheoryAtom(TheoryAtom { ast: self.ast })),
             ASTType::Aggregate => Ok(HeadIsA::Aggregate(Aggregate { ast: self.ast })),
-            x => panic!("unexpected ASTType for Head: {:?}", x),
+            x => {
+                eprintln!("unexpected ASTType for Head: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for Head.",
+                })
+            }
         }
     }
     pub fn to_string(&self) -> Result<String, ClingoError> {
@@ -651,7 +1054,12 @@ impl<'a> BodyLiteral<'a> {
                 }))
             }
             ASTType::TheoryAtom => Ok(BodyLiteralIsA::TheoryAtom(TheoryAtom { ast: self.ast })),
-            x => panic!("unexpected ASTType for BodyLiteral: {:?}", x),
+            x => {
+                eprintln!("unexpected ASTType for BodyLiteral: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for BodyLiteral.",
+                })
+            }
         }
     }
     pub fn to_string(&self) -> Result<String, ClingoError> {
@@ -684,10 +1092,106 @@ impl<'a> From<TheoryAtom<'a>> for BodyAtom<'a> {
     }
 }
 
+/// The atom of a literal.
+#[derive(Debug, Clone)]
+pub struct Atom<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Atom<'a> {
+    pub fn is_a(self) -> Result<AtomIsA<'a>, ClingoError> {
+        match self.ast.get_type()? {
+            ASTType::SymbolicAtom => Ok(AtomIsA::SymbolicAtom(SymbolicAtom { ast: self.ast })),
+            ASTType::Comparison => Ok(AtomIsA::Comparison(Comparison { ast: self.ast })),
+            ASTType::BooleanConstant => {
+                Ok(AtomIsA::BooleanConstant(BooleanConstant { ast: self.ast }))
+            }
+            ASTType::Aggregate => Ok(AtomIsA::Aggregate(Aggregate { ast: self.ast })),
+            ASTType::BodyAggregate => Ok(AtomIsA::BodyAggregate(BodyAggregate { ast: self.ast })),
+            ASTType::TheoryAtom => Ok(AtomIsA::TheoryAtom(TheoryAtom { ast: self.ast })),
+            x => {
+                eprintln!("unexpected ASTType for Atom: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for Atom.",
+                })
+            }
+        }
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast.to_string()
+    }
+}
+#[derive(Debug, Clone)]
+pub enum AtomIsA<'a> {
+    SymbolicAtom(SymbolicAtom<'a>),
+    Comparison(Comparison<'a>),
+    BooleanConstant(BooleanConstant<'a>),
+    Aggregate(Aggregate<'a>),
+    BodyAggregate(BodyAggregate<'a>),
+    TheoryAtom(TheoryAtom<'a>),
+}
+impl<'a> From<SymbolicAtom<'a>> for Atom<'a> {
+    fn from(x: SymbolicAtom<'a>) -> Self {
+        Atom { ast: x.ast }
+    }
+}
+impl<'a> From<Comparison<'a>> for Atom<'a> {
+    fn from(x: Comparison<'a>) -> Self {
+        Atom { ast: x.ast }
+    }
+}
+impl<'a> From<BooleanConstant<'a>> for Atom<'a> {
+    fn from(x: BooleanConstant<'a>) -> Self {
+        Atom { ast: x.ast }
+    }
+}
+impl<'a> From<BodyAtom<'a>> for Atom<'a> {
+    fn from(x: BodyAtom<'a>) -> Self {
+        Atom { ast: x.ast }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TheoryTerm<'a> {
     ast: AST<'a>,
 }
+impl<'a> TheoryTerm<'a> {
+    pub fn is_a(self) -> Result<TheoryTermIsA<'a>, ClingoError> {
+        match self.ast.get_type()? {
+            ASTType::SymbolicTerm => {
+                Ok(TheoryTermIsA::SymbolicTerm(SymbolicTerm { ast: self.ast }))
+            }
+            ASTType::Variable => Ok(TheoryTermIsA::Variable(Variable { ast: self.ast })),
+            ASTType::TheorySequence => Ok(TheoryTermIsA::TheorySequence(TheorySequence {
+                ast: self.ast,
+            })),
+            ASTType::TheoryFunction => Ok(TheoryTermIsA::TheoryFunction(TheoryFunction {
+                ast: self.ast,
+            })),
+            ASTType::TheoryUnparsedTerm => {
+                Ok(TheoryTermIsA::TheoryUnparsedTerm(TheoryUnparsedTerm {
+                    ast: self.ast,
+                }))
+            }
+            x => {
+                eprintln!("unexpected ASTType for TheoryTerm: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for TheoryTerm.",
+                })
+            }
+        }
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast.to_string()
+    }
+}
+#[derive(Debug, Clone)]
+pub enum TheoryTermIsA<'a> {
+    SymbolicTerm(SymbolicTerm<'a>),
+    Variable(Variable<'a>),
+    TheorySequence(TheorySequence<'a>),
+    TheoryFunction(TheoryFunction<'a>),
+    TheoryUnparsedTerm(TheoryUnparsedTerm<'a>),
+}
 impl<'a> From<SymbolicTerm<'a>> for TheoryTerm<'a> {
     fn from(x: SymbolicTerm<'a>) -> Self {
         TheoryTerm { ast: x.ast }
@@ -738,12 +1242,27 @@ impl<'a> Statement<'a> {
             ASTType::ProjectSignature => Ok(StatementIsA::ProjectSignature(ProjectSignature {
                 ast: self.ast,
             })),
-            x => panic!("unexpected ASTType: {:?}", x),
+            ASTType::TheoryDefinition => Ok(StatementIsA::TheoryDefinition(TheoryDefinition {
+                ast: self.ast,
+            })),
+            ASTType::Comment => Ok(StatementIsA::Comment(Comment { ast: self.ast })),
+            x => {
+                eprintln!("unexpected ASTType for Statement: {:?}", x);
+                Err(ClingoError::FFIError {
+                    msg: "Unexpected ASTType for Statement.",
+                })
+            }
         }
     }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
 }
 #[derive(Debug, Clone)]
 pub enum StatementIsA<'a> {
@@ -761,6 +1280,7 @@ pub enum StatementIsA<'a> {
     ProjectAtom(ProjectAtom<'a>),
     ProjectSignature(ProjectSignature<'a>),
     TheoryDefinition(TheoryDefinition<'a>),
+    Comment(Comment<'a>),
 }
 impl<'a> From<Rule<'a>> for Statement<'a> {
     fn from(x: Rule<'a>) -> Self {
@@ -832,15 +1352,46 @@ impl<'a> From<TheoryDefinition<'a>> for Statement<'a> {
         Statement { ast: x.ast }
     }
 }
+impl<'a> From<Comment<'a>> for Statement<'a> {
+    fn from(x: Comment<'a>) -> Self {
+        Statement { ast: x.ast }
+    }
+}
 #[derive(Debug, Clone)]
 pub struct Id<'a> {
     ast: AST<'a>,
 }
+impl<'a> Id<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+}
 #[derive(Debug, Clone)]
 pub struct Variable<'a> {
     ast: AST<'a>,
 }
 impl<'a> Variable<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
@@ -851,6 +1402,18 @@ pub struct SymbolicTerm<'a> {
 }
 
 impl<'a> SymbolicTerm<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn symbol(&self) -> Result<Symbol, ClingoError> {
+        self.ast.get_attribute_symbol(ASTAttribute::Symbol)
+    }
+    pub fn set_symbol(&mut self, symbol: Symbol) -> Result<(), ClingoError> {
+        self.ast.set_attribute_symbol(ASTAttribute::Symbol, symbol)
+    }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
@@ -861,32 +1424,98 @@ pub struct Function<'a> {
     pub(crate) ast: AST<'a>,
 }
 impl<'a> Function<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn arguments(&self) -> Result<Vec<Term<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Arguments)?;
+        Ok(asts.into_iter().map(|ast| Term { ast }).collect())
+    }
+    pub fn set_arguments(&mut self, arguments: &[Term]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Arguments, arguments.iter().map(|x| &x.ast))
+    }
+    pub fn external(&self) -> Result<bool, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::External)? != 0)
+    }
+    pub fn set_external(&mut self, external: bool) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::External, external as i32)
+    }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
-
-    pub fn name(&self) -> String {
-        self.ast.name().unwrap()
-    }
 }
 #[derive(Debug, Clone)]
 pub struct UnaryOperation<'a> {
     ast: AST<'a>,
 }
-impl<'a> UnaryOperation<'a> {}
+impl<'a> UnaryOperation<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn operator_type(&self) -> Result<UnaryOperator, ClingoError> {
+        UnaryOperator::try_from(self.ast.get_attribute_number(ASTAttribute::OperatorType)? as u32)
+    }
+    pub fn set_operator_type(&mut self, operator_type: UnaryOperator) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::OperatorType, operator_type as i32)
+    }
+    pub fn argument(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Argument)?;
+        Ok(Term { ast })
+    }
+    pub fn set_argument<T: Into<Term<'a>>>(&mut self, argument: T) -> Result<(), ClingoError> {
+        let argument: Term = argument.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Argument, &argument.ast)
+    }
+}
 #[derive(Debug, Clone)]
 pub struct BinaryOperation<'a> {
     ast: AST<'a>,
 }
 impl<'a> BinaryOperation<'a> {
-    // pub fn operator_type(&self) -> BinaryOperator {
-    //     self.ast.operator_type()
-    // }
-    pub fn left(&self) -> Term {
-        self.ast.left()
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
     }
-    pub fn right(&self) -> Term {
-        self.ast.right()
+    pub fn operator_type(&self) -> Result<BinaryOperator, ClingoError> {
+        BinaryOperator::try_from(self.ast.get_attribute_number(ASTAttribute::OperatorType)? as u32)
+    }
+    pub fn set_operator_type(&mut self, operator_type: BinaryOperator) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::OperatorType, operator_type as i32)
+    }
+    pub fn left(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Left)?;
+        Ok(Term { ast })
+    }
+    pub fn set_left<T: Into<Term<'a>>>(&mut self, left: T) -> Result<(), ClingoError> {
+        let left: Term = left.into();
+        self.ast.set_attribute_ast(ASTAttribute::Left, &left.ast)
+    }
+    pub fn right(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Right)?;
+        Ok(Term { ast })
+    }
+    pub fn set_right<T: Into<Term<'a>>>(&mut self, right: T) -> Result<(), ClingoError> {
+        let right: Term = right.into();
+        self.ast.set_attribute_ast(ASTAttribute::Right, &right.ast)
     }
 }
 #[derive(Debug, Clone)]
@@ -894,28 +1523,78 @@ pub struct Interval<'a> {
     ast: AST<'a>,
 }
 impl<'a> Interval<'a> {
-    pub fn left(&self) -> Term {
-        self.ast.left()
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn left(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Left)?;
+        Ok(Term { ast })
+    }
+    pub fn set_left<T: Into<Term<'a>>>(&mut self, left: T) -> Result<(), ClingoError> {
+        let left: Term = left.into();
+        self.ast.set_attribute_ast(ASTAttribute::Left, &left.ast)
     }
-    pub fn right(&self) -> Term {
-        self.ast.right()
+    pub fn right(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Right)?;
+        Ok(Term { ast })
+    }
+    pub fn set_right<T: Into<Term<'a>>>(&mut self, right: T) -> Result<(), ClingoError> {
+        let right: Term = right.into();
+        self.ast.set_attribute_ast(ASTAttribute::Right, &right.ast)
     }
 }
 #[derive(Debug, Clone)]
 pub struct Pool<'a> {
     ast: AST<'a>,
 }
+impl<'a> Pool<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn arguments(&self) -> Result<Vec<Term<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Arguments)?;
+        Ok(asts.into_iter().map(|ast| Term { ast }).collect())
+    }
+    pub fn set_arguments(&mut self, arguments: &[Term]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Arguments, arguments.iter().map(|x| &x.ast))
+    }
+}
 
 #[derive(Debug, Clone)]
-struct BooleanConstant<'a> {
+pub struct BooleanConstant<'a> {
     ast: AST<'a>,
 }
+impl<'a> BooleanConstant<'a> {
+    pub fn value(&self) -> Result<bool, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Value)? != 0)
+    }
+    pub fn set_value(&mut self, value: bool) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Value, value as i32)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct SymbolicAtom<'a> {
     ast: AST<'a>,
 }
 impl<'a> SymbolicAtom<'a> {
+    pub fn symbol(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Symbol)?;
+        Ok(Term { ast })
+    }
+    pub fn set_symbol<T: Into<Term<'a>>>(&mut self, symbol: T) -> Result<(), ClingoError> {
+        let symbol: Term = symbol.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Symbol, &symbol.ast)
+    }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
@@ -925,6 +1604,21 @@ pub struct Guard<'a> {
     ast: AST<'a>,
 }
 impl<'a> Guard<'a> {
+    pub fn comparison(&self) -> Result<ComparisonOperator, ClingoError> {
+        ComparisonOperator::try_from(self.ast.get_attribute_number(ASTAttribute::Comparison)? as u32)
+    }
+    pub fn set_comparison(&mut self, comparison: ComparisonOperator) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Comparison, comparison as i32)
+    }
+    pub fn term(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Term)?;
+        Ok(Term { ast })
+    }
+    pub fn set_term<T: Into<Term<'a>>>(&mut self, term: T) -> Result<(), ClingoError> {
+        let term: Term = term.into();
+        self.ast.set_attribute_ast(ASTAttribute::Term, &term.ast)
+    }
     pub fn to_string(&self) -> Result<String, ClingoError> {
         self.ast.to_string()
     }
@@ -933,197 +1627,2678 @@ impl<'a> Guard<'a> {
 pub struct Comparison<'a> {
     ast: AST<'a>,
 }
-impl<'a> Comparison<'a> {
-    pub fn to_string(&self) -> Result<String, ClingoError> {
-        self.ast.to_string()
+impl<'a> Comparison<'a> {
+    pub fn term(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Term)?;
+        Ok(Term { ast })
+    }
+    pub fn set_term<T: Into<Term<'a>>>(&mut self, term: T) -> Result<(), ClingoError> {
+        let term: Term = term.into();
+        self.ast.set_attribute_ast(ASTAttribute::Term, &term.ast)
+    }
+    pub fn guards(&self) -> Result<Vec<Guard<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Guards)?;
+        Ok(asts.into_iter().map(|ast| Guard { ast }).collect())
+    }
+    pub fn set_guards(&mut self, guards: &[Guard]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Guards, guards.iter().map(|x| &x.ast))
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast.to_string()
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ConditionalLiteral<'a> {
+    ast: AST<'a>,
+}
+impl<'a> ConditionalLiteral<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn literal(&self) -> Result<Literal<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Literal)?;
+        Ok(Literal { ast })
+    }
+    pub fn set_literal<T: Into<Literal<'a>>>(&mut self, literal: T) -> Result<(), ClingoError> {
+        let literal: Literal = literal.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Literal, &literal.ast)
+    }
+    pub fn condition(&self) -> Result<Vec<Literal<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Condition)?;
+        Ok(asts.into_iter().map(|ast| Literal { ast }).collect())
+    }
+    pub fn set_condition(&mut self, condition: &[Literal]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Condition, condition.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Aggregate<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Aggregate<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn left_guard(&self) -> Result<Option<Guard<'a>>, ClingoError> {
+        let ast = self
+            .ast
+            .get_attribute_optional_ast(ASTAttribute::LeftGuard)?;
+        Ok(ast.map(|ast| Guard { ast }))
+    }
+    pub fn set_left_guard(&mut self, left_guard: Option<&Guard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::LeftGuard, left_guard.map(|x| &x.ast))
+    }
+    pub fn elements(&self) -> Result<Vec<ConditionalLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Elements)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| ConditionalLiteral { ast })
+            .collect())
+    }
+    pub fn set_elements(&mut self, elements: &[ConditionalLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Elements, elements.iter().map(|x| &x.ast))
+    }
+    pub fn right_guard(&self) -> Result<Option<Guard<'a>>, ClingoError> {
+        let ast = self
+            .ast
+            .get_attribute_optional_ast(ASTAttribute::RightGuard)?;
+        Ok(ast.map(|ast| Guard { ast }))
+    }
+    pub fn set_right_guard(&mut self, right_guard: Option<&Guard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::RightGuard, right_guard.map(|x| &x.ast))
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast.to_string()
+    }
+}
+#[derive(Debug, Clone)]
+pub struct BodyAggregateElement<'a> {
+    ast: AST<'a>,
+}
+impl<'a> BodyAggregateElement<'a> {
+    pub fn terms(&self) -> Result<Vec<Term<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Terms)?;
+        Ok(asts.into_iter().map(|ast| Term { ast }).collect())
+    }
+    pub fn set_terms(&mut self, terms: &[Term]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Terms, terms.iter().map(|x| &x.ast))
+    }
+    pub fn condition(&self) -> Result<Vec<Literal<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Condition)?;
+        Ok(asts.into_iter().map(|ast| Literal { ast }).collect())
+    }
+    pub fn set_condition(&mut self, condition: &[Literal]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Condition, condition.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct BodyAggregate<'a> {
+    ast: AST<'a>,
+}
+impl<'a> BodyAggregate<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn left_guard(&self) -> Result<Option<Guard<'a>>, ClingoError> {
+        let ast = self
+            .ast
+            .get_attribute_optional_ast(ASTAttribute::LeftGuard)?;
+        Ok(ast.map(|ast| Guard { ast }))
+    }
+    pub fn set_left_guard(&mut self, left_guard: Option<&Guard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::LeftGuard, left_guard.map(|x| &x.ast))
+    }
+    pub fn function(&self) -> Result<AggregateFunction, ClingoError> {
+        AggregateFunction::try_from(self.ast.get_attribute_number(ASTAttribute::Function)? as u32)
+    }
+    pub fn set_function(&mut self, function: AggregateFunction) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Function, function as i32)
+    }
+    pub fn elements(&self) -> Result<Vec<BodyAggregateElement<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Elements)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| BodyAggregateElement { ast })
+            .collect())
+    }
+    pub fn set_elements(&mut self, elements: &[BodyAggregateElement]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Elements, elements.iter().map(|x| &x.ast))
+    }
+    pub fn right_guard(&self) -> Result<Option<Guard<'a>>, ClingoError> {
+        let ast = self
+            .ast
+            .get_attribute_optional_ast(ASTAttribute::RightGuard)?;
+        Ok(ast.map(|ast| Guard { ast }))
+    }
+    pub fn set_right_guard(&mut self, right_guard: Option<&Guard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::RightGuard, right_guard.map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct HeadAggregateElement<'a> {
+    ast: AST<'a>,
+}
+impl<'a> HeadAggregateElement<'a> {
+    pub fn terms(&self) -> Result<Vec<Term<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Terms)?;
+        Ok(asts.into_iter().map(|ast| Term { ast }).collect())
+    }
+    pub fn set_terms(&mut self, terms: &[Term]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Terms, terms.iter().map(|x| &x.ast))
+    }
+    pub fn condition(&self) -> Result<ConditionalLiteral<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Condition)?;
+        Ok(ConditionalLiteral { ast })
+    }
+    pub fn set_condition(&mut self, condition: &ConditionalLiteral) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast(ASTAttribute::Condition, &condition.ast)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct HeadAggregate<'a> {
+    ast: AST<'a>,
+}
+impl<'a> HeadAggregate<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn left_guard(&self) -> Result<Option<Guard<'a>>, ClingoError> {
+        let ast = self
+            .ast
+            .get_attribute_optional_ast(ASTAttribute::LeftGuard)?;
+        Ok(ast.map(|ast| Guard { ast }))
+    }
+    pub fn set_left_guard(&mut self, left_guard: Option<&Guard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::LeftGuard, left_guard.map(|x| &x.ast))
+    }
+    pub fn function(&self) -> Result<AggregateFunction, ClingoError> {
+        AggregateFunction::try_from(self.ast.get_attribute_number(ASTAttribute::Function)? as u32)
+    }
+    pub fn set_function(&mut self, function: AggregateFunction) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Function, function as i32)
+    }
+    pub fn elements(&self) -> Result<Vec<HeadAggregateElement<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Elements)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| HeadAggregateElement { ast })
+            .collect())
+    }
+    pub fn set_elements(&mut self, elements: &[HeadAggregateElement]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Elements, elements.iter().map(|x| &x.ast))
+    }
+    pub fn right_guard(&self) -> Result<Option<Guard<'a>>, ClingoError> {
+        let ast = self
+            .ast
+            .get_attribute_optional_ast(ASTAttribute::RightGuard)?;
+        Ok(ast.map(|ast| Guard { ast }))
+    }
+    pub fn set_right_guard(&mut self, right_guard: Option<&Guard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::RightGuard, right_guard.map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Disjunction<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Disjunction<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn elements(&self) -> Result<Vec<ConditionalLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Elements)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| ConditionalLiteral { ast })
+            .collect())
+    }
+    pub fn set_elements(&mut self, elements: &[ConditionalLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Elements, elements.iter().map(|x| &x.ast))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DisjointElement<'a> {
+    ast: AST<'a>,
+}
+#[derive(Debug, Clone)]
+pub struct Disjoint<'a> {
+    ast: AST<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TheorySequence<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheorySequence<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn sequence_type(&self) -> Result<TheoryTermSequenceType, ClingoError> {
+        TheoryTermSequenceType::try_from(
+            self.ast.get_attribute_number(ASTAttribute::SequenceType)? as u32,
+        )
+    }
+    pub fn set_sequence_type(
+        &mut self,
+        sequence_type: TheoryTermSequenceType,
+    ) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::SequenceType, sequence_type as i32)
+    }
+    pub fn terms(&self) -> Result<Vec<TheoryTerm<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Terms)?;
+        Ok(asts.into_iter().map(|ast| TheoryTerm { ast }).collect())
+    }
+    pub fn set_terms(&mut self, terms: &[TheoryTerm]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Terms, terms.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryFunction<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryFunction<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn arguments(&self) -> Result<Vec<TheoryTerm<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Arguments)?;
+        Ok(asts.into_iter().map(|ast| TheoryTerm { ast }).collect())
+    }
+    pub fn set_arguments(&mut self, arguments: &[TheoryTerm]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Arguments, arguments.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryUnparsedTermElement<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryUnparsedTermElement<'a> {
+    pub fn operators(&self) -> Result<Vec<String>, ClingoError> {
+        self.ast.get_attribute_string_array(ASTAttribute::Operators)
+    }
+    pub fn set_operators(&mut self, operators: &[&str]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_string_array(ASTAttribute::Operators, operators)
+    }
+    pub fn term(&self) -> Result<TheoryTerm<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Term)?;
+        Ok(TheoryTerm { ast })
+    }
+    pub fn set_term<T: Into<TheoryTerm<'a>>>(&mut self, term: T) -> Result<(), ClingoError> {
+        let term: TheoryTerm = term.into();
+        self.ast.set_attribute_ast(ASTAttribute::Term, &term.ast)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryUnparsedTerm<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryUnparsedTerm<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn elements(&self) -> Result<Vec<TheoryUnparsedTermElement<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Elements)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| TheoryUnparsedTermElement { ast })
+            .collect())
+    }
+    pub fn set_elements(
+        &mut self,
+        elements: &[TheoryUnparsedTermElement],
+    ) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Elements, elements.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryGuard<'a> {
+    pub(crate) ast: AST<'a>,
+}
+impl<'a> TheoryGuard<'a> {
+    pub fn operator_name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::OperatorName)
+    }
+    pub fn set_operator_name(&mut self, operator_name: &str) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_string(ASTAttribute::OperatorName, operator_name)
+    }
+    pub fn term(&self) -> Result<TheoryTerm<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Term)?;
+        Ok(TheoryTerm { ast })
+    }
+    pub fn set_term<T: Into<TheoryTerm<'a>>>(&mut self, term: T) -> Result<(), ClingoError> {
+        let term: TheoryTerm = term.into();
+        self.ast.set_attribute_ast(ASTAttribute::Term, &term.ast)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryAtomElement<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryAtomElement<'a> {
+    pub fn terms(&self) -> Result<Vec<TheoryTerm<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Terms)?;
+        Ok(asts.into_iter().map(|ast| TheoryTerm { ast }).collect())
+    }
+    pub fn set_terms(&mut self, terms: &[TheoryTerm]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Terms, terms.iter().map(|x| &x.ast))
+    }
+    pub fn condition(&self) -> Result<Vec<Literal<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Condition)?;
+        Ok(asts.into_iter().map(|ast| Literal { ast }).collect())
+    }
+    pub fn set_condition(&mut self, condition: &[Literal]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Condition, condition.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryAtom<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryAtom<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn term(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Term)?;
+        Ok(Term { ast })
+    }
+    pub fn set_term<T: Into<Term<'a>>>(&mut self, term: T) -> Result<(), ClingoError> {
+        let term: Term = term.into();
+        self.ast.set_attribute_ast(ASTAttribute::Term, &term.ast)
+    }
+    pub fn elements(&self) -> Result<Vec<TheoryAtomElement<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Elements)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| TheoryAtomElement { ast })
+            .collect())
+    }
+    pub fn set_elements(&mut self, elements: &[TheoryAtomElement]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Elements, elements.iter().map(|x| &x.ast))
+    }
+    pub fn guard(&self) -> Result<Option<TheoryGuard<'a>>, ClingoError> {
+        let ast = self.ast.get_attribute_optional_ast(ASTAttribute::Guard)?;
+        Ok(ast.map(|ast| TheoryGuard { ast }))
+    }
+    pub fn set_guard(&mut self, guard: Option<&TheoryGuard>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::Guard, guard.map(|x| &x.ast))
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast.to_string()
+    }
+}
+#[derive(Debug, Clone)]
+pub struct AtomicLiteral<'a> {
+    ast: AST<'a>,
+}
+impl<'a> AtomicLiteral<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn sign(&self) -> Result<Sign, ClingoError> {
+        Sign::try_from(self.ast.get_attribute_number(ASTAttribute::Sign)? as u32)
+    }
+    pub fn set_sign(&mut self, sign: Sign) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Sign, sign as i32)
+    }
+    pub fn atom(&self) -> Result<Atom<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Atom)?;
+        Ok(Atom { ast })
+    }
+    pub fn set_atom<T: Into<Atom<'a>>>(&mut self, atom: T) -> Result<(), ClingoError> {
+        let atom: Atom = atom.into();
+        self.ast.set_attribute_ast(ASTAttribute::Atom, &atom.ast)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct BasicLiteral<'a> {
+    ast: AST<'a>,
+}
+impl<'a> BasicLiteral<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn sign(&self) -> Result<Sign, ClingoError> {
+        Sign::try_from(self.ast.get_attribute_number(ASTAttribute::Sign)? as u32)
+    }
+    pub fn set_sign(&mut self, sign: Sign) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Sign, sign as i32)
+    }
+    pub fn atom(&self) -> Result<Atom<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Atom)?;
+        Ok(Atom { ast })
+    }
+    pub fn set_atom<T: Into<Atom<'a>>>(&mut self, atom: T) -> Result<(), ClingoError> {
+        let atom: Atom = atom.into();
+        self.ast.set_attribute_ast(ASTAttribute::Atom, &atom.ast)
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast.to_string()
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryOperatorDefinition<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryOperatorDefinition<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn priority(&self) -> Result<u32, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Priority)? as u32)
+    }
+    pub fn set_priority(&mut self, priority: u32) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Priority, priority as i32)
+    }
+    pub fn operator_type(&self) -> Result<TheoryOperatorType, ClingoError> {
+        TheoryOperatorType::try_from(
+            self.ast.get_attribute_number(ASTAttribute::OperatorType)? as u32
+        )
+    }
+    pub fn set_operator_type(
+        &mut self,
+        operator_type: TheoryOperatorType,
+    ) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::OperatorType, operator_type as i32)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryTermDefinition<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryTermDefinition<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn operators(&self) -> Result<Vec<TheoryOperatorDefinition<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Operators)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| TheoryOperatorDefinition { ast })
+            .collect())
+    }
+    pub fn set_operators(
+        &mut self,
+        operators: &[TheoryOperatorDefinition],
+    ) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Operators, operators.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryGuardDefinition<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryGuardDefinition<'a> {
+    pub fn operators(&self) -> Result<Vec<String>, ClingoError> {
+        self.ast.get_attribute_string_array(ASTAttribute::Operators)
+    }
+    pub fn set_operators(&mut self, operators: &[&str]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_string_array(ASTAttribute::Operators, operators)
+    }
+    pub fn term(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Term)
+    }
+    pub fn set_term(&mut self, term: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Term, term)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryAtomDefinition<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryAtomDefinition<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn atom_type(&self) -> Result<TheoryAtomType, ClingoError> {
+        TheoryAtomType::try_from(self.ast.get_attribute_number(ASTAttribute::AtomType)? as u32)
+    }
+    pub fn set_atom_type(&mut self, atom_type: TheoryAtomType) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::AtomType, atom_type as i32)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn arity(&self) -> Result<u32, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Arity)? as u32)
+    }
+    pub fn set_arity(&mut self, arity: u32) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Arity, arity as i32)
+    }
+    pub fn term(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Term)
+    }
+    pub fn set_term(&mut self, term: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Term, term)
+    }
+    pub fn guard(&self) -> Result<Option<TheoryGuardDefinition<'a>>, ClingoError> {
+        let ast = self.ast.get_attribute_optional_ast(ASTAttribute::Guard)?;
+        Ok(ast.map(|ast| TheoryGuardDefinition { ast }))
+    }
+    pub fn set_guard(&mut self, guard: Option<&TheoryGuardDefinition>) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_optional_ast(ASTAttribute::Guard, guard.map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Rule<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Rule<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn head(&self) -> Result<Head<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Head)?;
+        Ok(Head { ast })
+    }
+    pub fn set_head<T: Into<Head<'a>>>(&mut self, head: T) -> Result<(), ClingoError> {
+        let head: Head = head.into();
+        self.ast.set_attribute_ast(ASTAttribute::Head, &head.ast)
+    }
+    pub fn body(&self) -> Body<'a> {
+        self.ast.body()
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Definition<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Definition<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn value(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Value)?;
+        Ok(Term { ast })
+    }
+    pub fn set_value<T: Into<Term<'a>>>(&mut self, value: T) -> Result<(), ClingoError> {
+        let value: Term = value.into();
+        self.ast.set_attribute_ast(ASTAttribute::Value, &value.ast)
+    }
+    pub fn is_default(&self) -> Result<bool, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::IsDefault)? != 0)
+    }
+    pub fn set_is_default(&mut self, is_default: bool) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::IsDefault, is_default as i32)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ShowSignature<'a> {
+    ast: AST<'a>,
+}
+impl<'a> ShowSignature<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn arity(&self) -> Result<u32, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Arity)? as u32)
+    }
+    pub fn set_arity(&mut self, arity: u32) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Arity, arity as i32)
+    }
+    pub fn positive(&self) -> Result<bool, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Positive)? != 0)
+    }
+    pub fn set_positive(&mut self, positive: bool) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Positive, positive as i32)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ShowTerm<'a> {
+    ast: AST<'a>,
+}
+impl<'a> ShowTerm<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn term(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Term)?;
+        Ok(Term { ast })
+    }
+    pub fn set_term<T: Into<Term<'a>>>(&mut self, term: T) -> Result<(), ClingoError> {
+        let term: Term = term.into();
+        self.ast.set_attribute_ast(ASTAttribute::Term, &term.ast)
+    }
+    pub fn body(&self) -> Result<Vec<BodyLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Body)?;
+        Ok(asts.into_iter().map(|ast| BodyLiteral { ast }).collect())
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Minimize<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Minimize<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn weight(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Weight)?;
+        Ok(Term { ast })
+    }
+    pub fn set_weight<T: Into<Term<'a>>>(&mut self, weight: T) -> Result<(), ClingoError> {
+        let weight: Term = weight.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Weight, &weight.ast)
+    }
+    pub fn priority(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Priority)?;
+        Ok(Term { ast })
+    }
+    pub fn set_priority<T: Into<Term<'a>>>(&mut self, priority: T) -> Result<(), ClingoError> {
+        let priority: Term = priority.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Priority, &priority.ast)
+    }
+    pub fn terms(&self) -> Result<Vec<Term<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Terms)?;
+        Ok(asts.into_iter().map(|ast| Term { ast }).collect())
+    }
+    pub fn set_terms(&mut self, terms: &[Term]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Terms, terms.iter().map(|x| &x.ast))
+    }
+    pub fn body(&self) -> Result<Vec<BodyLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Body)?;
+        Ok(asts.into_iter().map(|ast| BodyLiteral { ast }).collect())
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Script<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Script<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn code(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Code)
+    }
+    pub fn set_code(&mut self, code: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Code, code)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Program<'a> {
+    pub(crate) ast: AST<'a>,
+}
+impl<'a> Program<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn parameters(&self) -> Result<Vec<Id<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Parameters)?;
+        Ok(asts.into_iter().map(|ast| Id { ast }).collect())
+    }
+    pub fn set_parameters(&mut self, parameters: &[Id]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Parameters, parameters.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct External<'a> {
+    pub(crate) ast: AST<'a>,
+}
+impl<'a> External<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn atom(&self) -> Result<SymbolicAtom<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Atom)?;
+        Ok(SymbolicAtom { ast })
+    }
+    pub fn set_atom(&mut self, atom: &SymbolicAtom) -> Result<(), ClingoError> {
+        self.ast.set_attribute_ast(ASTAttribute::Atom, &atom.ast)
+    }
+    pub fn body(&self) -> Result<Vec<BodyLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Body)?;
+        Ok(asts.into_iter().map(|ast| BodyLiteral { ast }).collect())
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+    pub fn external_type(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::ExternalType)?;
+        Ok(Term { ast })
+    }
+    pub fn set_external_type<T: Into<Term<'a>>>(
+        &mut self,
+        external_type: T,
+    ) -> Result<(), ClingoError> {
+        let external_type: Term = external_type.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::ExternalType, &external_type.ast)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Edge<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Edge<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn node_u(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::NodeU)?;
+        Ok(Term { ast })
+    }
+    pub fn set_node_u<T: Into<Term<'a>>>(&mut self, node_u: T) -> Result<(), ClingoError> {
+        let node_u: Term = node_u.into();
+        self.ast.set_attribute_ast(ASTAttribute::NodeU, &node_u.ast)
+    }
+    pub fn node_v(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::NodeV)?;
+        Ok(Term { ast })
+    }
+    pub fn set_node_v<T: Into<Term<'a>>>(&mut self, node_v: T) -> Result<(), ClingoError> {
+        let node_v: Term = node_v.into();
+        self.ast.set_attribute_ast(ASTAttribute::NodeV, &node_v.ast)
+    }
+    pub fn body(&self) -> Result<Vec<BodyLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Body)?;
+        Ok(asts.into_iter().map(|ast| BodyLiteral { ast }).collect())
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Heuristic<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Heuristic<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn atom(&self) -> Result<SymbolicAtom<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Atom)?;
+        Ok(SymbolicAtom { ast })
+    }
+    pub fn set_atom(&mut self, atom: &SymbolicAtom) -> Result<(), ClingoError> {
+        self.ast.set_attribute_ast(ASTAttribute::Atom, &atom.ast)
+    }
+    pub fn body(&self) -> Result<Vec<BodyLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Body)?;
+        Ok(asts.into_iter().map(|ast| BodyLiteral { ast }).collect())
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+    pub fn bias(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Bias)?;
+        Ok(Term { ast })
+    }
+    pub fn set_bias<T: Into<Term<'a>>>(&mut self, bias: T) -> Result<(), ClingoError> {
+        let bias: Term = bias.into();
+        self.ast.set_attribute_ast(ASTAttribute::Bias, &bias.ast)
+    }
+    pub fn priority(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Priority)?;
+        Ok(Term { ast })
+    }
+    pub fn set_priority<T: Into<Term<'a>>>(&mut self, priority: T) -> Result<(), ClingoError> {
+        let priority: Term = priority.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Priority, &priority.ast)
+    }
+    pub fn modifier(&self) -> Result<Term<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Modifier)?;
+        Ok(Term { ast })
+    }
+    pub fn set_modifier<T: Into<Term<'a>>>(&mut self, modifier: T) -> Result<(), ClingoError> {
+        let modifier: Term = modifier.into();
+        self.ast
+            .set_attribute_ast(ASTAttribute::Modifier, &modifier.ast)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ProjectAtom<'a> {
+    ast: AST<'a>,
+}
+impl<'a> ProjectAtom<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn atom(&self) -> Result<SymbolicAtom<'a>, ClingoError> {
+        let ast = self.ast.get_attribute_ast(ASTAttribute::Atom)?;
+        Ok(SymbolicAtom { ast })
+    }
+    pub fn set_atom(&mut self, atom: &SymbolicAtom) -> Result<(), ClingoError> {
+        self.ast.set_attribute_ast(ASTAttribute::Atom, &atom.ast)
+    }
+    pub fn body(&self) -> Result<Vec<BodyLiteral<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Body)?;
+        Ok(asts.into_iter().map(|ast| BodyLiteral { ast }).collect())
+    }
+    pub fn set_body(&mut self, body: &[BodyLiteral]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Body, body.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct ProjectSignature<'a> {
+    ast: AST<'a>,
+}
+impl<'a> ProjectSignature<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn arity(&self) -> Result<u32, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Arity)? as u32)
+    }
+    pub fn set_arity(&mut self, arity: u32) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Arity, arity as i32)
+    }
+    pub fn positive(&self) -> Result<bool, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Positive)? != 0)
+    }
+    pub fn set_positive(&mut self, positive: bool) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Positive, positive as i32)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Defined<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Defined<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn arity(&self) -> Result<u32, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Arity)? as u32)
+    }
+    pub fn set_arity(&mut self, arity: u32) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Arity, arity as i32)
+    }
+    pub fn positive(&self) -> Result<bool, ClingoError> {
+        Ok(self.ast.get_attribute_number(ASTAttribute::Positive)? != 0)
+    }
+    pub fn set_positive(&mut self, positive: bool) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::Positive, positive as i32)
+    }
+}
+#[derive(Debug, Clone)]
+pub struct TheoryDefinition<'a> {
+    ast: AST<'a>,
+}
+impl<'a> TheoryDefinition<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn name(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Name)
+    }
+    pub fn set_name(&mut self, name: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Name, name)
+    }
+    pub fn terms(&self) -> Result<Vec<TheoryTermDefinition<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Terms)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| TheoryTermDefinition { ast })
+            .collect())
+    }
+    pub fn set_terms(&mut self, terms: &[TheoryTermDefinition]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Terms, terms.iter().map(|x| &x.ast))
+    }
+    pub fn atoms(&self) -> Result<Vec<TheoryAtomDefinition<'a>>, ClingoError> {
+        let asts = self.ast.get_attribute_ast_array(ASTAttribute::Atoms)?;
+        Ok(asts
+            .into_iter()
+            .map(|ast| TheoryAtomDefinition { ast })
+            .collect())
+    }
+    pub fn set_atoms(&mut self, atoms: &[TheoryAtomDefinition]) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_ast_array(ASTAttribute::Atoms, atoms.iter().map(|x| &x.ast))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Comment<'a> {
+    ast: AST<'a>,
+}
+impl<'a> Comment<'a> {
+    pub fn location(&self) -> Result<Location, ClingoError> {
+        self.ast.location()
+    }
+    pub fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        self.ast.set_location(location)
+    }
+    pub fn value(&self) -> Result<String, ClingoError> {
+        self.ast.get_attribute_string(ASTAttribute::Value)
+    }
+    pub fn set_value(&mut self, value: &str) -> Result<(), ClingoError> {
+        self.ast.set_attribute_string(ASTAttribute::Value, value)
+    }
+    pub fn comment_type(&self) -> Result<CommentType, ClingoError> {
+        CommentType::try_from(self.ast.get_attribute_number(ASTAttribute::CommentType)? as u32)
+    }
+    pub fn set_comment_type(&mut self, comment_type: CommentType) -> Result<(), ClingoError> {
+        self.ast
+            .set_attribute_number(ASTAttribute::CommentType, comment_type as i32)
+    }
+}
+
+/// A node of the abstract syntax tree tagged with its type.
+///
+/// Nodes are passed to [`Visitor`](trait.Visitor.html) and [`Transformer`](trait.Transformer.html) implementations.
+/// Converting a reference to a node type into a `Node` does not copy the underlying AST,
+/// both refer to the same node.
+#[derive(Debug, Clone)]
+pub enum Node<'a> {
+    Id(Id<'a>),
+    Variable(Variable<'a>),
+    SymbolicTerm(SymbolicTerm<'a>),
+    UnaryOperation(UnaryOperation<'a>),
+    BinaryOperation(BinaryOperation<'a>),
+    Interval(Interval<'a>),
+    Function(Function<'a>),
+    Pool(Pool<'a>),
+    BooleanConstant(BooleanConstant<'a>),
+    SymbolicAtom(SymbolicAtom<'a>),
+    Comparison(Comparison<'a>),
+    Guard(Guard<'a>),
+    ConditionalLiteral(ConditionalLiteral<'a>),
+    Aggregate(Aggregate<'a>),
+    BodyAggregateElement(BodyAggregateElement<'a>),
+    BodyAggregate(BodyAggregate<'a>),
+    HeadAggregateElement(HeadAggregateElement<'a>),
+    HeadAggregate(HeadAggregate<'a>),
+    Disjunction(Disjunction<'a>),
+    TheorySequence(TheorySequence<'a>),
+    TheoryFunction(TheoryFunction<'a>),
+    TheoryUnparsedTermElement(TheoryUnparsedTermElement<'a>),
+    TheoryUnparsedTerm(TheoryUnparsedTerm<'a>),
+    TheoryGuard(TheoryGuard<'a>),
+    TheoryAtomElement(TheoryAtomElement<'a>),
+    TheoryAtom(TheoryAtom<'a>),
+    Literal(BasicLiteral<'a>),
+    TheoryOperatorDefinition(TheoryOperatorDefinition<'a>),
+    TheoryTermDefinition(TheoryTermDefinition<'a>),
+    TheoryGuardDefinition(TheoryGuardDefinition<'a>),
+    TheoryAtomDefinition(TheoryAtomDefinition<'a>),
+    Rule(Rule<'a>),
+    Definition(Definition<'a>),
+    ShowSignature(ShowSignature<'a>),
+    ShowTerm(ShowTerm<'a>),
+    Minimize(Minimize<'a>),
+    Script(Script<'a>),
+    Program(Program<'a>),
+    External(External<'a>),
+    Edge(Edge<'a>),
+    Heuristic(Heuristic<'a>),
+    ProjectAtom(ProjectAtom<'a>),
+    ProjectSignature(ProjectSignature<'a>),
+    Defined(Defined<'a>),
+    TheoryDefinition(TheoryDefinition<'a>),
+    Comment(Comment<'a>),
+}
+impl<'a> Node<'a> {
+    pub(crate) fn from_ast(ast: AST<'a>) -> Result<Node<'a>, ClingoError> {
+        match ast.get_type()? {
+            ASTType::Id => Ok(Node::Id(Id { ast })),
+            ASTType::Variable => Ok(Node::Variable(Variable { ast })),
+            ASTType::SymbolicTerm => Ok(Node::SymbolicTerm(SymbolicTerm { ast })),
+            ASTType::UnaryOperation => Ok(Node::UnaryOperation(UnaryOperation { ast })),
+            ASTType::BinaryOperation => Ok(Node::BinaryOperation(BinaryOperation { ast })),
+            ASTType::Interval => Ok(Node::Interval(Interval { ast })),
+            ASTType::Function => Ok(Node::Function(Function { ast })),
+            ASTType::Pool => Ok(Node::Pool(Pool { ast })),
+            ASTType::BooleanConstant => Ok(Node::BooleanConstant(BooleanConstant { ast })),
+            ASTType::SymbolicAtom => Ok(Node::SymbolicAtom(SymbolicAtom { ast })),
+            ASTType::Comparison => Ok(Node::Comparison(Comparison { ast })),
+            ASTType::Guard => Ok(Node::Guard(Guard { ast })),
+            ASTType::ConditionalLiteral => Ok(Node::ConditionalLiteral(ConditionalLiteral { ast })),
+            ASTType::Aggregate => Ok(Node::Aggregate(Aggregate { ast })),
+            ASTType::BodyAggregateElement => {
+                Ok(Node::BodyAggregateElement(BodyAggregateElement { ast }))
+            }
+            ASTType::BodyAggregate => Ok(Node::BodyAggregate(BodyAggregate { ast })),
+            ASTType::HeadAggregateElement => {
+                Ok(Node::HeadAggregateElement(HeadAggregateElement { ast }))
+            }
+            ASTType::HeadAggregate => Ok(Node::HeadAggregate(HeadAggregate { ast })),
+            ASTType::Disjunction => Ok(Node::Disjunction(Disjunction { ast })),
+            ASTType::TheorySequence => Ok(Node::TheorySequence(TheorySequence { ast })),
+            ASTType::TheoryFunction => Ok(Node::TheoryFunction(TheoryFunction { ast })),
+            ASTType::TheoryUnparsedTermElement => {
+                Ok(Node::TheoryUnparsedTermElement(TheoryUnparsedTermElement {
+                    ast,
+                }))
+            }
+            ASTType::TheoryUnparsedTerm => Ok(Node::TheoryUnparsedTerm(TheoryUnparsedTerm { ast })),
+            ASTType::TheoryGuard => Ok(Node::TheoryGuard(TheoryGuard { ast })),
+            ASTType::TheoryAtomElement => Ok(Node::TheoryAtomElement(TheoryAtomElement { ast })),
+            ASTType::TheoryAtom => Ok(Node::TheoryAtom(TheoryAtom { ast })),
+            ASTType::Literal => Ok(Node::Literal(BasicLiteral { ast })),
+            ASTType::TheoryOperatorDefinition => {
+                Ok(Node::TheoryOperatorDefinition(TheoryOperatorDefinition {
+                    ast,
+                }))
+            }
+            ASTType::TheoryTermDefinition => {
+                Ok(Node::TheoryTermDefinition(TheoryTermDefinition { ast }))
+            }
+            ASTType::TheoryGuardDefinition => {
+                Ok(Node::TheoryGuardDefinition(TheoryGuardDefinition { ast }))
+            }
+            ASTType::TheoryAtomDefinition => {
+                Ok(Node::TheoryAtomDefinition(TheoryAtomDefinition { ast }))
+            }
+            ASTType::Rule => Ok(Node::Rule(Rule { ast })),
+            ASTType::Definition => Ok(Node::Definition(Definition { ast })),
+            ASTType::ShowSignature => Ok(Node::ShowSignature(ShowSignature { ast })),
+            ASTType::ShowTerm => Ok(Node::ShowTerm(ShowTerm { ast })),
+            ASTType::Minimize => Ok(Node::Minimize(Minimize { ast })),
+            ASTType::Script => Ok(Node::Script(Script { ast })),
+            ASTType::Program => Ok(Node::Program(Program { ast })),
+            ASTType::External => Ok(Node::External(External { ast })),
+            ASTType::Edge => Ok(Node::Edge(Edge { ast })),
+            ASTType::Heuristic => Ok(Node::Heuristic(Heuristic { ast })),
+            ASTType::ProjectAtom => Ok(Node::ProjectAtom(ProjectAtom { ast })),
+            ASTType::ProjectSignature => Ok(Node::ProjectSignature(ProjectSignature { ast })),
+            ASTType::Defined => Ok(Node::Defined(Defined { ast })),
+            ASTType::TheoryDefinition => Ok(Node::TheoryDefinition(TheoryDefinition { ast })),
+            ASTType::Comment => Ok(Node::Comment(Comment { ast })),
+        }
+    }
+    pub(crate) fn ast(&self) -> &AST<'a> {
+        match self {
+            Node::Id(x) => &x.ast,
+            Node::Variable(x) => &x.ast,
+            Node::SymbolicTerm(x) => &x.ast,
+            Node::UnaryOperation(x) => &x.ast,
+            Node::BinaryOperation(x) => &x.ast,
+            Node::Interval(x) => &x.ast,
+            Node::Function(x) => &x.ast,
+            Node::Pool(x) => &x.ast,
+            Node::BooleanConstant(x) => &x.ast,
+            Node::SymbolicAtom(x) => &x.ast,
+            Node::Comparison(x) => &x.ast,
+            Node::Guard(x) => &x.ast,
+            Node::ConditionalLiteral(x) => &x.ast,
+            Node::Aggregate(x) => &x.ast,
+            Node::BodyAggregateElement(x) => &x.ast,
+            Node::BodyAggregate(x) => &x.ast,
+            Node::HeadAggregateElement(x) => &x.ast,
+            Node::HeadAggregate(x) => &x.ast,
+            Node::Disjunction(x) => &x.ast,
+            Node::TheorySequence(x) => &x.ast,
+            Node::TheoryFunction(x) => &x.ast,
+            Node::TheoryUnparsedTermElement(x) => &x.ast,
+            Node::TheoryUnparsedTerm(x) => &x.ast,
+            Node::TheoryGuard(x) => &x.ast,
+            Node::TheoryAtomElement(x) => &x.ast,
+            Node::TheoryAtom(x) => &x.ast,
+            Node::Literal(x) => &x.ast,
+            Node::TheoryOperatorDefinition(x) => &x.ast,
+            Node::TheoryTermDefinition(x) => &x.ast,
+            Node::TheoryGuardDefinition(x) => &x.ast,
+            Node::TheoryAtomDefinition(x) => &x.ast,
+            Node::Rule(x) => &x.ast,
+            Node::Definition(x) => &x.ast,
+            Node::ShowSignature(x) => &x.ast,
+            Node::ShowTerm(x) => &x.ast,
+            Node::Minimize(x) => &x.ast,
+            Node::Script(x) => &x.ast,
+            Node::Program(x) => &x.ast,
+            Node::External(x) => &x.ast,
+            Node::Edge(x) => &x.ast,
+            Node::Heuristic(x) => &x.ast,
+            Node::ProjectAtom(x) => &x.ast,
+            Node::ProjectSignature(x) => &x.ast,
+            Node::Defined(x) => &x.ast,
+            Node::TheoryDefinition(x) => &x.ast,
+            Node::Comment(x) => &x.ast,
+        }
+    }
+    fn into_ast(self) -> AST<'a> {
+        match self {
+            Node::Id(x) => x.ast,
+            Node::Variable(x) => x.ast,
+            Node::SymbolicTerm(x) => x.ast,
+            Node::UnaryOperation(x) => x.ast,
+            Node::BinaryOperation(x) => x.ast,
+            Node::Interval(x) => x.ast,
+            Node::Function(x) => x.ast,
+            Node::Pool(x) => x.ast,
+            Node::BooleanConstant(x) => x.ast,
+            Node::SymbolicAtom(x) => x.ast,
+            Node::Comparison(x) => x.ast,
+            Node::Guard(x) => x.ast,
+            Node::ConditionalLiteral(x) => x.ast,
+            Node::Aggregate(x) => x.ast,
+            Node::BodyAggregateElement(x) => x.ast,
+            Node::BodyAggregate(x) => x.ast,
+            Node::HeadAggregateElement(x) => x.ast,
+            Node::HeadAggregate(x) => x.ast,
+            Node::Disjunction(x) => x.ast,
+            Node::TheorySequence(x) => x.ast,
+            Node::TheoryFunction(x) => x.ast,
+            Node::TheoryUnparsedTermElement(x) => x.ast,
+            Node::TheoryUnparsedTerm(x) => x.ast,
+            Node::TheoryGuard(x) => x.ast,
+            Node::TheoryAtomElement(x) => x.ast,
+            Node::TheoryAtom(x) => x.ast,
+            Node::Literal(x) => x.ast,
+            Node::TheoryOperatorDefinition(x) => x.ast,
+            Node::TheoryTermDefinition(x) => x.ast,
+            Node::TheoryGuardDefinition(x) => x.ast,
+            Node::TheoryAtomDefinition(x) => x.ast,
+            Node::Rule(x) => x.ast,
+            Node::Definition(x) => x.ast,
+            Node::ShowSignature(x) => x.ast,
+            Node::ShowTerm(x) => x.ast,
+            Node::Minimize(x) => x.ast,
+            Node::Script(x) => x.ast,
+            Node::Program(x) => x.ast,
+            Node::External(x) => x.ast,
+            Node::Edge(x) => x.ast,
+            Node::Heuristic(x) => x.ast,
+            Node::ProjectAtom(x) => x.ast,
+            Node::ProjectSignature(x) => x.ast,
+            Node::Defined(x) => x.ast,
+            Node::TheoryDefinition(x) => x.ast,
+            Node::Comment(x) => x.ast,
+        }
+    }
+    /// Get the child nodes in the order of the attributes of the node.
+    ///
+    /// Absent optional attributes are skipped.
+    pub fn children(&self) -> Result<Vec<Node<'a>>, ClingoError> {
+        let ast = self.ast();
+        let mut children = vec![];
+        for (attribute, attribute_type) in ast.child_attributes()? {
+            match attribute_type {
+                ASTAttributeType::AST => {
+                    children.push(Node::from_ast(ast.get_attribute_ast(attribute)?)?);
+                }
+                ASTAttributeType::OptionalAST => {
+                    if let Some(child) = ast.get_attribute_optional_ast(attribute)? {
+                        children.push(Node::from_ast(child)?);
+                    }
+                }
+                _ => {
+                    for child in ast.get_attribute_ast_array(attribute)? {
+                        children.push(Node::from_ast(child)?);
+                    }
+                }
+            }
+        }
+        Ok(children)
+    }
+    pub fn location(&self) -> Result<Option<Location>, ClingoError> {
+        if self.ast().has_attribute(ASTAttribute::Location)? {
+            Ok(Some(self.ast().location()?))
+        } else {
+            Ok(None)
+        }
+    }
+    pub fn to_string(&self) -> Result<String, ClingoError> {
+        self.ast().to_string()
+    }
+}
+impl<'a> fmt::Display for Node<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ast().fmt(f)
+    }
+}
+impl<'a> From<Id<'a>> for Node<'a> {
+    fn from(x: Id<'a>) -> Self {
+        Node::Id(x)
+    }
+}
+impl<'a> From<&Id<'a>> for Node<'a> {
+    fn from(x: &Id<'a>) -> Self {
+        Node::Id(Id { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Variable<'a>> for Node<'a> {
+    fn from(x: Variable<'a>) -> Self {
+        Node::Variable(x)
+    }
+}
+impl<'a> From<&Variable<'a>> for Node<'a> {
+    fn from(x: &Variable<'a>) -> Self {
+        Node::Variable(Variable { ast: x.ast.share() })
+    }
+}
+impl<'a> From<SymbolicTerm<'a>> for Node<'a> {
+    fn from(x: SymbolicTerm<'a>) -> Self {
+        Node::SymbolicTerm(x)
+    }
+}
+impl<'a> From<&SymbolicTerm<'a>> for Node<'a> {
+    fn from(x: &SymbolicTerm<'a>) -> Self {
+        Node::SymbolicTerm(SymbolicTerm { ast: x.ast.share() })
+    }
+}
+impl<'a> From<UnaryOperation<'a>> for Node<'a> {
+    fn from(x: UnaryOperation<'a>) -> Self {
+        Node::UnaryOperation(x)
+    }
+}
+impl<'a> From<&UnaryOperation<'a>> for Node<'a> {
+    fn from(x: &UnaryOperation<'a>) -> Self {
+        Node::UnaryOperation(UnaryOperation { ast: x.ast.share() })
+    }
+}
+impl<'a> From<BinaryOperation<'a>> for Node<'a> {
+    fn from(x: BinaryOperation<'a>) -> Self {
+        Node::BinaryOperation(x)
+    }
+}
+impl<'a> From<&BinaryOperation<'a>> for Node<'a> {
+    fn from(x: &BinaryOperation<'a>) -> Self {
+        Node::BinaryOperation(BinaryOperation { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Interval<'a>> for Node<'a> {
+    fn from(x: Interval<'a>) -> Self {
+        Node::Interval(x)
+    }
+}
+impl<'a> From<&Interval<'a>> for Node<'a> {
+    fn from(x: &Interval<'a>) -> Self {
+        Node::Interval(Interval { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Function<'a>> for Node<'a> {
+    fn from(x: Function<'a>) -> Self {
+        Node::Function(x)
+    }
+}
+impl<'a> From<&Function<'a>> for Node<'a> {
+    fn from(x: &Function<'a>) -> Self {
+        Node::Function(Function { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Pool<'a>> for Node<'a> {
+    fn from(x: Pool<'a>) -> Self {
+        Node::Pool(x)
+    }
+}
+impl<'a> From<&Pool<'a>> for Node<'a> {
+    fn from(x: &Pool<'a>) -> Self {
+        Node::Pool(Pool { ast: x.ast.share() })
+    }
+}
+impl<'a> From<BooleanConstant<'a>> for Node<'a> {
+    fn from(x: BooleanConstant<'a>) -> Self {
+        Node::BooleanConstant(x)
+    }
+}
+impl<'a> From<&BooleanConstant<'a>> for Node<'a> {
+    fn from(x: &BooleanConstant<'a>) -> Self {
+        Node::BooleanConstant(BooleanConstant { ast: x.ast.share() })
+    }
+}
+impl<'a> From<SymbolicAtom<'a>> for Node<'a> {
+    fn from(x: SymbolicAtom<'a>) -> Self {
+        Node::SymbolicAtom(x)
+    }
+}
+impl<'a> From<&SymbolicAtom<'a>> for Node<'a> {
+    fn from(x: &SymbolicAtom<'a>) -> Self {
+        Node::SymbolicAtom(SymbolicAtom { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Comparison<'a>> for Node<'a> {
+    fn from(x: Comparison<'a>) -> Self {
+        Node::Comparison(x)
+    }
+}
+impl<'a> From<&Comparison<'a>> for Node<'a> {
+    fn from(x: &Comparison<'a>) -> Self {
+        Node::Comparison(Comparison { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Guard<'a>> for Node<'a> {
+    fn from(x: Guard<'a>) -> Self {
+        Node::Guard(x)
+    }
+}
+impl<'a> From<&Guard<'a>> for Node<'a> {
+    fn from(x: &Guard<'a>) -> Self {
+        Node::Guard(Guard { ast: x.ast.share() })
+    }
+}
+impl<'a> From<ConditionalLiteral<'a>> for Node<'a> {
+    fn from(x: ConditionalLiteral<'a>) -> Self {
+        Node::ConditionalLiteral(x)
+    }
+}
+impl<'a> From<&ConditionalLiteral<'a>> for Node<'a> {
+    fn from(x: &ConditionalLiteral<'a>) -> Self {
+        Node::ConditionalLiteral(ConditionalLiteral { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Aggregate<'a>> for Node<'a> {
+    fn from(x: Aggregate<'a>) -> Self {
+        Node::Aggregate(x)
+    }
+}
+impl<'a> From<&Aggregate<'a>> for Node<'a> {
+    fn from(x: &Aggregate<'a>) -> Self {
+        Node::Aggregate(Aggregate { ast: x.ast.share() })
+    }
+}
+impl<'a> From<BodyAggregateElement<'a>> for Node<'a> {
+    fn from(x: BodyAggregateElement<'a>) -> Self {
+        Node::BodyAggregateElement(x)
+    }
+}
+impl<'a> From<&BodyAggregateElement<'a>> for Node<'a> {
+    fn from(x: &BodyAggregateElement<'a>) -> Self {
+        Node::BodyAggregateElement(BodyAggregateElement { ast: x.ast.share() })
+    }
+}
+impl<'a> From<BodyAggregate<'a>> for Node<'a> {
+    fn from(x: BodyAggregate<'a>) -> Self {
+        Node::BodyAggregate(x)
+    }
+}
+impl<'a> From<&BodyAggregate<'a>> for Node<'a> {
+    fn from(x: &BodyAggregate<'a>) -> Self {
+        Node::BodyAggregate(BodyAggregate { ast: x.ast.share() })
+    }
+}
+impl<'a> From<HeadAggregateElement<'a>> for Node<'a> {
+    fn from(x: HeadAggregateElement<'a>) -> Self {
+        Node::HeadAggregateElement(x)
+    }
+}
+impl<'a> From<&HeadAggregateElement<'a>> for Node<'a> {
+    fn from(x: &HeadAggregateElement<'a>) -> Self {
+        Node::HeadAggregateElement(HeadAggregateElement { ast: x.ast.share() })
+    }
+}
+impl<'a> From<HeadAggregate<'a>> for Node<'a> {
+    fn from(x: HeadAggregate<'a>) -> Self {
+        Node::HeadAggregate(x)
+    }
+}
+impl<'a> From<&HeadAggregate<'a>> for Node<'a> {
+    fn from(x: &HeadAggregate<'a>) -> Self {
+        Node::HeadAggregate(HeadAggregate { ast: x.ast.share() })
+    }
+}
+impl<'a> From<Disjunction<'a>> for Node<'a> {
+    fn from(x: Disjunction<'a>) -> Self {
+        Node::Disjunction(x)
+    }
+}
+impl<'a> From<&Disjunction<'a>> for Node<'a> {
+    fn from(x: &Disjunction<'a>) -> Self {
+        Node::Disjunction(Disjunction { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheorySequence<'a>> for Node<'a> {
+    fn from(x: TheorySequence<'a>) -> Self {
+        Node::TheorySequence(x)
+    }
+}
+impl<'a> From<&TheorySequence<'a>> for Node<'a> {
+    fn from(x: &TheorySequence<'a>) -> Self {
+        Node::TheorySequence(TheorySequence { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryFunction<'a>> for Node<'a> {
+    fn from(x: TheoryFunction<'a>) -> Self {
+        Node::TheoryFunction(x)
+    }
+}
+impl<'a> From<&TheoryFunction<'a>> for Node<'a> {
+    fn from(x: &TheoryFunction<'a>) -> Self {
+        Node::TheoryFunction(TheoryFunction { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryUnparsedTermElement<'a>> for Node<'a> {
+    fn from(x: TheoryUnparsedTermElement<'a>) -> Self {
+        Node::TheoryUnparsedTermElement(x)
+    }
+}
+impl<'a> From<&TheoryUnparsedTermElement<'a>> for Node<'a> {
+    fn from(x: &TheoryUnparsedTermElement<'a>) -> Self {
+        Node::TheoryUnparsedTermElement(TheoryUnparsedTermElement { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryUnparsedTerm<'a>> for Node<'a> {
+    fn from(x: TheoryUnparsedTerm<'a>) -> Self {
+        Node::TheoryUnparsedTerm(x)
+    }
+}
+impl<'a> From<&TheoryUnparsedTerm<'a>> for Node<'a> {
+    fn from(x: &TheoryUnparsedTerm<'a>) -> Self {
+        Node::TheoryUnparsedTerm(TheoryUnparsedTerm { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryGuard<'a>> for Node<'a> {
+    fn from(x: TheoryGuard<'a>) -> Self {
+        Node::TheoryGuard(x)
+    }
+}
+impl<'a> From<&TheoryGuard<'a>> for Node<'a> {
+    fn from(x: &TheoryGuard<'a>) -> Self {
+        Node::TheoryGuard(TheoryGuard { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryAtomElement<'a>> for Node<'a> {
+    fn from(x: TheoryAtomElement<'a>) -> Self {
+        Node::TheoryAtomElement(x)
+    }
+}
+impl<'a> From<&TheoryAtomElement<'a>> for Node<'a> {
+    fn from(x: &TheoryAtomElement<'a>) -> Self {
+        Node::TheoryAtomElement(TheoryAtomElement { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryAtom<'a>> for Node<'a> {
+    fn from(x: TheoryAtom<'a>) -> Self {
+        Node::TheoryAtom(x)
+    }
+}
+impl<'a> From<&TheoryAtom<'a>> for Node<'a> {
+    fn from(x: &TheoryAtom<'a>) -> Self {
+        Node::TheoryAtom(TheoryAtom { ast: x.ast.share() })
+    }
+}
+impl<'a> From<BasicLiteral<'a>> for Node<'a> {
+    fn from(x: BasicLiteral<'a>) -> Self {
+        Node::Literal(x)
+    }
+}
+impl<'a> From<&BasicLiteral<'a>> for Node<'a> {
+    fn from(x: &BasicLiteral<'a>) -> Self {
+        Node::Literal(BasicLiteral { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryOperatorDefinition<'a>> for Node<'a> {
+    fn from(x: TheoryOperatorDefinition<'a>) -> Self {
+        Node::TheoryOperatorDefinition(x)
+    }
+}
+impl<'a> From<&TheoryOperatorDefinition<'a>> for Node<'a> {
+    fn from(x: &TheoryOperatorDefinition<'a>) -> Self {
+        Node::TheoryOperatorDefinition(TheoryOperatorDefinition { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryTermDefinition<'a>> for Node<'a> {
+    fn from(x: TheoryTermDefinition<'a>) -> Self {
+        Node::TheoryTermDefinition(x)
+    }
+}
+impl<'a> From<&TheoryTermDefinition<'a>> for Node<'a> {
+    fn from(x: &TheoryTermDefinition<'a>) -> Self {
+        Node::TheoryTermDefinition(TheoryTermDefinition { ast: x.ast.share() })
+    }
+}
+impl<'a> From<TheoryGuardDefinition<'a>> for Node<'a> {
+    fn from(x: TheoryGuardDefinition<'a>) -> Self {
+        Node::TheoryGuardDefinition(x)
     }
 }
-#[derive(Debug, Clone)]
-pub struct ConditionalLiteral<'a> {
-    ast: AST<'a>,
+impl<'a> From<&TheoryGuardDefinition<'a>> for Node<'a> {
+    fn from(x: &TheoryGuardDefinition<'a>) -> Self {
+        Node::TheoryGuardDefinition(TheoryGuardDefinition { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Aggregate<'a> {
-    ast: AST<'a>,
+impl<'a> From<TheoryAtomDefinition<'a>> for Node<'a> {
+    fn from(x: TheoryAtomDefinition<'a>) -> Self {
+        Node::TheoryAtomDefinition(x)
+    }
 }
-impl<'a> Aggregate<'a> {
-    pub fn to_string(&self) -> Result<String, ClingoError> {
-        self.ast.to_string()
+impl<'a> From<&TheoryAtomDefinition<'a>> for Node<'a> {
+    fn from(x: &TheoryAtomDefinition<'a>) -> Self {
+        Node::TheoryAtomDefinition(TheoryAtomDefinition { ast: x.ast.share() })
     }
 }
-#[derive(Debug, Clone)]
-pub struct BodyAggregateElement<'a> {
-    ast: AST<'a>,
+impl<'a> From<Rule<'a>> for Node<'a> {
+    fn from(x: Rule<'a>) -> Self {
+        Node::Rule(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct BodyAggregate<'a> {
-    ast: AST<'a>,
+impl<'a> From<&Rule<'a>> for Node<'a> {
+    fn from(x: &Rule<'a>) -> Self {
+        Node::Rule(Rule { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct HeadAggregateElement<'a> {
-    ast: AST<'a>,
+impl<'a> From<Definition<'a>> for Node<'a> {
+    fn from(x: Definition<'a>) -> Self {
+        Node::Definition(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct HeadAggregate<'a> {
-    ast: AST<'a>,
+impl<'a> From<&Definition<'a>> for Node<'a> {
+    fn from(x: &Definition<'a>) -> Self {
+        Node::Definition(Definition { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Disjunction<'a> {
-    ast: AST<'a>,
+impl<'a> From<ShowSignature<'a>> for Node<'a> {
+    fn from(x: ShowSignature<'a>) -> Self {
+        Node::ShowSignature(x)
+    }
 }
-
-#[derive(Debug, Clone)]
-pub struct DisjointElement<'a> {
-    ast: AST<'a>,
+impl<'a> From<&ShowSignature<'a>> for Node<'a> {
+    fn from(x: &ShowSignature<'a>) -> Self {
+        Node::ShowSignature(ShowSignature { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Disjoint<'a> {
-    ast: AST<'a>,
+impl<'a> From<ShowTerm<'a>> for Node<'a> {
+    fn from(x: ShowTerm<'a>) -> Self {
+        Node::ShowTerm(x)
+    }
 }
-
-#[derive(Debug, Clone)]
-pub struct TheorySequence<'a> {
-    ast: AST<'a>,
+impl<'a> From<&ShowTerm<'a>> for Node<'a> {
+    fn from(x: &ShowTerm<'a>) -> Self {
+        Node::ShowTerm(ShowTerm { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryFunction<'a> {
-    ast: AST<'a>,
+impl<'a> From<Minimize<'a>> for Node<'a> {
+    fn from(x: Minimize<'a>) -> Self {
+        Node::Minimize(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryUnparsedTermElement<'a> {
-    ast: AST<'a>,
+impl<'a> From<&Minimize<'a>> for Node<'a> {
+    fn from(x: &Minimize<'a>) -> Self {
+        Node::Minimize(Minimize { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryUnparsedTerm<'a> {
-    ast: AST<'a>,
+impl<'a> From<Script<'a>> for Node<'a> {
+    fn from(x: Script<'a>) -> Self {
+        Node::Script(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryGuard<'a> {
-    pub(crate) ast: AST<'a>,
+impl<'a> From<&Script<'a>> for Node<'a> {
+    fn from(x: &Script<'a>) -> Self {
+        Node::Script(Script { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryAtomElement<'a> {
-    ast: AST<'a>,
+impl<'a> From<Program<'a>> for Node<'a> {
+    fn from(x: Program<'a>) -> Self {
+        Node::Program(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryAtom<'a> {
-    ast: AST<'a>,
+impl<'a> From<&Program<'a>> for Node<'a> {
+    fn from(x: &Program<'a>) -> Self {
+        Node::Program(Program { ast: x.ast.share() })
+    }
 }
-impl<'a> TheoryAtom<'a> {
-    pub fn location(&self) -> Location {
-        self.ast.location().unwrap()
+impl<'a> From<External<'a>> for Node<'a> {
+    fn from(x: External<'a>) -> Self {
+        Node::External(x)
     }
-    pub fn to_string(&self) -> Result<String, ClingoError> {
-        self.ast.to_string()
+}
+impl<'a> From<&External<'a>> for Node<'a> {
+    fn from(x: &External<'a>) -> Self {
+        Node::External(External { ast: x.ast.share() })
     }
-    pub fn term(&self) -> Term {
-        self.ast.term()
+}
+impl<'a> From<Edge<'a>> for Node<'a> {
+    fn from(x: Edge<'a>) -> Self {
+        Node::Edge(x)
     }
-    pub fn set_term(&mut self, term: Term) {
-        self.ast.set_term(term)
+}
+impl<'a> From<&Edge<'a>> for Node<'a> {
+    fn from(x: &Edge<'a>) -> Self {
+        Node::Edge(Edge { ast: x.ast.share() })
     }
-    pub fn guard(&self) -> TheoryGuard {
-        self.ast.guard()
+}
+impl<'a> From<Heuristic<'a>> for Node<'a> {
+    fn from(x: Heuristic<'a>) -> Self {
+        Node::Heuristic(x)
     }
-    pub fn elements(&self) -> &[TheoryAtomElement] {
-        self.ast.elements()
+}
+impl<'a> From<&Heuristic<'a>> for Node<'a> {
+    fn from(x: &Heuristic<'a>) -> Self {
+        Node::Heuristic(Heuristic { ast: x.ast.share() })
     }
 }
-#[derive(Debug, Clone)]
-pub struct AtomicLiteral<'a> {
-    ast: AST<'a>,
+impl<'a> From<ProjectAtom<'a>> for Node<'a> {
+    fn from(x: ProjectAtom<'a>) -> Self {
+        Node::ProjectAtom(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct BasicLiteral<'a> {
-    ast: AST<'a>,
+impl<'a> From<&ProjectAtom<'a>> for Node<'a> {
+    fn from(x: &ProjectAtom<'a>) -> Self {
+        Node::ProjectAtom(ProjectAtom { ast: x.ast.share() })
+    }
 }
-impl<'a> BasicLiteral<'a> {
-    pub fn to_string(&self) -> Result<String, ClingoError> {
-        self.ast.to_string()
+impl<'a> From<ProjectSignature<'a>> for Node<'a> {
+    fn from(x: ProjectSignature<'a>) -> Self {
+        Node::ProjectSignature(x)
     }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryOperatorDefinition<'a> {
-    ast: AST<'a>,
+impl<'a> From<&ProjectSignature<'a>> for Node<'a> {
+    fn from(x: &ProjectSignature<'a>) -> Self {
+        Node::ProjectSignature(ProjectSignature { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryTermDefinition<'a> {
-    ast: AST<'a>,
+impl<'a> From<Defined<'a>> for Node<'a> {
+    fn from(x: Defined<'a>) -> Self {
+        Node::Defined(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryGuardDefinition<'a> {
-    ast: AST<'a>,
+impl<'a> From<&Defined<'a>> for Node<'a> {
+    fn from(x: &Defined<'a>) -> Self {
+        Node::Defined(Defined { ast: x.ast.share() })
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryAtomDefinition<'a> {
-    ast: AST<'a>,
+impl<'a> From<TheoryDefinition<'a>> for Node<'a> {
+    fn from(x: TheoryDefinition<'a>) -> Self {
+        Node::TheoryDefinition(x)
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Rule<'a> {
-    ast: AST<'a>,
+impl<'a> From<&TheoryDefinition<'a>> for Node<'a> {
+    fn from(x: &TheoryDefinition<'a>) -> Self {
+        Node::TheoryDefinition(TheoryDefinition { ast: x.ast.share() })
+    }
 }
-impl<'a> Rule<'a> {
-    pub fn location(&self) -> Location {
-        self.ast.location().unwrap()
+impl<'a> From<Comment<'a>> for Node<'a> {
+    fn from(x: Comment<'a>) -> Self {
+        Node::Comment(x)
     }
-    pub fn body(&self) -> Body {
-        self.ast.body()
+}
+impl<'a> From<&Comment<'a>> for Node<'a> {
+    fn from(x: &Comment<'a>) -> Self {
+        Node::Comment(Comment { ast: x.ast.share() })
     }
-    pub fn head(&self) -> Head {
-        self.ast.head()
+}
+impl<'a> TryFrom<&Statement<'a>> for Node<'a> {
+    type Error = ClingoError;
+
+    fn try_from(x: &Statement<'a>) -> Result<Self, Self::Error> {
+        Node::from_ast(x.ast.share())
     }
 }
-#[derive(Debug, Clone)]
-pub struct Definition<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<&Term<'a>> for Node<'a> {
+    type Error = ClingoError;
+
+    fn try_from(x: &Term<'a>) -> Result<Self, Self::Error> {
+        Node::from_ast(x.ast.share())
+    }
 }
-#[derive(Debug, Clone)]
-pub struct ShowSignature<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<&Literal<'a>> for Node<'a> {
+    type Error = ClingoError;
+
+    fn try_from(x: &Literal<'a>) -> Result<Self, Self::Error> {
+        Node::from_ast(x.ast.share())
+    }
 }
-#[derive(Debug, Clone)]
-pub struct ShowTerm<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<&Head<'a>> for Node<'a> {
+    type Error = ClingoError;
+
+    fn try_from(x: &Head<'a>) -> Result<Self, Self::Error> {
+        Node::from_ast(x.ast.share())
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Minimize<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<&BodyLiteral<'a>> for Node<'a> {
+    type Error = ClingoError;
+
+    fn try_from(x: &BodyLiteral<'a>) -> Result<Self, Self::Error> {
+        Node::from_ast(x.ast.share())
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Script<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<&TheoryTerm<'a>> for Node<'a> {
+    type Error = ClingoError;
+
+    fn try_from(x: &TheoryTerm<'a>) -> Result<Self, Self::Error> {
+        Node::from_ast(x.ast.share())
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Program<'a> {
-    pub(crate) ast: AST<'a>,
+impl<'a> TryFrom<Node<'a>> for Statement<'a> {
+    type Error = ClingoError;
+
+    fn try_from(node: Node<'a>) -> Result<Self, Self::Error> {
+        match node {
+            Node::Rule(_)
+            | Node::Definition(_)
+            | Node::ShowSignature(_)
+            | Node::ShowTerm(_)
+            | Node::Minimize(_)
+            | Node::Script(_)
+            | Node::Program(_)
+            | Node::External(_)
+            | Node::Edge(_)
+            | Node::Heuristic(_)
+            | Node::ProjectAtom(_)
+            | Node::ProjectSignature(_)
+            | Node::Defined(_)
+            | Node::TheoryDefinition(_)
+            | Node::Comment(_) => Ok(Statement {
+                ast: node.into_ast(),
+            }),
+            _ => Err(ClingoError::new_external("The node is not a statement.")),
+        }
+    }
 }
-#[derive(Debug, Clone)]
-pub struct External<'a> {
-    pub(crate) ast: AST<'a>,
+impl<'a> TryFrom<Node<'a>> for Term<'a> {
+    type Error = ClingoError;
+
+    fn try_from(node: Node<'a>) -> Result<Self, Self::Error> {
+        match node {
+            Node::Variable(_)
+            | Node::SymbolicTerm(_)
+            | Node::UnaryOperation(_)
+            | Node::BinaryOperation(_)
+            | Node::Interval(_)
+            | Node::Function(_)
+            | Node::Pool(_) => Ok(Term {
+                ast: node.into_ast(),
+            }),
+            _ => Err(ClingoError::new_external("The node is not a term.")),
+        }
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Edge<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<Node<'a>> for TheoryTerm<'a> {
+    type Error = ClingoError;
+
+    fn try_from(node: Node<'a>) -> Result<Self, Self::Error> {
+        match node {
+            Node::SymbolicTerm(_)
+            | Node::Variable(_)
+            | Node::TheorySequence(_)
+            | Node::TheoryFunction(_)
+            | Node::TheoryUnparsedTerm(_) => Ok(TheoryTerm {
+                ast: node.into_ast(),
+            }),
+            _ => Err(ClingoError::new_external("The node is not a theory term.")),
+        }
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Heuristic<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<Node<'a>> for Literal<'a> {
+    type Error = ClingoError;
+
+    fn try_from(node: Node<'a>) -> Result<Self, Self::Error> {
+        match node {
+            Node::Literal(_) => Ok(Literal {
+                ast: node.into_ast(),
+            }),
+            _ => Err(ClingoError::new_external("The node is not a literal.")),
+        }
+    }
 }
-#[derive(Debug, Clone)]
-pub struct ProjectAtom<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<Node<'a>> for Head<'a> {
+    type Error = ClingoError;
+
+    fn try_from(node: Node<'a>) -> Result<Self, Self::Error> {
+        match node {
+            Node::Literal(_)
+            | Node::Aggregate(_)
+            | Node::HeadAggregate(_)
+            | Node::Disjunction(_)
+            | Node::TheoryAtom(_) => Ok(Head {
+                ast: node.into_ast(),
+            }),
+            _ => Err(ClingoError::new_external("The node is not a head.")),
+        }
+    }
 }
-#[derive(Debug, Clone)]
-pub struct ProjectSignature<'a> {
-    ast: AST<'a>,
+impl<'a> TryFrom<Node<'a>> for BodyLiteral<'a> {
+    type Error = ClingoError;
+
+    fn try_from(node: Node<'a>) -> Result<Self, Self::Error> {
+        match node {
+            Node::Literal(_) | Node::ConditionalLiteral(_) => Ok(BodyLiteral {
+                ast: node.into_ast(),
+            }),
+            _ => Err(ClingoError::new_external("The node is not a body literal.")),
+        }
+    }
 }
-#[derive(Debug, Clone)]
-pub struct Defined<'a> {
-    ast: AST<'a>,
+/// Visitor for the nodes of the abstract syntax tree.
+///
+/// There is one method per node type.
+/// By default each method visits the children of the node,
+/// implementations override the methods for the node types they are interested in
+/// and call [`visit_children()`](#method.visit_children) to continue the traversal below such a node.
+///
+/// **Example:**
+///
+/// ```ignore
+/// struct VariableCollector(Vec<String>);
+/// impl<'a> Visitor<'a> for VariableCollector {
+///     fn visit_variable(&mut self, variable: &Variable<'a>) -> Result<(), ClingoError> {
+///         self.0.push(variable.name()?);
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Visitor<'a> {
+    /// Dispatch the node to the method for its type.
+    fn visit(&mut self, node: &Node<'a>) -> Result<(), ClingoError> {
+        match node {
+            Node::Id(x) => self.visit_id(x),
+            Node::Variable(x) => self.visit_variable(x),
+            Node::SymbolicTerm(x) => self.visit_symbolic_term(x),
+            Node::UnaryOperation(x) => self.visit_unary_operation(x),
+            Node::BinaryOperation(x) => self.visit_binary_operation(x),
+            Node::Interval(x) => self.visit_interval(x),
+            Node::Function(x) => self.visit_function(x),
+            Node::Pool(x) => self.visit_pool(x),
+            Node::BooleanConstant(x) => self.visit_boolean_constant(x),
+            Node::SymbolicAtom(x) => self.visit_symbolic_atom(x),
+            Node::Comparison(x) => self.visit_comparison(x),
+            Node::Guard(x) => self.visit_guard(x),
+            Node::ConditionalLiteral(x) => self.visit_conditional_literal(x),
+            Node::Aggregate(x) => self.visit_aggregate(x),
+            Node::BodyAggregateElement(x) => self.visit_body_aggregate_element(x),
+            Node::BodyAggregate(x) => self.visit_body_aggregate(x),
+            Node::HeadAggregateElement(x) => self.visit_head_aggregate_element(x),
+            Node::HeadAggregate(x) => self.visit_head_aggregate(x),
+            Node::Disjunction(x) => self.visit_disjunction(x),
+            Node::TheorySequence(x) => self.visit_theory_sequence(x),
+            Node::TheoryFunction(x) => self.visit_theory_function(x),
+            Node::TheoryUnparsedTermElement(x) => self.visit_theory_unparsed_term_element(x),
+            Node::TheoryUnparsedTerm(x) => self.visit_theory_unparsed_term(x),
+            Node::TheoryGuard(x) => self.visit_theory_guard(x),
+            Node::TheoryAtomElement(x) => self.visit_theory_atom_element(x),
+            Node::TheoryAtom(x) => self.visit_theory_atom(x),
+            Node::Literal(x) => self.visit_literal(x),
+            Node::TheoryOperatorDefinition(x) => self.visit_theory_operator_definition(x),
+            Node::TheoryTermDefinition(x) => self.visit_theory_term_definition(x),
+            Node::TheoryGuardDefinition(x) => self.visit_theory_guard_definition(x),
+            Node::TheoryAtomDefinition(x) => self.visit_theory_atom_definition(x),
+            Node::Rule(x) => self.visit_rule(x),
+            Node::Definition(x) => self.visit_definition(x),
+            Node::ShowSignature(x) => self.visit_show_signature(x),
+            Node::ShowTerm(x) => self.visit_show_term(x),
+            Node::Minimize(x) => self.visit_minimize(x),
+            Node::Script(x) => self.visit_script(x),
+            Node::Program(x) => self.visit_program(x),
+            Node::External(x) => self.visit_external(x),
+            Node::Edge(x) => self.visit_edge(x),
+            Node::Heuristic(x) => self.visit_heuristic(x),
+            Node::ProjectAtom(x) => self.visit_project_atom(x),
+            Node::ProjectSignature(x) => self.visit_project_signature(x),
+            Node::Defined(x) => self.visit_defined(x),
+            Node::TheoryDefinition(x) => self.visit_theory_definition(x),
+            Node::Comment(x) => self.visit_comment(x),
+        }
+    }
+    /// Visit the statement.
+    fn visit_statement(&mut self, stm: &Statement<'a>) -> Result<(), ClingoError> {
+        self.visit(&Node::try_from(stm)?)
+    }
+    /// Visit all children of the node.
+    fn visit_children(&mut self, node: &Node<'a>) -> Result<(), ClingoError> {
+        for child in node.children()? {
+            self.visit(&child)?;
+        }
+        Ok(())
+    }
+    fn visit_id(&mut self, id: &Id<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&id.into())
+    }
+    fn visit_variable(&mut self, variable: &Variable<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&variable.into())
+    }
+    fn visit_symbolic_term(&mut self, symbolic_term: &SymbolicTerm<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&symbolic_term.into())
+    }
+    fn visit_unary_operation(
+        &mut self,
+        unary_operation: &UnaryOperation<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&unary_operation.into())
+    }
+    fn visit_binary_operation(
+        &mut self,
+        binary_operation: &BinaryOperation<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&binary_operation.into())
+    }
+    fn visit_interval(&mut self, interval: &Interval<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&interval.into())
+    }
+    fn visit_function(&mut self, function: &Function<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&function.into())
+    }
+    fn visit_pool(&mut self, pool: &Pool<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&pool.into())
+    }
+    fn visit_boolean_constant(
+        &mut self,
+        boolean_constant: &BooleanConstant<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&boolean_constant.into())
+    }
+    fn visit_symbolic_atom(&mut self, symbolic_atom: &SymbolicAtom<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&symbolic_atom.into())
+    }
+    fn visit_comparison(&mut self, comparison: &Comparison<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&comparison.into())
+    }
+    fn visit_guard(&mut self, guard: &Guard<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&guard.into())
+    }
+    fn visit_conditional_literal(
+        &mut self,
+        conditional_literal: &ConditionalLiteral<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&conditional_literal.into())
+    }
+    fn visit_aggregate(&mut self, aggregate: &Aggregate<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&aggregate.into())
+    }
+    fn visit_body_aggregate_element(
+        &mut self,
+        body_aggregate_element: &BodyAggregateElement<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&body_aggregate_element.into())
+    }
+    fn visit_body_aggregate(
+        &mut self,
+        body_aggregate: &BodyAggregate<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&body_aggregate.into())
+    }
+    fn visit_head_aggregate_element(
+        &mut self,
+        head_aggregate_element: &HeadAggregateElement<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&head_aggregate_element.into())
+    }
+    fn visit_head_aggregate(
+        &mut self,
+        head_aggregate: &HeadAggregate<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&head_aggregate.into())
+    }
+    fn visit_disjunction(&mut self, disjunction: &Disjunction<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&disjunction.into())
+    }
+    fn visit_theory_sequence(
+        &mut self,
+        theory_sequence: &TheorySequence<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_sequence.into())
+    }
+    fn visit_theory_function(
+        &mut self,
+        theory_function: &TheoryFunction<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_function.into())
+    }
+    fn visit_theory_unparsed_term_element(
+        &mut self,
+        theory_unparsed_term_element: &TheoryUnparsedTermElement<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_unparsed_term_element.into())
+    }
+    fn visit_theory_unparsed_term(
+        &mut self,
+        theory_unparsed_term: &TheoryUnparsedTerm<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_unparsed_term.into())
+    }
+    fn visit_theory_guard(&mut self, theory_guard: &TheoryGuard<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&theory_guard.into())
+    }
+    fn visit_theory_atom_element(
+        &mut self,
+        theory_atom_element: &TheoryAtomElement<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_atom_element.into())
+    }
+    fn visit_theory_atom(&mut self, theory_atom: &TheoryAtom<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&theory_atom.into())
+    }
+    fn visit_literal(&mut self, literal: &BasicLiteral<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&literal.into())
+    }
+    fn visit_theory_operator_definition(
+        &mut self,
+        theory_operator_definition: &TheoryOperatorDefinition<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_operator_definition.into())
+    }
+    fn visit_theory_term_definition(
+        &mut self,
+        theory_term_definition: &TheoryTermDefinition<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_term_definition.into())
+    }
+    fn visit_theory_guard_definition(
+        &mut self,
+        theory_guard_definition: &TheoryGuardDefinition<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_guard_definition.into())
+    }
+    fn visit_theory_atom_definition(
+        &mut self,
+        theory_atom_definition: &TheoryAtomDefinition<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_atom_definition.into())
+    }
+    fn visit_rule(&mut self, rule: &Rule<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&rule.into())
+    }
+    fn visit_definition(&mut self, definition: &Definition<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&definition.into())
+    }
+    fn visit_show_signature(
+        &mut self,
+        show_signature: &ShowSignature<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&show_signature.into())
+    }
+    fn visit_show_term(&mut self, show_term: &ShowTerm<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&show_term.into())
+    }
+    fn visit_minimize(&mut self, minimize: &Minimize<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&minimize.into())
+    }
+    fn visit_script(&mut self, script: &Script<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&script.into())
+    }
+    fn visit_program(&mut self, program: &Program<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&program.into())
+    }
+    fn visit_external(&mut self, external: &External<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&external.into())
+    }
+    fn visit_edge(&mut self, edge: &Edge<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&edge.into())
+    }
+    fn visit_heuristic(&mut self, heuristic: &Heuristic<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&heuristic.into())
+    }
+    fn visit_project_atom(&mut self, project_atom: &ProjectAtom<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&project_atom.into())
+    }
+    fn visit_project_signature(
+        &mut self,
+        project_signature: &ProjectSignature<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&project_signature.into())
+    }
+    fn visit_defined(&mut self, defined: &Defined<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&defined.into())
+    }
+    fn visit_theory_definition(
+        &mut self,
+        theory_definition: &TheoryDefinition<'a>,
+    ) -> Result<(), ClingoError> {
+        self.visit_children(&theory_definition.into())
+    }
+    fn visit_comment(&mut self, comment: &Comment<'a>) -> Result<(), ClingoError> {
+        self.visit_children(&comment.into())
+    }
 }
-#[derive(Debug, Clone)]
-pub struct TheoryDefinition<'a> {
-    ast: AST<'a>,
+/// Transformer for the nodes of the abstract syntax tree.
+///
+/// There is one method per node type, each returns the node that replaces the given one.
+/// By default each method transforms the children of the node,
+/// implementations override the methods for the node types they want to replace.
+/// The replacement must fit in the place of the original node,
+/// for example a term can only be replaced by another term.
+///
+/// The given nodes are not modified.
+/// If a child is replaced a copy of its parent node is created,
+/// unchanged parts of the tree are shared between the original and the transformed tree.
+/// Hence a transformer should not modify the nodes passed to it in place but
+/// [`clone()`](enum.Node.html#impl-Clone) them first.
+///
+/// The result of [`transform_statement()`](#method.transform_statement)
+/// can be added to a program with [`ProgramBuilder::add()`](struct.ProgramBuilder.html#method.add).
+///
+/// **Example:**
+///
+/// ```ignore
+/// struct Renamer;
+/// impl<'a> Transformer<'a> for Renamer {
+///     fn transform_variable(&mut self, variable: Variable<'a>) -> Result<Node<'a>, ClingoError> {
+///         let name = format!("{}_", variable.name()?);
+///         Ok(ast::variable(&variable.location()?, &name)?.into())
+///     }
+/// }
+/// ```
+pub trait Transformer<'a> {
+    /// Dispatch the node to the method for its type.
+    fn transform(&mut self, node: Node<'a>) -> Result<Node<'a>, ClingoError> {
+        match node {
+            Node::Id(x) => self.transform_id(x),
+            Node::Variable(x) => self.transform_variable(x),
+            Node::SymbolicTerm(x) => self.transform_symbolic_term(x),
+            Node::UnaryOperation(x) => self.transform_unary_operation(x),
+            Node::BinaryOperation(x) => self.transform_binary_operation(x),
+            Node::Interval(x) => self.transform_interval(x),
+            Node::Function(x) => self.transform_function(x),
+            Node::Pool(x) => self.transform_pool(x),
+            Node::BooleanConstant(x) => self.transform_boolean_constant(x),
+            Node::SymbolicAtom(x) => self.transform_symbolic_atom(x),
+            Node::Comparison(x) => self.transform_comparison(x),
+            Node::Guard(x) => self.transform_guard(x),
+            Node::ConditionalLiteral(x) => self.transform_conditional_literal(x),
+            Node::Aggregate(x) => self.transform_aggregate(x),
+            Node::BodyAggregateElement(x) => self.transform_body_aggregate_element(x),
+            Node::BodyAggregate(x) => self.transform_body_aggregate(x),
+            Node::HeadAggregateElement(x) => self.transform_head_aggregate_element(x),
+            Node::HeadAggregate(x) => self.transform_head_aggregate(x),
+            Node::Disjunction(x) => self.transform_disjunction(x),
+            Node::TheorySequence(x) => self.transform_theory_sequence(x),
+            Node::TheoryFunction(x) => self.transform_theory_function(x),
+            Node::TheoryUnparsedTermElement(x) => self.transform_theory_unparsed_term_element(x),
+            Node::TheoryUnparsedTerm(x) => self.transform_theory_unparsed_term(x),
+            Node::TheoryGuard(x) => self.transform_theory_guard(x),
+            Node::TheoryAtomElement(x) => self.transform_theory_atom_element(x),
+            Node::TheoryAtom(x) => self.transform_theory_atom(x),
+            Node::Literal(x) => self.transform_literal(x),
+            Node::TheoryOperatorDefinition(x) => self.transform_theory_operator_definition(x),
+            Node::TheoryTermDefinition(x) => self.transform_theory_term_definition(x),
+            Node::TheoryGuardDefinition(x) => self.transform_theory_guard_definition(x),
+            Node::TheoryAtomDefinition(x) => self.transform_theory_atom_definition(x),
+            Node::Rule(x) => self.transform_rule(x),
+            Node::Definition(x) => self.transform_definition(x),
+            Node::ShowSignature(x) => self.transform_show_signature(x),
+            Node::ShowTerm(x) => self.transform_show_term(x),
+            Node::Minimize(x) => self.transform_minimize(x),
+            Node::Script(x) => self.transform_script(x),
+            Node::Program(x) => self.transform_program(x),
+            Node::External(x) => self.transform_external(x),
+            Node::Edge(x) => self.transform_edge(x),
+            Node::Heuristic(x) => self.transform_heuristic(x),
+            Node::ProjectAtom(x) => self.transform_project_atom(x),
+            Node::ProjectSignature(x) => self.transform_project_signature(x),
+            Node::Defined(x) => self.transform_defined(x),
+            Node::TheoryDefinition(x) => self.transform_theory_definition(x),
+            Node::Comment(x) => self.transform_comment(x),
+        }
+    }
+    /// Transform the statement.
+    ///
+    /// Fails with [`ClingoError::ExternalError`](enum.ClingoError.html#variant.ExternalError)
+    /// if the statement is replaced by a node that is not a statement.
+    fn transform_statement(&mut self, stm: &Statement<'a>) -> Result<Statement<'a>, ClingoError> {
+        let node = self.transform(Node::try_from(stm)?)?;
+        Statement::try_from(node)
+    }
+    /// Transform all children of the node.
+    ///
+    /// Returns the node itself if no child was replaced
+    /// and a shallow copy with the replaced children otherwise.
+    fn transform_children(&mut self, node: Node<'a>) -> Result<Node<'a>, ClingoError> {
+        let ast = node.ast();
+        let mut copy: Option<AST<'a>> = None;
+        for (attribute, attribute_type) in ast.child_attributes()? {
+            match attribute_type {
+                ASTAttributeType::AST => {
+                    let child = ast.get_attribute_ast(attribute)?;
+                    let ptr = child.ptr;
+                    let new = self.transform(Node::from_ast(child)?)?;
+                    if new.ast().ptr != ptr {
+                        if copy.is_none() {
+                            copy = Some(ast.shallow_copy()?);
+                        }
+                        if let Some(copy) = &mut copy {
+                            copy.set_attribute_ast(attribute, new.ast())?;
+                        }
+                    }
+                }
+                ASTAttributeType::OptionalAST => {
+                    if let Some(child) = ast.get_attribute_optional_ast(attribute)? {
+                        let ptr = child.ptr;
+                        let new = self.transform(Node::from_ast(child)?)?;
+                        if new.ast().ptr != ptr {
+                            if copy.is_none() {
+                                copy = Some(ast.shallow_copy()?);
+                            }
+                            if let Some(copy) = &mut copy {
+                                copy.set_attribute_optional_ast(attribute, Some(new.ast()))?;
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    let mut changed = false;
+                    let mut news = vec![];
+                    for child in ast.get_attribute_ast_array(attribute)? {
+                        let ptr = child.ptr;
+                        let new = self.transform(Node::from_ast(child)?)?;
+                        changed |= new.ast().ptr != ptr;
+                        news.push(new);
+                    }
+                    if changed {
+                        if copy.is_none() {
+                            copy = Some(ast.shallow_copy()?);
+                        }
+                        if let Some(copy) = &mut copy {
+                            copy.set_attribute_ast_array(attribute, news.iter().map(|x| x.ast()))?;
+                        }
+                    }
+                }
+            }
+        }
+        match copy {
+            Some(copy) => Node::from_ast(copy),
+            None => Ok(node),
+        }
+    }
+    fn transform_id(&mut self, id: Id<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(id.into())
+    }
+    fn transform_variable(&mut self, variable: Variable<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(variable.into())
+    }
+    fn transform_symbolic_term(
+        &mut self,
+        symbolic_term: SymbolicTerm<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(symbolic_term.into())
+    }
+    fn transform_unary_operation(
+        &mut self,
+        unary_operation: UnaryOperation<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(unary_operation.into())
+    }
+    fn transform_binary_operation(
+        &mut self,
+        binary_operation: BinaryOperation<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(binary_operation.into())
+    }
+    fn transform_interval(&mut self, interval: Interval<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(interval.into())
+    }
+    fn transform_function(&mut self, function: Function<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(function.into())
+    }
+    fn transform_pool(&mut self, pool: Pool<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(pool.into())
+    }
+    fn transform_boolean_constant(
+        &mut self,
+        boolean_constant: BooleanConstant<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(boolean_constant.into())
+    }
+    fn transform_symbolic_atom(
+        &mut self,
+        symbolic_atom: SymbolicAtom<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(symbolic_atom.into())
+    }
+    fn transform_comparison(
+        &mut self,
+        comparison: Comparison<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(comparison.into())
+    }
+    fn transform_guard(&mut self, guard: Guard<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(guard.into())
+    }
+    fn transform_conditional_literal(
+        &mut self,
+        conditional_literal: ConditionalLiteral<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(conditional_literal.into())
+    }
+    fn transform_aggregate(&mut self, aggregate: Aggregate<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(aggregate.into())
+    }
+    fn transform_body_aggregate_element(
+        &mut self,
+        body_aggregate_element: BodyAggregateElement<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(body_aggregate_element.into())
+    }
+    fn transform_body_aggregate(
+        &mut self,
+        body_aggregate: BodyAggregate<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(body_aggregate.into())
+    }
+    fn transform_head_aggregate_element(
+        &mut self,
+        head_aggregate_element: HeadAggregateElement<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(head_aggregate_element.into())
+    }
+    fn transform_head_aggregate(
+        &mut self,
+        head_aggregate: HeadAggregate<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(head_aggregate.into())
+    }
+    fn transform_disjunction(
+        &mut self,
+        disjunction: Disjunction<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(disjunction.into())
+    }
+    fn transform_theory_sequence(
+        &mut self,
+        theory_sequence: TheorySequence<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_sequence.into())
+    }
+    fn transform_theory_function(
+        &mut self,
+        theory_function: TheoryFunction<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_function.into())
+    }
+    fn transform_theory_unparsed_term_element(
+        &mut self,
+        theory_unparsed_term_element: TheoryUnparsedTermElement<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_unparsed_term_element.into())
+    }
+    fn transform_theory_unparsed_term(
+        &mut self,
+        theory_unparsed_term: TheoryUnparsedTerm<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_unparsed_term.into())
+    }
+    fn transform_theory_guard(
+        &mut self,
+        theory_guard: TheoryGuard<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_guard.into())
+    }
+    fn transform_theory_atom_element(
+        &mut self,
+        theory_atom_element: TheoryAtomElement<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_atom_element.into())
+    }
+    fn transform_theory_atom(
+        &mut self,
+        theory_atom: TheoryAtom<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_atom.into())
+    }
+    fn transform_literal(&mut self, literal: BasicLiteral<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(literal.into())
+    }
+    fn transform_theory_operator_definition(
+        &mut self,
+        theory_operator_definition: TheoryOperatorDefinition<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_operator_definition.into())
+    }
+    fn transform_theory_term_definition(
+        &mut self,
+        theory_term_definition: TheoryTermDefinition<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_term_definition.into())
+    }
+    fn transform_theory_guard_definition(
+        &mut self,
+        theory_guard_definition: TheoryGuardDefinition<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_guard_definition.into())
+    }
+    fn transform_theory_atom_definition(
+        &mut self,
+        theory_atom_definition: TheoryAtomDefinition<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_atom_definition.into())
+    }
+    fn transform_rule(&mut self, rule: Rule<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(rule.into())
+    }
+    fn transform_definition(
+        &mut self,
+        definition: Definition<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(definition.into())
+    }
+    fn transform_show_signature(
+        &mut self,
+        show_signature: ShowSignature<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(show_signature.into())
+    }
+    fn transform_show_term(&mut self, show_term: ShowTerm<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(show_term.into())
+    }
+    fn transform_minimize(&mut self, minimize: Minimize<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(minimize.into())
+    }
+    fn transform_script(&mut self, script: Script<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(script.into())
+    }
+    fn transform_program(&mut self, program: Program<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(program.into())
+    }
+    fn transform_external(&mut self, external: External<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(external.into())
+    }
+    fn transform_edge(&mut self, edge: Edge<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(edge.into())
+    }
+    fn transform_heuristic(&mut self, heuristic: Heuristic<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(heuristic.into())
+    }
+    fn transform_project_atom(
+        &mut self,
+        project_atom: ProjectAtom<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(project_atom.into())
+    }
+    fn transform_project_signature(
+        &mut self,
+        project_signature: ProjectSignature<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(project_signature.into())
+    }
+    fn transform_defined(&mut self, defined: Defined<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(defined.into())
+    }
+    fn transform_theory_definition(
+        &mut self,
+        theory_definition: TheoryDefinition<'a>,
+    ) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(theory_definition.into())
+    }
+    fn transform_comment(&mut self, comment: Comment<'a>) -> Result<Node<'a>, ClingoError> {
+        self.transform_children(comment.into())
+    }
 }
 
 // extern "C" {
@@ -2931,6 +6106,41 @@ pub fn theory_definition<'a>(
     }
 }
 
+/// Construct an AST node of type `ASTType.Comment`.
+pub fn comment<'a>(
+    location: &Location,
+    value: &str,
+    comment_type: CommentType,
+) -> Result<Comment<'a>, ClingoError> {
+    let mut ast = std::ptr::null_mut();
+    let value = internalize_string(value)?;
+
+    if !unsafe {
+        clingo_ast_build(
+            clingo_ast_type_e_clingo_ast_type_comment as i32,
+            &mut ast,
+            location,
+            value,
+            comment_type as i32,
+        )
+    } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_ast_build() failed.",
+        ));
+    }
+    match NonNull::new(ast) {
+        Some(ast) => Ok(Comment {
+            ast: AST {
+                ptr: ast,
+                _lifetime: PhantomData,
+            },
+        }),
+        None => Err(ClingoError::FFIError {
+            msg: "Tried creating NonNull from a null pointer.",
+        })?,
+    }
+}
+
 impl<'a> From<ProgramBuilder<'a>> for &'a mut clingo_program_builder_t {
     fn from(pb: ProgramBuilder<'a>) -> Self {
         pb.theref
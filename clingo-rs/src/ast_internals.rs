@@ -1,9 +1,10 @@
-use crate::ast::{BodyLiteral, Head, Location, Term, TheoryAtomElement, TheoryGuard};
-use crate::{ClingoError, Symbol};
+use crate::ast::{BodyLiteral, Location, UnpoolType};
+use crate::{set_internal_error, ClingoError, ErrorType, Symbol};
 use clingo_sys::*;
+use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
+use std::os::raw::c_void;
 use std::ptr::NonNull;
-use std::{ffi::CStr, u32};
 
 #[derive(Debug, Clone)]
 pub struct Body<'a>(ASTArray<'a>);
@@ -20,52 +21,46 @@ impl<'a> Body<'a> {
         self.0.size()
     }
 }
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct ASTArray<'a> {
-    ast: &'a AST<'a>,
+    ast: AST<'a>,
+    attribute: ASTAttribute,
     index: usize,
 }
+impl<'a> Clone for ASTArray<'a> {
+    fn clone(&self) -> ASTArray<'a> {
+        ASTArray {
+            ast: self.ast.share(),
+            attribute: self.attribute,
+            index: self.index,
+        }
+    }
+}
 impl<'a> ASTArray<'a> {
     /// Get the size of an ASTArray
     ///
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
-
     pub(crate) fn size(&self) -> Result<usize, ClingoError> {
-        let mut size: usize = 0;
-        if !unsafe {
-            clingo_ast_attribute_size_ast_array(
-                self.ast.ptr.as_ptr(),
-                ASTAttribute::Body as i32,
-                &mut size,
-            )
-        } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_ast_attribute_size_ast_array() failed.",
-            ));
-        }
-        Ok(size)
+        self.ast.size_attribute_ast_array(self.attribute)
     }
 }
 impl<'a> Iterator for ASTArray<'a> {
     type Item = AST<'a>;
 
     fn next(&mut self) -> Option<AST<'a>> {
-        let size = self.size().unwrap(); //Err->None
+        let size = self.size().ok()?;
 
         if size == self.index {
             return None;
         }
 
-        if let Ok(ast) = self
+        let ast = self
             .ast
-            .get_attribute_ast_at(ASTAttribute::Body, self.index)
-        {
-            self.index += 1;
-            Some(ast)
-        } else {
-            None
-        }
+            .get_attribute_ast_at(self.attribute, self.index)
+            .ok()?;
+        self.index += 1;
+        Some(ast)
     }
 }
 
@@ -83,6 +78,7 @@ pub(crate) enum ASTType {
     BooleanConstant = clingo_ast_type_e_clingo_ast_type_boolean_constant as isize,
     SymbolicAtom = clingo_ast_type_e_clingo_ast_type_symbolic_atom as isize,
     Comparison = clingo_ast_type_e_clingo_ast_type_comparison as isize,
+    Guard = clingo_ast_type_e_clingo_ast_type_guard as isize,
     ConditionalLiteral = clingo_ast_type_e_clingo_ast_type_conditional_literal as isize,
     Aggregate = clingo_ast_type_e_clingo_ast_type_aggregate as isize,
     BodyAggregateElement = clingo_ast_type_e_clingo_ast_type_body_aggregate_element as isize,
@@ -118,6 +114,7 @@ pub(crate) enum ASTType {
     ProjectSignature = clingo_ast_type_e_clingo_ast_type_project_signature as isize,
     Defined = clingo_ast_type_e_clingo_ast_type_defined as isize,
     TheoryDefinition = clingo_ast_type_e_clingo_ast_type_theory_definition as isize,
+    Comment = clingo_ast_type_e_clingo_ast_type_comment as isize,
 }
 impl ASTType {
     fn try_from(code: u32) -> Result<ASTType, ClingoError> {
@@ -134,6 +131,7 @@ impl ASTType {
             clingo_ast_type_e_clingo_ast_type_boolean_constant => Ok(ASTType::BooleanConstant),
             clingo_ast_type_e_clingo_ast_type_symbolic_atom => Ok(ASTType::SymbolicAtom),
             clingo_ast_type_e_clingo_ast_type_comparison => Ok(ASTType::Comparison),
+            clingo_ast_type_e_clingo_ast_type_guard => Ok(ASTType::Guard),
             clingo_ast_type_e_clingo_ast_type_conditional_literal => {
                 Ok(ASTType::ConditionalLiteral)
             }
@@ -185,6 +183,7 @@ impl ASTType {
             clingo_ast_type_e_clingo_ast_type_project_signature => Ok(ASTType::ProjectSignature),
             clingo_ast_type_e_clingo_ast_type_defined => Ok(ASTType::Defined),
             clingo_ast_type_e_clingo_ast_type_theory_definition => Ok(ASTType::TheoryDefinition),
+            clingo_ast_type_e_clingo_ast_type_comment => Ok(ASTType::Comment),
             x => {
                 eprintln!(
                     "FFIError in {} {}, {} : Failed to match clingo_ast_type {}",
@@ -267,7 +266,7 @@ impl TryFrom<u32> for ASTAttributeType {
 }
 #[derive(Debug, Copy, Clone)]
 /// Enumeration of attributes used by the AST.
-enum ASTAttribute {
+pub(crate) enum ASTAttribute {
     Argument = clingo_ast_attribute_e_clingo_ast_attribute_argument as isize,
     Arguments = clingo_ast_attribute_e_clingo_ast_attribute_arguments as isize,
     Arity = clingo_ast_attribute_e_clingo_ast_attribute_arity as isize,
@@ -277,7 +276,6 @@ enum ASTAttribute {
     Bias = clingo_ast_attribute_e_clingo_ast_attribute_bias as isize,
     Body = clingo_ast_attribute_e_clingo_ast_attribute_body as isize,
     Code = clingo_ast_attribute_e_clingo_ast_attribute_code as isize,
-    Coefficient = clingo_ast_attribute_e_clingo_ast_attribute_coefficient as isize,
     Comparison = clingo_ast_attribute_e_clingo_ast_attribute_comparison as isize,
     Condition = clingo_ast_attribute_e_clingo_ast_attribute_condition as isize,
     Elements = clingo_ast_attribute_e_clingo_ast_attribute_elements as isize,
@@ -310,8 +308,79 @@ enum ASTAttribute {
     Term = clingo_ast_attribute_e_clingo_ast_attribute_term as isize,
     Terms = clingo_ast_attribute_e_clingo_ast_attribute_terms as isize,
     Value = clingo_ast_attribute_e_clingo_ast_attribute_value as isize,
-    Variable = clingo_ast_attribute_e_clingo_ast_attribute_variable as isize,
     Weight = clingo_ast_attribute_e_clingo_ast_attribute_weight as isize,
+    CommentType = clingo_ast_attribute_e_clingo_ast_attribute_comment_type as isize,
+}
+impl ASTAttribute {
+    pub(crate) fn try_from(code: u32) -> Result<ASTAttribute, ClingoError> {
+        match code {
+            clingo_ast_attribute_e_clingo_ast_attribute_argument => Ok(ASTAttribute::Argument),
+            clingo_ast_attribute_e_clingo_ast_attribute_arguments => Ok(ASTAttribute::Arguments),
+            clingo_ast_attribute_e_clingo_ast_attribute_arity => Ok(ASTAttribute::Arity),
+            clingo_ast_attribute_e_clingo_ast_attribute_atom => Ok(ASTAttribute::Atom),
+            clingo_ast_attribute_e_clingo_ast_attribute_atoms => Ok(ASTAttribute::Atoms),
+            clingo_ast_attribute_e_clingo_ast_attribute_atom_type => Ok(ASTAttribute::AtomType),
+            clingo_ast_attribute_e_clingo_ast_attribute_bias => Ok(ASTAttribute::Bias),
+            clingo_ast_attribute_e_clingo_ast_attribute_body => Ok(ASTAttribute::Body),
+            clingo_ast_attribute_e_clingo_ast_attribute_code => Ok(ASTAttribute::Code),
+            clingo_ast_attribute_e_clingo_ast_attribute_comparison => Ok(ASTAttribute::Comparison),
+            clingo_ast_attribute_e_clingo_ast_attribute_condition => Ok(ASTAttribute::Condition),
+            clingo_ast_attribute_e_clingo_ast_attribute_elements => Ok(ASTAttribute::Elements),
+            clingo_ast_attribute_e_clingo_ast_attribute_external => Ok(ASTAttribute::External),
+            clingo_ast_attribute_e_clingo_ast_attribute_external_type => {
+                Ok(ASTAttribute::ExternalType)
+            }
+            clingo_ast_attribute_e_clingo_ast_attribute_function => Ok(ASTAttribute::Function),
+            clingo_ast_attribute_e_clingo_ast_attribute_guard => Ok(ASTAttribute::Guard),
+            clingo_ast_attribute_e_clingo_ast_attribute_guards => Ok(ASTAttribute::Guards),
+            clingo_ast_attribute_e_clingo_ast_attribute_head => Ok(ASTAttribute::Head),
+            clingo_ast_attribute_e_clingo_ast_attribute_is_default => Ok(ASTAttribute::IsDefault),
+            clingo_ast_attribute_e_clingo_ast_attribute_left => Ok(ASTAttribute::Left),
+            clingo_ast_attribute_e_clingo_ast_attribute_left_guard => Ok(ASTAttribute::LeftGuard),
+            clingo_ast_attribute_e_clingo_ast_attribute_literal => Ok(ASTAttribute::Literal),
+            clingo_ast_attribute_e_clingo_ast_attribute_location => Ok(ASTAttribute::Location),
+            clingo_ast_attribute_e_clingo_ast_attribute_modifier => Ok(ASTAttribute::Modifier),
+            clingo_ast_attribute_e_clingo_ast_attribute_name => Ok(ASTAttribute::Name),
+            clingo_ast_attribute_e_clingo_ast_attribute_node_u => Ok(ASTAttribute::NodeU),
+            clingo_ast_attribute_e_clingo_ast_attribute_node_v => Ok(ASTAttribute::NodeV),
+            clingo_ast_attribute_e_clingo_ast_attribute_operator_name => {
+                Ok(ASTAttribute::OperatorName)
+            }
+            clingo_ast_attribute_e_clingo_ast_attribute_operator_type => {
+                Ok(ASTAttribute::OperatorType)
+            }
+            clingo_ast_attribute_e_clingo_ast_attribute_operators => Ok(ASTAttribute::Operators),
+            clingo_ast_attribute_e_clingo_ast_attribute_parameters => Ok(ASTAttribute::Parameters),
+            clingo_ast_attribute_e_clingo_ast_attribute_positive => Ok(ASTAttribute::Positive),
+            clingo_ast_attribute_e_clingo_ast_attribute_priority => Ok(ASTAttribute::Priority),
+            clingo_ast_attribute_e_clingo_ast_attribute_right => Ok(ASTAttribute::Right),
+            clingo_ast_attribute_e_clingo_ast_attribute_right_guard => Ok(ASTAttribute::RightGuard),
+            clingo_ast_attribute_e_clingo_ast_attribute_sequence_type => {
+                Ok(ASTAttribute::SequenceType)
+            }
+            clingo_ast_attribute_e_clingo_ast_attribute_sign => Ok(ASTAttribute::Sign),
+            clingo_ast_attribute_e_clingo_ast_attribute_symbol => Ok(ASTAttribute::Symbol),
+            clingo_ast_attribute_e_clingo_ast_attribute_term => Ok(ASTAttribute::Term),
+            clingo_ast_attribute_e_clingo_ast_attribute_terms => Ok(ASTAttribute::Terms),
+            clingo_ast_attribute_e_clingo_ast_attribute_value => Ok(ASTAttribute::Value),
+            clingo_ast_attribute_e_clingo_ast_attribute_weight => Ok(ASTAttribute::Weight),
+            clingo_ast_attribute_e_clingo_ast_attribute_comment_type => {
+                Ok(ASTAttribute::CommentType)
+            }
+            x => {
+                eprintln!(
+                    "FFIError in {} {}, {} : Failed to match clingo_ast_attribute {}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    x
+                );
+                Err(ClingoError::FFIError {
+                    msg: "Failed to match clingo_ast_attribute.",
+                })
+            }
+        }
+    }
 }
 
 /// This struct provides a view to nodes in the AST.
@@ -345,42 +414,22 @@ impl<'a> Drop for AST<'a> {
     }
 }
 impl<'a> AST<'a> {
-    pub(crate) fn body(&self) -> Body {
+    pub(crate) fn body(&self) -> Body<'a> {
         Body(ASTArray {
-            ast: self,
+            ast: self.share(),
+            attribute: ASTAttribute::Body,
             index: 0,
         })
     }
-    pub(crate) fn head(&self) -> Head {
-        let ast = self.get_attribute_ast(ASTAttribute::Head).unwrap();
-        Head { ast }
-    }
-    pub(crate) fn term(&self) -> Term {
-        let ast = self.get_attribute_ast(ASTAttribute::Term).unwrap();
-        Term { ast }
-    }
-    pub(crate) fn set_term(&mut self, term: Term) {
-        let term = term.ast;
-        // TODO: check
-        self.set_attribute_ast(ASTAttribute::Term, term).unwrap();
-    }
-    pub(crate) fn guard(&self) -> TheoryGuard {
-        // TODO: check
-        let ast = self.get_attribute_ast(ASTAttribute::Guard).unwrap();
-        TheoryGuard { ast }
-    }
-    pub(crate) fn elements(&self) -> &[TheoryAtomElement] {
-        unimplemented!()
-        // let ast = self.get_attribute_ast(ASTAttribute::Elements).unwrap();
-        // &[]
-    }
-    pub(crate) fn left(&self) -> Term<'a> {
-        let ast = self.get_attribute_ast(ASTAttribute::Left).unwrap();
-        Term { ast }
-    }
-    pub(crate) fn right(&self) -> Term<'a> {
-        let ast = self.get_attribute_ast(ASTAttribute::Right).unwrap();
-        Term { ast }
+    /// Create another reference to the same AST node.
+    ///
+    /// In contrast to `clone()` no copy is made, changes to the node are visible through both references.
+    pub(crate) fn share(&self) -> AST<'a> {
+        self.acquire();
+        AST {
+            ptr: self.ptr,
+            _lifetime: self._lifetime,
+        }
     }
 
     /// Increment the reference count of an AST node.
@@ -393,41 +442,37 @@ impl<'a> AST<'a> {
     /// Decrement the reference count of an AST node.
     /// *Note:* The node is deleted if the reference count reaches zero.
     fn release(&self) {
-        // println!("release");
-        // println!("ast: {:?}", self);
-        // println!("ast: {}", self.to_string().unwrap());
         unsafe { clingo_ast_release(self.ptr.as_ptr()) }
     }
-    /// Create a shallow copy of an AST node.
+    /// Create a deep copy of an AST node.
     /// might set one of the following error codes:
     /// - ::clingo_error_bad_alloc
-    fn copy(&self) -> Result<AST, ClingoError> {
+    fn deep_copy(&self) -> Result<AST<'a>, ClingoError> {
         let mut cpy = std::ptr::null_mut();
-        if !unsafe { clingo_ast_copy(self.ptr.as_ptr(), &mut cpy) } {
-            eprintln!("Call to clingo_ast_copy() failed");
+        if !unsafe { clingo_ast_deep_copy(self.ptr.as_ptr(), &mut cpy) } {
+            eprintln!("Call to clingo_ast_deep_copy() failed");
             return Err(ClingoError::new_internal(
-                "Call to clingo_ast_copy() failed.",
+                "Call to clingo_ast_deep_copy() failed.",
             ));
         }
         match NonNull::new(cpy) {
             Some(cpy) => Ok(AST {
                 ptr: cpy,
-                _lifetime: PhantomData,
+                _lifetime: self._lifetime,
             }),
             None => Err(ClingoError::FFIError {
                 msg: "Tried creating NonNull from a null pointer.",
             })?,
         }
     }
-    /// Create a deep copy of an AST node.
+    /// Create a shallow copy of an AST node.
     /// might set one of the following error codes:
     /// - ::clingo_error_bad_alloc
-    fn deep_copy(&self) -> Result<AST<'a>, ClingoError> {
+    pub(crate) fn shallow_copy(&self) -> Result<AST<'a>, ClingoError> {
         let mut cpy = std::ptr::null_mut();
-        if !unsafe { clingo_ast_deep_copy(self.ptr.as_ptr(), &mut cpy) } {
-            eprintln!("Call to clingo_ast_deep_copy() failed");
+        if !unsafe { clingo_ast_copy(self.ptr.as_ptr(), &mut cpy) } {
             return Err(ClingoError::new_internal(
-                "Call to clingo_ast_deep_copy() failed.",
+                "Call to clingo_ast_copy() failed.",
             ));
         }
         match NonNull::new(cpy) {
@@ -464,15 +509,6 @@ impl<'a> AST<'a> {
     //     pub fn clingo_ast_hash(ast: *mut clingo_ast_t) -> usize;
     // }
 
-    // extern "C" {
-    //     #[doc = "! Get the size of the string representation of an AST node."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[out] size the size of the string representation"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_to_string_size(ast: *mut clingo_ast_t, size: *mut usize) -> bool;
-    // }
     /// Get the string representation of an AST node.
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
@@ -516,26 +552,73 @@ impl<'a> AST<'a> {
         ASTType::try_from(ast_type as u32)
     }
 
-    // extern "C" {
-    //     #[doc = "! Check if an AST has the given attribute."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the attribute to check"]
-    //     #[doc = "! @param[out] has_attribute the result"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_has_attribute(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         has_attribute: *mut bool,
-    //     ) -> bool;
-    // }
+    /// Get the attributes of an AST node that hold child nodes.
+    ///
+    /// The attributes are looked up in the constructor table of clingo
+    /// and returned in the order of the constructor arguments.
+    pub(crate) fn child_attributes(
+        &self,
+    ) -> Result<Vec<(ASTAttribute, ASTAttributeType)>, ClingoError> {
+        let ast_type = self.get_type()? as usize;
+        let constructors = unsafe { std::ptr::addr_of!(g_clingo_ast_constructors).read() };
+        if ast_type >= constructors.size {
+            return Err(ClingoError::FFIError {
+                msg: "No constructor for the AST type.",
+            });
+        }
+        let constructor = unsafe { *constructors.constructors.add(ast_type) };
+        let arguments = if constructor.size == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(constructor.arguments, constructor.size) }
+        };
+        let mut attributes = vec![];
+        for argument in arguments {
+            let attribute_type = ASTAttributeType::try_from(argument.type_ as u32)?;
+            match attribute_type {
+                ASTAttributeType::AST
+                | ASTAttributeType::OptionalAST
+                | ASTAttributeType::ASTArray => attributes.push((
+                    ASTAttribute::try_from(argument.attribute as u32)?,
+                    attribute_type,
+                )),
+                _ => (),
+            }
+        }
+        Ok(attributes)
+    }
+
+    /// Check if an AST has the given attribute.
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn has_attribute(&self, attribute: ASTAttribute) -> Result<bool, ClingoError> {
+        let mut has_attribute = false;
+        if !unsafe {
+            clingo_ast_has_attribute(self.ptr.as_ptr(), attribute as i32, &mut has_attribute)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_has_attribute() failed.",
+            ));
+        }
+        Ok(has_attribute)
+    }
 
     /// Get the type of the given AST node.
     ///
+    /// Fails with [`ClingoError::ExternalError`] if the node does not have the attribute.
+    ///
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
-    fn get_attribute_type(&self, attribute: ASTAttribute) -> Result<ASTAttributeType, ClingoError> {
+    pub(crate) fn get_attribute_type(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<ASTAttributeType, ClingoError> {
+        if !self.has_attribute(attribute)? {
+            return Err(ClingoError::new_external(
+                "The AST node does not have the requested attribute.",
+            ));
+        }
         let mut attribute_type = 0;
         if !unsafe {
             clingo_ast_attribute_type(self.ptr.as_ptr(), attribute as i32, &mut attribute_type)
@@ -547,42 +630,69 @@ impl<'a> AST<'a> {
         ASTAttributeType::try_from(attribute_type as u32)
     }
 
-    // extern "C" {
-    //     #[doc = "! Get the value of an attribute of type \"clingo_ast_attribute_type_number\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[out] value the resulting value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_get_number(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *mut ::std::os::raw::c_int,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_number\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_set_number(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: ::std::os::raw::c_int,
-    //     ) -> bool;
-    // }
+    /// Make sure that the attribute exists and is of the expected type.
+    ///
+    /// The C API only checks attribute types with assertions,
+    /// so every accessor checks the type before touching the value.
+    fn check_attribute(
+        &self,
+        attribute: ASTAttribute,
+        expected: ASTAttributeType,
+    ) -> Result<(), ClingoError> {
+        let attribute_type = self.get_attribute_type(attribute)?;
+        if attribute_type as u32 != expected as u32 {
+            return Err(ClingoError::new_external(
+                "The AST attribute has an unexpected type.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the value of an attribute of type ASTAttributeType::Number
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn get_attribute_number(&self, attribute: ASTAttribute) -> Result<i32, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::Number)?;
+        let mut value = 0;
+        if !unsafe {
+            clingo_ast_attribute_get_number(self.ptr.as_ptr(), attribute as i32, &mut value)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_get_number() failed.",
+            ));
+        }
+        Ok(value)
+    }
+
+    /// Set the value of an attribute of type ASTAttributeType::Number
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn set_attribute_number(
+        &mut self,
+        attribute: ASTAttribute,
+        value: i32,
+    ) -> Result<(), ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::Number)?;
+        if !unsafe { clingo_ast_attribute_set_number(self.ptr.as_ptr(), attribute as i32, value) } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_set_number() failed.",
+            ));
+        }
+        Ok(())
+    }
 
     /// Get the value of an attribute of type ASTAttributeType::Symbol
     ///
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
-    fn symbol(&self) -> Result<Symbol, ClingoError> {
+    pub(crate) fn get_attribute_symbol(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<Symbol, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::Symbol)?;
         let mut sym = 0;
-        let attribute = ASTAttribute::Symbol;
         if !unsafe {
             clingo_ast_attribute_get_symbol(self.ptr.as_ptr(), attribute as i32, &mut sym)
         } {
@@ -592,28 +702,34 @@ impl<'a> AST<'a> {
         }
         Ok(Symbol(sym))
     }
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_symbol\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_set_symbol(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: clingo_symbol_t,
-    //     ) -> bool;
-    // }
 
-    /// Get the value of an attribute of type \"clingo_ast_attribute_type_location\".
+    /// Set the value of an attribute of type ASTAttributeType::Symbol
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn set_attribute_symbol(
+        &mut self,
+        attribute: ASTAttribute,
+        value: Symbol,
+    ) -> Result<(), ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::Symbol)?;
+        if !unsafe { clingo_ast_attribute_set_symbol(self.ptr.as_ptr(), attribute as i32, value.0) }
+        {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_set_symbol() failed.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the value of an attribute of type ASTAttributeType::Location
     ///
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
     pub(crate) fn location(&self) -> Result<Location, ClingoError> {
-        let mut loc: clingo_location = Location::default().0;
         let attribute = ASTAttribute::Location;
+        self.check_attribute(attribute, ASTAttributeType::Location)?;
+        let mut loc: clingo_location = Location::default().0;
         if !unsafe {
             clingo_ast_attribute_get_location(self.ptr.as_ptr(), attribute as i32, &mut loc)
         } {
@@ -624,92 +740,79 @@ impl<'a> AST<'a> {
         Ok(Location(loc))
     }
 
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_location\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_set_location(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *const clingo_location_t,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Get the value of an attribute of type \"clingo_ast_attribute_type_string\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[out] value the resulting value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_get_string(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *mut *const ::std::os::raw::c_char,
-    //     ) -> bool;
-    // }
-    pub(crate) fn name(&self) -> Result<String, ClingoError> {
-        let attribute = ASTAttribute::Name;
-        let ast_attribute_type = self.get_attribute_type(attribute).unwrap();
-        dbg!(ast_attribute_type);
-        self.to_string()
-        // TODO
-        // let c_str = std::ptr::null_mut();
-        // if !unsafe { clingo_ast_attribute_get_string(self.ptr.as_ptr(), attribute as i32, c_str) } {
-        //     return Err(ClingoError::new_internal(
-        //         "Call to clingo_ast_attribute_get_string() failed.",
-        //     ));
-        // }
+    /// Set the value of an attribute of type ASTAttributeType::Location
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn set_location(&mut self, location: &Location) -> Result<(), ClingoError> {
+        let attribute = ASTAttribute::Location;
+        self.check_attribute(attribute, ASTAttributeType::Location)?;
+        if !unsafe {
+            clingo_ast_attribute_set_location(self.ptr.as_ptr(), attribute as i32, &location.0)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_set_location() failed.",
+            ));
+        }
+        Ok(())
+    }
 
-        // let c_str: &CStr = unsafe { CStr::from_ptr(*c_str) };
-        // let str_slice: &str = match c_str.to_str() {
-        //     Ok(slice) => slice,
-        //     Err(e) => {
-        //         eprintln!("{:?}", e);
-        //         return Err(ClingoError::new_internal("Call to c_str.to_str() failed."));
-        //     }
-        // };
-        // Ok(str_slice.to_string())
+    /// Get the value of an attribute of type ASTAttributeType::String
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn get_attribute_string(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<String, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::String)?;
+        let mut c_str = std::ptr::null();
+        if !unsafe {
+            clingo_ast_attribute_get_string(self.ptr.as_ptr(), attribute as i32, &mut c_str)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_get_string() failed.",
+            ));
+        }
+        if c_str.is_null() {
+            return Err(ClingoError::FFIError {
+                msg: "clingo_ast_attribute_get_string() returned a null pointer.",
+            });
+        }
+        let c_str = unsafe { CStr::from_ptr(c_str) };
+        Ok(c_str.to_str()?.to_string())
     }
 
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_string\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_set_string(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *const ::std::os::raw::c_char,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Get the value of an attribute of type \"clingo_ast_attribute_type_ast\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[out] value the resulting value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_get_ast(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *mut *mut clingo_ast_t,
-    //     ) -> bool;
-    // }
+    /// Set the value of an attribute of type ASTAttributeType::String
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn set_attribute_string(
+        &mut self,
+        attribute: ASTAttribute,
+        value: &str,
+    ) -> Result<(), ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::String)?;
+        let value = CString::new(value)?;
+        if !unsafe {
+            clingo_ast_attribute_set_string(self.ptr.as_ptr(), attribute as i32, value.as_ptr())
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_set_string() failed.",
+            ));
+        }
+        Ok(())
+    }
 
-    /// Get the value of an attribute of type ASTAttributeType
+    /// Get the value of an attribute of type ASTAttributeType::AST
     ///
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
-    fn get_attribute_ast(&self, attribute: ASTAttribute) -> Result<AST<'a>, ClingoError> {
+    pub(crate) fn get_attribute_ast(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<AST<'a>, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::AST)?;
         let mut ast = std::ptr::null_mut();
         if !unsafe { clingo_ast_attribute_get_ast(self.ptr.as_ptr(), attribute as i32, &mut ast) } {
             return Err(ClingoError::new_internal(
@@ -727,11 +830,16 @@ impl<'a> AST<'a> {
         }
     }
 
-    /// Set the value of an attribute of type \"clingo_ast_attribute_type_ast\".
+    /// Set the value of an attribute of type ASTAttributeType::AST
     ///
     /// might set one of the following error codes:
     /// - ::clingo_error_runtime
-    fn set_attribute_ast(&self, attribute: ASTAttribute, value: AST) -> Result<(), ClingoError> {
+    pub(crate) fn set_attribute_ast(
+        &mut self,
+        attribute: ASTAttribute,
+        value: &AST,
+    ) -> Result<(), ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::AST)?;
         if !unsafe {
             clingo_ast_attribute_set_ast(self.ptr.as_ptr(), attribute as i32, value.ptr.as_ptr())
         } {
@@ -742,132 +850,163 @@ impl<'a> AST<'a> {
         Ok(())
     }
 
-    // extern "C" {
-    //     #[doc = "! Get the value of an attribute of type \"clingo_ast_attribute_type_optional_ast\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @note The value might be \"NULL\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[out] value the resulting value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_get_optional_ast(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *mut *mut clingo_ast_t,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_optional_ast\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @note The value might be \"NULL\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_set_optional_ast(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         value: *mut clingo_ast_t,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Get the value of an attribute of type \"clingo_ast_attribute_type_string_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @param[out] value the resulting value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_get_string_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //         value: *mut *const ::std::os::raw::c_char,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_string_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     #[doc = "! - ::clingo_error_bad_alloc"]
-    //     pub fn clingo_ast_attribute_set_string_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //         value: *const ::std::os::raw::c_char,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Remove an element from an attribute of type \"clingo_ast_attribute_type_string_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_delete_string_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Get the size of an attribute of type \"clingo_ast_attribute_type_string_array\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[out] size the resulting size"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_size_string_array(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         size: *mut usize,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Insert a value into an attribute of type \"clingo_ast_attribute_type_string_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     #[doc = "! - ::clingo_error_bad_alloc"]
-    //     pub fn clingo_ast_attribute_insert_string_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //         value: *const ::std::os::raw::c_char,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Get the value of an attribute of type \"clingo_ast_attribute_type_ast_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @param[out] value the resulting value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_get_ast_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //         value: *mut *mut clingo_ast_t,
-    //     ) -> bool;
-    // }
+    /// Get the value of an attribute of type ASTAttributeType::OptionalAST
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn get_attribute_optional_ast(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<Option<AST<'a>>, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::OptionalAST)?;
+        let mut ast = std::ptr::null_mut();
+        if !unsafe {
+            clingo_ast_attribute_get_optional_ast(self.ptr.as_ptr(), attribute as i32, &mut ast)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_get_optional_ast() failed.",
+            ));
+        }
+        Ok(NonNull::new(ast).map(|x| AST {
+            ptr: x,
+            _lifetime: PhantomData,
+        }))
+    }
+
+    /// Set the value of an attribute of type ASTAttributeType::OptionalAST
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn set_attribute_optional_ast(
+        &mut self,
+        attribute: ASTAttribute,
+        value: Option<&AST>,
+    ) -> Result<(), ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::OptionalAST)?;
+        let value = match value {
+            Some(ast) => ast.ptr.as_ptr(),
+            None => std::ptr::null_mut(),
+        };
+        if !unsafe {
+            clingo_ast_attribute_set_optional_ast(self.ptr.as_ptr(), attribute as i32, value)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_set_optional_ast() failed.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the size of an attribute of type ASTAttributeType::StringArray
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    fn size_attribute_string_array(&self, attribute: ASTAttribute) -> Result<usize, ClingoError> {
+        let mut size: usize = 0;
+        if !unsafe {
+            clingo_ast_attribute_size_string_array(self.ptr.as_ptr(), attribute as i32, &mut size)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_size_string_array() failed.",
+            ));
+        }
+        Ok(size)
+    }
+
+    /// Get the values of an attribute of type ASTAttributeType::StringArray
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn get_attribute_string_array(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<Vec<String>, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::StringArray)?;
+        let size = self.size_attribute_string_array(attribute)?;
+        let mut strings = Vec::with_capacity(size);
+        for index in 0..size {
+            let mut c_str = std::ptr::null();
+            if !unsafe {
+                clingo_ast_attribute_get_string_at(
+                    self.ptr.as_ptr(),
+                    attribute as i32,
+                    index,
+                    &mut c_str,
+                )
+            } {
+                return Err(ClingoError::new_internal(
+                    "Call to clingo_ast_attribute_get_string_at() failed.",
+                ));
+            }
+            if c_str.is_null() {
+                return Err(ClingoError::FFIError {
+                    msg: "clingo_ast_attribute_get_string_at() returned a null pointer.",
+                });
+            }
+            let c_str = unsafe { CStr::from_ptr(c_str) };
+            strings.push(c_str.to_str()?.to_string());
+        }
+        Ok(strings)
+    }
+
+    /// Replace the values of an attribute of type ASTAttributeType::StringArray
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    /// - ::clingo_error_bad_alloc
+    pub(crate) fn set_attribute_string_array(
+        &mut self,
+        attribute: ASTAttribute,
+        values: &[&str],
+    ) -> Result<(), ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::StringArray)?;
+        let values = values
+            .iter()
+            .map(|value| CString::new(*value))
+            .collect::<Result<Vec<_>, _>>()?;
+        let size = self.size_attribute_string_array(attribute)?;
+        for index in (0..size).rev() {
+            if !unsafe {
+                clingo_ast_attribute_delete_string_at(self.ptr.as_ptr(), attribute as i32, index)
+            } {
+                return Err(ClingoError::new_internal(
+                    "Call to clingo_ast_attribute_delete_string_at() failed.",
+                ));
+            }
+        }
+        for (index, value) in values.iter().enumerate() {
+            if !unsafe {
+                clingo_ast_attribute_insert_string_at(
+                    self.ptr.as_ptr(),
+                    attribute as i32,
+                    index,
+                    value.as_ptr(),
+                )
+            } {
+                return Err(ClingoError::new_internal(
+                    "Call to clingo_ast_attribute_insert_string_at() failed.",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the size of an attribute of type ASTAttributeType::ASTArray
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    fn size_attribute_ast_array(&self, attribute: ASTAttribute) -> Result<usize, ClingoError> {
+        self.check_attribute(attribute, ASTAttributeType::ASTArray)?;
+        let mut size: usize = 0;
+        if !unsafe {
+            clingo_ast_attribute_size_ast_array(self.ptr.as_ptr(), attribute as i32, &mut size)
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_attribute_size_ast_array() failed.",
+            ));
+        }
+        Ok(size)
+    }
 
     /// Get the value of an attribute of type ASTAttributeType::ASTArray at the given index.
     ///
@@ -877,7 +1016,7 @@ impl<'a> AST<'a> {
         &self,
         attribute: ASTAttribute,
         index: usize,
-    ) -> Result<AST, ClingoError> {
+    ) -> Result<AST<'a>, ClingoError> {
         let mut ast = std::ptr::null_mut();
         if !unsafe {
             clingo_ast_attribute_get_ast_at(self.ptr.as_ptr(), attribute as i32, index, &mut ast)
@@ -897,75 +1036,81 @@ impl<'a> AST<'a> {
         }
     }
 
-    // extern "C" {
-    //     #[doc = "! Set the value of an attribute of type \"clingo_ast_attribute_type_ast_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @param[in] value the value"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     #[doc = "! - ::clingo_error_bad_alloc"]
-    //     pub fn clingo_ast_attribute_set_ast_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //         value: *mut clingo_ast_t,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Remove an element from an attribute of type \"clingo_ast_attribute_type_ast_array\" at the given index."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[in] index the target index"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_delete_ast_at(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         index: usize,
-    //     ) -> bool;
-    // }
-    // extern "C" {
-    //     #[doc = "! Get the size of an attribute of type \"clingo_ast_attribute_type_ast_array\"."]
-    //     #[doc = "!"]
-    //     #[doc = "! @param[in] ast the target AST"]
-    //     #[doc = "! @param[in] attribute the target attribute"]
-    //     #[doc = "! @param[out] size the resulting size"]
-    //     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-    //     #[doc = "! - ::clingo_error_runtime"]
-    //     pub fn clingo_ast_attribute_size_ast_array(
-    //         ast: *mut clingo_ast_t,
-    //         attribute: clingo_ast_attribute_t,
-    //         size: *mut usize,
-    //     ) -> bool;
-    // }
-    // fn ast_array(&self, attribute: ASTAttribute) -> ASTArray {
-    //     ASTArray {
-    //         ast: &self,
-    //         attribute,
-    //     }
-    // }
+    /// Get the values of an attribute of type ASTAttributeType::ASTArray
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    pub(crate) fn get_attribute_ast_array(
+        &self,
+        attribute: ASTAttribute,
+    ) -> Result<Vec<AST<'a>>, ClingoError> {
+        let size = self.size_attribute_ast_array(attribute)?;
+        (0..size)
+            .map(|index| self.get_attribute_ast_at(attribute, index))
+            .collect()
+    }
+
+    /// Replace the values of an attribute of type ASTAttributeType::ASTArray
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_runtime
+    /// - ::clingo_error_bad_alloc
+    pub(crate) fn set_attribute_ast_array<'b, I>(
+        &mut self,
+        attribute: ASTAttribute,
+        values: I,
+    ) -> Result<(), ClingoError>
+    where
+        I: IntoIterator<Item = &'b AST<'b>>,
+    {
+        let size = self.size_attribute_ast_array(attribute)?;
+        for index in (0..size).rev() {
+            if !unsafe {
+                clingo_ast_attribute_delete_ast_at(self.ptr.as_ptr(), attribute as i32, index)
+            } {
+                return Err(ClingoError::new_internal(
+                    "Call to clingo_ast_attribute_delete_ast_at() failed.",
+                ));
+            }
+        }
+        for (index, value) in values.into_iter().enumerate() {
+            if !unsafe {
+                clingo_ast_attribute_insert_ast_at(
+                    self.ptr.as_ptr(),
+                    attribute as i32,
+                    index,
+                    value.ptr.as_ptr(),
+                )
+            } {
+                return Err(ClingoError::new_internal(
+                    "Call to clingo_ast_attribute_insert_ast_at() failed.",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Unpool the given AST.
+    ///
+    /// might set one of the following error codes:
+    /// - ::clingo_error_bad_alloc
+    pub(crate) fn unpool(&self, unpool_type: UnpoolType) -> Result<Vec<AST<'a>>, ClingoError> {
+        let mut asts: Vec<AST<'a>> = vec![];
+        if !unsafe {
+            clingo_ast_unpool(
+                self.ptr.as_ptr(),
+                unpool_type as clingo_ast_unpool_type_bitset_t,
+                Some(unsafe_unpool_callback),
+                &mut asts as *mut Vec<AST<'a>> as *mut c_void,
+            )
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_ast_unpool() failed.",
+            ));
+        }
+        Ok(asts)
+    }
 }
-// extern "C" {
-//     #[doc = "! Insert a value into an attribute of type \"clingo_ast_attribute_type_ast_array\" at the given index."]
-//     #[doc = "!"]
-//     #[doc = "! @param[in] ast the target AST"]
-//     #[doc = "! @param[in] attribute the target attribute"]
-//     #[doc = "! @param[in] index the target index"]
-//     #[doc = "! @param[in] value the value"]
-//     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-//     #[doc = "! - ::clingo_error_runtime"]
-//     #[doc = "! - ::clingo_error_bad_alloc"]
-//     pub fn clingo_ast_attribute_insert_ast_at(
-//         ast: *mut clingo_ast_t,
-//         attribute: clingo_ast_attribute_t,
-//         index: usize,
-//         value: *mut clingo_ast_t,
-//     ) -> bool;
-// }
 
 // extern "C" {
 //     #[doc = "! Parse the programs in the given list of files and return an abstract syntax tree for each statement via a callback."]
@@ -994,31 +1139,33 @@ impl<'a> AST<'a> {
 //     ) -> bool;
 // }
 
-#[derive(Debug, Copy, Clone)]
-/// Enum to configure unpooling.
-pub(crate) enum Unpooling {
-    /// To only unpool conditions of conditional literals.
-    Condition = clingo_ast_unpool_type_e_clingo_ast_unpool_type_condition as isize,
-    /// To unpool everything except conditions of conditional literals.
-    Other = clingo_ast_unpool_type_e_clingo_ast_unpool_type_other as isize,
-    /// To unpool everything.
-    All = clingo_ast_unpool_type_e_clingo_ast_unpool_type_all as isize,
+unsafe extern "C" fn unsafe_unpool_callback(ast: *mut clingo_ast_t, data: *mut c_void) -> bool {
+    // check for null pointers
+    if data.is_null() {
+        set_internal_error(
+            ErrorType::Runtime,
+            "unsafe_unpool_callback() got a null pointer.",
+        );
+        return false;
+    }
+    let asts = &mut *(data as *mut Vec<AST>);
+    match NonNull::new(ast) {
+        Some(ptr) => {
+            let ast = AST {
+                ptr,
+                _lifetime: PhantomData,
+            };
+            // the reference count of callback arguments is not incremented
+            ast.acquire();
+            asts.push(ast);
+            true
+        }
+        None => {
+            set_internal_error(
+                ErrorType::Runtime,
+                "unsafe_unpool_callback() got a null pointer.",
+            );
+            false
+        }
+    }
 }
-
-// TODO
-// extern "C" {
-//     #[doc = "! Unpool the given AST."]
-//     #[doc = "!"]
-//     #[doc = "! @param[in] ast the target AST"]
-//     #[doc = "! @param[in] unpool_type what to unpool"]
-//     #[doc = "! @param[in] callback the callback to report ASTs"]
-//     #[doc = "! @param[in] callback_data user data for the callback"]
-//     #[doc = "! @return whether the call was successful; might set one of the following error codes:"]
-//     #[doc = "! - ::clingo_error_bad_alloc"]
-//     pub fn clingo_ast_unpool(
-//         ast: *mut clingo_ast_t,
-//         unpool_type: clingo_ast_unpool_type_bitset_t,
-//         callback: clingo_ast_callback_v2_t,
-//         callback_data: *mut ::std::os::raw::c_void,
-//     ) -> bool;
-// }
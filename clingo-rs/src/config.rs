@@ -0,0 +1,403 @@
+//! [`SolverConfig`] sets common `solve.*` and `solver.*` options of a control object with typed
+//! values instead of string keys.
+//!
+//! ```ignore
+//! let mut ctl = control(vec![])?;
+//! SolverConfig::new()
+//!     .models(0)
+//!     .parallel(4, ParallelMode::Compete)
+//!     .opt_strategy(OptStrategy::Usc)
+//!     .heuristic(Heuristic::Vsids)
+//!     .apply(&mut ctl)?;
+//! ```
+//!
+//! Before setting a value, the option is looked up in the configuration of the control object
+//! and checked against the alternatives listed in its [`Configuration::description()`].
+//! Options that are not covered can be set with [`SolverConfig::set()`]
+//! or via the raw [`Configuration`] API.
+use crate::{ClingoError, Configuration, ConfigurationType, ControlCtx, GenericControl, Id};
+use thiserror::Error;
+
+/// Error returned if an option is unknown or a value is rejected by the configuration.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("ConfigError: {key}: {msg}")]
+pub struct ConfigError {
+    /// the configuration key, e.g., `solve.models`
+    pub key: String,
+    pub msg: String,
+}
+
+/// Mode of the parallel search, see option `solve.parallel_mode`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParallelMode {
+    /// Run competition based search
+    Compete,
+    /// Run splitting based search
+    Split,
+}
+/// Optimization algorithm, see option `solve.opt_mode`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OptMode {
+    /// Find an optimal model
+    Opt,
+    /// Find models with costs below the initial bound
+    Enum,
+    /// Find the optimum, then enumerate optimal models
+    OptN,
+    /// Ignore optimize statements
+    Ignore,
+}
+/// Enumeration algorithm, see option `solve.enum_mode`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EnumMode {
+    /// Backtrack decision literals from solutions
+    Bt,
+    /// Add nogoods for computed solutions
+    Record,
+    /// Compute brave consequences (union of models)
+    Brave,
+    /// Compute cautious consequences (intersection of models)
+    Cautious,
+    /// Use bt for enumeration and record for optimization
+    Auto,
+}
+/// Optimization strategy, see option `solver.opt_strategy`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OptStrategy {
+    /// Model-guided optimization
+    Bb,
+    /// Core-guided optimization
+    Usc,
+}
+/// Decision heuristic, see option `solver.heuristic`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Heuristic {
+    /// BerkMin-like heuristic
+    Berkmin,
+    /// Siege-like heuristic
+    Vmtf,
+    /// Chaff-like heuristic
+    Vsids,
+    /// Domain knowledge in Vsids-like heuristic
+    Domain,
+    /// Smodels-like heuristic
+    Unit,
+    /// Select the first free variable
+    None,
+}
+/// Default sign of decisions, see option `solver.sign_def`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SignDef {
+    Asp,
+    Pos,
+    Neg,
+    Rnd,
+}
+
+// Conversion of the typed values from and to the names used by clasp.
+trait OptionValue: Sized + Copy + PartialEq + 'static {
+    const VALUES: &'static [(Self, &'static str)];
+    fn name(self) -> &'static str {
+        Self::VALUES
+            .iter()
+            .find(|(value, _)| *value == self)
+            .map(|(_, name)| *name)
+            .unwrap()
+    }
+    // parse the first component of a value like `vsids,92`
+    fn parse(value: &str) -> Option<Self> {
+        let first = value.split(',').next()?;
+        Self::VALUES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(first))
+            .map(|(value, _)| *value)
+    }
+}
+impl OptionValue for ParallelMode {
+    const VALUES: &'static [(Self, &'static str)] = &[
+        (ParallelMode::Compete, "compete"),
+        (ParallelMode::Split, "split"),
+    ];
+}
+impl OptionValue for OptMode {
+    const VALUES: &'static [(Self, &'static str)] = &[
+        (OptMode::Opt, "opt"),
+        (OptMode::Enum, "enum"),
+        (OptMode::OptN, "optN"),
+        (OptMode::Ignore, "ignore"),
+    ];
+}
+impl OptionValue for EnumMode {
+    const VALUES: &'static [(Self, &'static str)] = &[
+        (EnumMode::Bt, "bt"),
+        (EnumMode::Record, "record"),
+        (EnumMode::Brave, "brave"),
+        (EnumMode::Cautious, "cautious"),
+        (EnumMode::Auto, "auto"),
+    ];
+}
+impl OptionValue for OptStrategy {
+    const VALUES: &'static [(Self, &'static str)] =
+        &[(OptStrategy::Bb, "bb"), (OptStrategy::Usc, "usc")];
+}
+impl OptionValue for Heuristic {
+    const VALUES: &'static [(Self, &'static str)] = &[
+        (Heuristic::Berkmin, "berkmin"),
+        (Heuristic::Vmtf, "vmtf"),
+        (Heuristic::Vsids, "vsids"),
+        (Heuristic::Domain, "domain"),
+        (Heuristic::Unit, "unit"),
+        (Heuristic::None, "none"),
+    ];
+}
+impl OptionValue for SignDef {
+    const VALUES: &'static [(Self, &'static str)] = &[
+        (SignDef::Asp, "asp"),
+        (SignDef::Pos, "pos"),
+        (SignDef::Neg, "neg"),
+        (SignDef::Rnd, "rnd"),
+    ];
+}
+
+const MODELS: &str = "solve.models";
+const PARALLEL_MODE: &str = "solve.parallel_mode";
+const OPT_MODE: &str = "solve.opt_mode";
+const ENUM_MODE: &str = "solve.enum_mode";
+const OPT_STRATEGY: &str = "solver.opt_strategy";
+const HEURISTIC: &str = "solver.heuristic";
+const SEED: &str = "solver.seed";
+const SIGN_DEF: &str = "solver.sign_def";
+
+/// Typed builder for the main solve and solver options.
+///
+/// Only the options that have been set are applied, all others keep their current value.
+/// The `solver.*` options refer to the configuration of the first solver,
+/// see the `solver` array of the raw [`Configuration`] for the other threads.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolverConfig {
+    models: Option<u32>,
+    parallel: Option<(u32, ParallelMode)>,
+    opt_mode: Option<OptMode>,
+    enum_mode: Option<EnumMode>,
+    opt_strategy: Option<OptStrategy>,
+    heuristic: Option<Heuristic>,
+    seed: Option<u32>,
+    sign_def: Option<SignDef>,
+    raw: Vec<(String, String)>,
+}
+impl SolverConfig {
+    /// Create a configuration that does not change any option.
+    pub fn new() -> SolverConfig {
+        SolverConfig::default()
+    }
+    /// Compute at most `models` models, 0 for all (`solve.models`).
+    pub fn models(mut self, models: u32) -> SolverConfig {
+        self.models = Some(models);
+        self
+    }
+    /// Run the search with the given number of threads (`solve.parallel_mode`).
+    pub fn parallel(mut self, threads: u32, mode: ParallelMode) -> SolverConfig {
+        self.parallel = Some((threads, mode));
+        self
+    }
+    /// Set the optimization algorithm (`solve.opt_mode`).
+    pub fn opt_mode(mut self, mode: OptMode) -> SolverConfig {
+        self.opt_mode = Some(mode);
+        self
+    }
+    /// Set the enumeration algorithm (`solve.enum_mode`).
+    pub fn enum_mode(mut self, mode: EnumMode) -> SolverConfig {
+        self.enum_mode = Some(mode);
+        self
+    }
+    /// Set the optimization strategy with its default tactics (`solver.opt_strategy`).
+    pub fn opt_strategy(mut self, strategy: OptStrategy) -> SolverConfig {
+        self.opt_strategy = Some(strategy);
+        self
+    }
+    /// Set the decision heuristic with its default parameter (`solver.heuristic`).
+    pub fn heuristic(mut self, heuristic: Heuristic) -> SolverConfig {
+        self.heuristic = Some(heuristic);
+        self
+    }
+    /// Set the seed of the random number generator (`solver.seed`).
+    pub fn seed(mut self, seed: u32) -> SolverConfig {
+        self.seed = Some(seed);
+        self
+    }
+    /// Set the default sign of decisions (`solver.sign_def`).
+    pub fn sign_def(mut self, sign: SignDef) -> SolverConfig {
+        self.sign_def = Some(sign);
+        self
+    }
+    /// Set an arbitrary option by its key, e.g., `("solver.restarts", "L,100")`.
+    ///
+    /// Raw options are applied after the typed ones, only the existence of the key is checked.
+    pub fn set(mut self, key: &str, value: &str) -> SolverConfig {
+        self.raw.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// The options as key value pairs in the order they are applied.
+    pub fn entries(&self) -> Vec<(String, String)> {
+        let mut entries = vec![];
+        let mut push = |key: &str, value: String| entries.push((key.to_string(), value));
+        if let Some(models) = self.models {
+            push(MODELS, models.to_string());
+        }
+        if let Some((threads, mode)) = self.parallel {
+            push(PARALLEL_MODE, format!("{},{}", threads, mode.name()));
+        }
+        if let Some(mode) = self.opt_mode {
+            push(OPT_MODE, mode.name().to_string());
+        }
+        if let Some(mode) = self.enum_mode {
+            push(ENUM_MODE, mode.name().to_string());
+        }
+        if let Some(strategy) = self.opt_strategy {
+            push(OPT_STRATEGY, strategy.name().to_string());
+        }
+        if let Some(heuristic) = self.heuristic {
+            push(HEURISTIC, heuristic.name().to_string());
+        }
+        if let Some(seed) = self.seed {
+            push(SEED, seed.to_string());
+        }
+        if let Some(sign) = self.sign_def {
+            push(SIGN_DEF, sign.name().to_string());
+        }
+        entries.extend(self.raw.iter().cloned());
+        entries
+    }
+
+    /// Validate the options against the configuration of `ctl` and set them.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::ConfigError`] if a key does not exist, does not refer to a value
+    ///   or the value is rejected
+    /// - [`ClingoError::InternalError`] if the configuration could not be accessed
+    pub fn apply<C: ControlCtx>(&self, ctl: &mut GenericControl<C>) -> Result<(), ClingoError> {
+        let conf = ctl.configuration_mut()?;
+        let entries = self.entries();
+        let typed = entries.len() - self.raw.len();
+        for (i, (key, value)) in entries.iter().enumerate() {
+            let id = lookup(conf, key)?;
+            if i < typed {
+                validate(conf, id, key, value)?;
+            }
+            if conf.value_set(id, value).is_err() {
+                return Err(error(
+                    key,
+                    format!(
+                        "invalid value `{}`, expected: {}",
+                        value,
+                        summary(conf.description(id)?)
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the typed options from the current configuration of `ctl`.
+    ///
+    /// Options that are unset or hold values not covered by the typed builder,
+    /// e.g., `solve.models` if it is left to clasp, are `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::ConfigError`] if a key does not exist
+    /// - [`ClingoError::InternalError`] if the configuration could not be accessed
+    pub fn from_control<C: ControlCtx>(
+        ctl: &GenericControl<C>,
+    ) -> Result<SolverConfig, ClingoError> {
+        let conf = ctl.configuration()?;
+        let get = |key: &str| -> Result<String, ClingoError> {
+            let id = lookup(conf, key)?;
+            conf.value_get(id)
+        };
+        let parallel = get(PARALLEL_MODE)?;
+        let mut parallel = parallel.split(',');
+        let threads = parallel.next().and_then(|n| n.parse().ok());
+        let mode = parallel
+            .next()
+            .map_or(Some(ParallelMode::Compete), ParallelMode::parse);
+        Ok(SolverConfig {
+            models: get(MODELS)?.parse().ok(),
+            parallel: threads.zip(mode),
+            opt_mode: OptMode::parse(&get(OPT_MODE)?),
+            enum_mode: EnumMode::parse(&get(ENUM_MODE)?),
+            opt_strategy: OptStrategy::parse(&get(OPT_STRATEGY)?),
+            heuristic: Heuristic::parse(&get(HEURISTIC)?),
+            seed: get(SEED)?.parse().ok(),
+            sign_def: SignDef::parse(&get(SIGN_DEF)?),
+            raw: vec![],
+        })
+    }
+}
+
+fn error(key: &str, msg: impl Into<String>) -> ClingoError {
+    ConfigError {
+        key: key.to_string(),
+        msg: msg.into(),
+    }
+    .into()
+}
+
+// Find the key of a value entry.
+fn lookup(conf: &Configuration, key: &str) -> Result<Id, ClingoError> {
+    let root = conf.root()?;
+    if !conf.map_has_subkey(root, key)? {
+        return Err(error(key, "unknown option"));
+    }
+    let id = conf.map_at(root, key)?;
+    if !conf
+        .configuration_type(id)?
+        .contains(ConfigurationType::VALUE)
+    {
+        return Err(error(key, "not a value"));
+    }
+    Ok(id)
+}
+
+// Check that one of the components of the value is listed in the first group of alternatives
+// `{a|b|c}` of the description.
+fn validate(conf: &Configuration, id: Id, key: &str, value: &str) -> Result<(), ClingoError> {
+    let description = conf.description(id)?;
+    let alternatives = description
+        .split('{')
+        .skip(1)
+        .filter_map(|group| group.split('}').next())
+        .find(|group| group.contains('|'));
+    if let Some(alternatives) = alternatives {
+        let known = value.split(',').any(|component| {
+            alternatives
+                .split('|')
+                .any(|alternative| alternative.eq_ignore_ascii_case(component))
+        });
+        if !known {
+            return Err(error(
+                key,
+                format!("`{}` is not one of {{{}}}", value, alternatives),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// The first line of a description without the placeholder of the argument.
+fn summary(description: &str) -> String {
+    let first = description.lines().next().unwrap_or_default();
+    let rest: Vec<&str> = description.lines().skip(1).collect();
+    match rest.first() {
+        Some(line) if line.trim_start().starts_with("%A:") => {
+            format!(
+                "{} ({})",
+                first,
+                line.trim_start().trim_start_matches("%A:").trim()
+            )
+        }
+        _ => first.to_string(),
+    }
+}
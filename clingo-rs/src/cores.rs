@@ -0,0 +1,236 @@
+//! Unsatisfiable cores over assumptions given as symbols.
+//!
+//! [`GenericSolveHandle::core()`] returns the solver literals of the assumptions that made a solve
+//! call unsatisfiable. [`core_symbols()`] maps such literals back to [`Assumption`]s and
+//! [`CoreMinimizer`] shrinks a set of assumptions to a minimal unsatisfiable subset, i.e., a core
+//! from which no assumption can be removed, by solving the same grounded program repeatedly.
+//!
+//! ```ignore
+//! let mut ctl = control(vec![])?;
+//! ctl.add("base", &[], "{ a; b; c }. :- a, b.")?;
+//! ctl.ground(&[Part::new("base", vec![])?])?;
+//! let a = Symbol::create_id("a", true)?;
+//! let b = Symbol::create_id("b", true)?;
+//! let c = Symbol::create_id("c", true)?;
+//! let mut minimizer = CoreMinimizer::new(&mut ctl)?;
+//! let core = minimizer.quick_xplain(&[(a, true), (b, true), (c, true)])?;
+//! assert_eq!(core, Some(vec![(a, true), (b, true)]));
+//! ```
+//!
+//! [`GenericSolveHandle::core()`]: crate::GenericSolveHandle::core
+use crate::{
+    ClingoError, ControlCtx, GenericControl, SolveMode, SolveResult, SolverLiteral, Symbol,
+};
+use std::collections::HashMap;
+
+/// Assumption that the atom `symbol` is true (`true`) or false (`false`).
+pub type Assumption = (Symbol, bool);
+
+/// Map the literals of an unsatisfiable core to assumptions over the corresponding atoms.
+///
+/// # Arguments
+///
+/// * `ctl` - the control object that was used for solving
+/// * `core` - literals of the core, e.g., obtained from [`GenericSolveHandle::core()`]
+///
+/// # Errors
+///
+/// - [`ClingoError::InternalError`] if the symbolic atoms could not be accessed
+/// - [`ClingoError::FFIError`] if a literal does not belong to a symbolic atom
+///
+/// [`GenericSolveHandle::core()`]: crate::GenericSolveHandle::core
+pub fn core_symbols<C: ControlCtx>(
+    ctl: &GenericControl<C>,
+    core: &[SolverLiteral],
+) -> Result<Vec<Assumption>, ClingoError> {
+    let mut symbols = HashMap::new();
+    for atom in ctl.symbolic_atoms()?.iter()? {
+        symbols.entry(atom.literal()?.0).or_insert(atom.symbol()?);
+    }
+    core.iter()
+        .map(|literal| match symbols.get(&literal.0.abs()) {
+            Some(symbol) => Ok((*symbol, literal.0 > 0)),
+            None => Err(ClingoError::FFIError {
+                msg: "Core literal does not belong to a symbolic atom.",
+            }),
+        })
+        .collect()
+}
+
+/// Computes minimal unsatisfiable subsets of assumptions over the currently grounded program.
+///
+/// All solve calls reuse the borrowed control object.
+/// Assumptions on atoms that do not appear in the grounded program are handled as well:
+/// assuming such an atom to be true is unsatisfiable on its own and assuming it to be false has no effect.
+#[derive(Debug)]
+pub struct CoreMinimizer<'a, C: ControlCtx> {
+    ctl: &'a mut GenericControl<C>,
+    literals: HashMap<Symbol, SolverLiteral>,
+    solve_calls: usize,
+}
+impl<'a, C: ControlCtx> CoreMinimizer<'a, C> {
+    /// Create a minimizer for the program grounded in `ctl`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] if the symbolic atoms could not be accessed
+    pub fn new(ctl: &'a mut GenericControl<C>) -> Result<Self, ClingoError> {
+        let mut literals = HashMap::new();
+        for atom in ctl.symbolic_atoms()?.iter()? {
+            literals.insert(atom.symbol()?, atom.literal()?);
+        }
+        Ok(CoreMinimizer {
+            ctl,
+            literals,
+            solve_calls: 0,
+        })
+    }
+
+    /// Number of solve calls issued so far.
+    pub fn solve_calls(&self) -> usize {
+        self.solve_calls
+    }
+
+    /// Check whether the program is satisfiable under the given assumptions.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`](crate::ErrorCode::BadAlloc)
+    ///   or [`ErrorCode::Runtime`](crate::ErrorCode::Runtime) if solving fails
+    pub fn is_satisfiable(&mut self, assumptions: &[Assumption]) -> Result<bool, ClingoError> {
+        Ok(self.solve(assumptions)?.is_none())
+    }
+
+    /// Get the core reported by the solver if the program is unsatisfiable under the given
+    /// assumptions, or `None` if it is satisfiable.
+    ///
+    /// The core is a subset of the assumptions in their original order but not necessarily minimal.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`](crate::ErrorCode::BadAlloc)
+    ///   or [`ErrorCode::Runtime`](crate::ErrorCode::Runtime) if solving fails
+    pub fn core(
+        &mut self,
+        assumptions: &[Assumption],
+    ) -> Result<Option<Vec<Assumption>>, ClingoError> {
+        self.solve(assumptions)
+    }
+
+    /// Minimize the core by deletion: each assumption of the core reported by the solver is
+    /// dropped in turn and kept out if the remaining assumptions are still unsatisfiable.
+    ///
+    /// Returns `None` if the program is satisfiable under the given assumptions.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`](crate::ErrorCode::BadAlloc)
+    ///   or [`ErrorCode::Runtime`](crate::ErrorCode::Runtime) if solving fails
+    pub fn minimize_deletion(
+        &mut self,
+        assumptions: &[Assumption],
+    ) -> Result<Option<Vec<Assumption>>, ClingoError> {
+        let mut core = match self.solve(assumptions)? {
+            Some(core) => core,
+            None => return Ok(None),
+        };
+        // all assumptions before index i are necessary
+        let mut i = 0;
+        while i < core.len() {
+            let mut candidate = core.clone();
+            candidate.remove(i);
+            match self.solve(&candidate)? {
+                // the solver core is a subset of the candidate that still contains the necessary
+                // assumptions at the front
+                Some(smaller) => core = smaller,
+                None => i += 1,
+            }
+        }
+        Ok(Some(core))
+    }
+
+    /// Minimize the core with the QuickXplain algorithm, which recursively splits the assumptions
+    /// and needs fewer solve calls than [`CoreMinimizer::minimize_deletion()`] if the minimal core
+    /// is small compared to the assumptions.
+    ///
+    /// Returns `None` if the program is satisfiable under the given assumptions.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`](crate::ErrorCode::BadAlloc)
+    ///   or [`ErrorCode::Runtime`](crate::ErrorCode::Runtime) if solving fails
+    pub fn quick_xplain(
+        &mut self,
+        assumptions: &[Assumption],
+    ) -> Result<Option<Vec<Assumption>>, ClingoError> {
+        let core = match self.solve(assumptions)? {
+            Some(core) => core,
+            None => return Ok(None),
+        };
+        if core.is_empty() {
+            return Ok(Some(core));
+        }
+        let mut minimal = self.quick_xplain_rec(&[], false, &core)?;
+        // restore the order of the assumptions
+        minimal.sort_by_key(|assumption| core.iter().position(|other| other == assumption));
+        Ok(Some(minimal))
+    }
+
+    // Returns a minimal subset of `constraints` that is unsatisfiable together with `background`,
+    // assuming that `background` alone is satisfiable if `delta` is false.
+    fn quick_xplain_rec(
+        &mut self,
+        background: &[Assumption],
+        delta: bool,
+        constraints: &[Assumption],
+    ) -> Result<Vec<Assumption>, ClingoError> {
+        if delta && !self.is_satisfiable(background)? {
+            return Ok(vec![]);
+        }
+        if constraints.len() == 1 {
+            return Ok(constraints.to_vec());
+        }
+        let (left, right) = constraints.split_at(constraints.len() / 2);
+        let extended = [background, left].concat();
+        let mut right = self.quick_xplain_rec(&extended, !left.is_empty(), right)?;
+        let extended = [background, &right].concat();
+        let left = self.quick_xplain_rec(&extended, !right.is_empty(), left)?;
+        right.extend(left);
+        Ok(right)
+    }
+
+    // Solve under the assumptions and return the core reported by the solver if unsatisfiable.
+    fn solve(
+        &mut self,
+        assumptions: &[Assumption],
+    ) -> Result<Option<Vec<Assumption>>, ClingoError> {
+        let mut mapped = Vec::with_capacity(assumptions.len());
+        for &(symbol, positive) in assumptions {
+            match self.literals.get(&symbol) {
+                Some(literal) if positive => mapped.push(((symbol, positive), *literal)),
+                Some(literal) => mapped.push(((symbol, positive), literal.negate())),
+                // the atom is false, so this assumption alone is a core
+                None if positive => return Ok(Some(vec![(symbol, positive)])),
+                None => {}
+            }
+        }
+        let literals: Vec<SolverLiteral> = mapped.iter().map(|(_, literal)| *literal).collect();
+        self.solve_calls += 1;
+        let mut handle = self.ctl.solve_mut(SolveMode::empty(), &literals)?;
+        let result = handle.get()?;
+        if !result.contains(SolveResult::UNSATISFIABLE) {
+            handle.close()?;
+            return Ok(None);
+        }
+        let core = handle.core()?;
+        handle.close()?;
+        // map the literals back to the assumptions keeping their order
+        Ok(Some(
+            mapped
+                .into_iter()
+                .filter(|(_, literal)| core.contains(literal))
+                .map(|(assumption, _)| assumption)
+                .collect(),
+        ))
+    }
+}
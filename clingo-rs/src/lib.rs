@@ -5,6 +5,7 @@
 use bitflags::bitflags;
 use clingo_sys::*;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::convert::Infallible;
 use std::convert::TryInto;
@@ -17,12 +18,24 @@ use std::os::raw::c_char;
 use std::os::raw::c_void;
 use std::ptr::NonNull;
 use std::str::Utf8Error;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use thiserror::Error;
 
+/// Reading and writing ground programs in aspif format.
+pub mod aspif;
 /// Functions and data structures to work with program ASTs.
 pub mod ast;
 mod ast_internals;
+/// Typed configuration of the solver.
+pub mod config;
+/// Extraction and minimisation of unsatisfiable cores.
+pub mod cores;
+/// Printing ground programs as readable ASP text.
+pub mod printer;
+/// Serde support for symbols, available with the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod serialize;
 
 pub mod theory;
 
@@ -47,8 +60,14 @@ pub enum ClingoError {
         code: ErrorCode,
         last: &'static str,
     },
-    #[error("ExternalError: ")]
+    #[error(transparent)]
     ExternalError(#[from] ExternalError),
+    #[error(transparent)]
+    FromSymbolError(#[from] FromSymbolError),
+    #[error(transparent)]
+    AspifError(#[from] aspif::AspifError),
+    #[error(transparent)]
+    ConfigError(#[from] config::ConfigError),
 }
 impl ClingoError {
     fn new_internal(msg: &'static str) -> ClingoError {
@@ -69,6 +88,18 @@ pub struct ExternalError {
     pub msg: &'static str,
 }
 
+/// Error returned when a symbol does not match the shape expected by a [`FromSymbol`] implementation.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("FromSymbolError: {msg}")]
+pub struct FromSymbolError {
+    pub msg: String,
+}
+impl From<Infallible> for ClingoError {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
 /// Enumeration of clingo error types
 /// See: set_error()
 #[derive(Debug, Copy, Clone)]
@@ -500,6 +531,7 @@ impl TheoryTermType {
 
 /// Enumeration for the different model types.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModelType {
     /// The model represents a stable model.
     StableModel = clingo_model_type_e_clingo_model_type_stable_model as isize,
@@ -631,6 +663,7 @@ bitflags! {
 }
 bitflags! {
     #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     /// Bit flags that describes the result of a solve call.
     pub struct SolveResult: u32 {
         /// The problem is satisfiable.
@@ -1360,6 +1393,47 @@ impl Symbol {
     pub fn symbol_type(self) -> Result<SymbolType, ClingoError> {
         SymbolType::try_from(unsafe { clingo_symbol_type(self.0) } as u32)
     }
+
+    /// Get the arguments of a symbol if it is a positive function with the given name and arity.
+    ///
+    /// Tuples are functions with an empty name.
+    /// Returns `None` for all other symbols.
+    pub fn match_function(
+        self,
+        name: &str,
+        arity: usize,
+    ) -> Result<Option<Vec<Symbol>>, ClingoError> {
+        if !matches!(self.symbol_type()?, SymbolType::Function)
+            || self.is_negative()?
+            || self.name()? != name
+        {
+            return Ok(None);
+        }
+        let arguments = self.arguments()?;
+        if arguments.len() != arity {
+            return Ok(None);
+        }
+        Ok(Some(arguments))
+    }
+
+    /// Get the arguments of a symbol that has to be a positive function with the given name and arity.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::FromSymbolError`] if the symbol has a different type, name or arity
+    pub fn expect_function(self, name: &str, arity: usize) -> Result<Vec<Symbol>, ClingoError> {
+        match self.match_function(name, arity)? {
+            Some(arguments) => Ok(arguments),
+            None if name.is_empty() => Err(FromSymbolError {
+                msg: format!("expected a tuple of size {arity} but found `{self}`"),
+            }
+            .into()),
+            None => Err(FromSymbolError {
+                msg: format!("expected {name}/{arity} but found `{self}`"),
+            }
+            .into()),
+        }
+    }
 }
 
 /// Obtain the clingo version.
@@ -1711,13 +1785,28 @@ impl ControlCtx for DefaultCtx {
 }
 
 /// Control object holding grounding and solving state.
+///
+/// # Thread safety
+///
+/// A control object is [`Send`] if its context is [`Send`], i.e., it can be moved to another thread,
+/// for example into a request handler, but it can not be shared between threads.
+/// The only operation that can be triggered from another thread while solving is an interrupt,
+/// see [`AsyncSolveHandle::interrupter()`].
 #[derive(Debug)]
 pub struct GenericControl<C: ControlCtx> {
     ctl: NonNull<clingo_control_t>,
     copied: bool,
     context: Box<C>,
+    // propagators added with add_propagator(), only kept alive until the control is dropped
+    propagators: Vec<Box<dyn std::any::Any + Send>>,
+    // observer registered by ground_with_observer(), forwards to the observer of the current call
+    forward_observer: Option<Box<ForwardObserver>>,
 }
 pub type Control = GenericControl<DefaultCtx>;
+// A control object is not bound to the thread that created it, clingo only requires that it is not
+// used concurrently, which is ensured by taking `&mut self` or `self` in all methods that modify it.
+// The context and propagators are moved along with the control and are called from solver threads.
+unsafe impl<C: ControlCtx + Send> Send for GenericControl<C> {}
 impl<C: ControlCtx> Drop for GenericControl<C> {
     fn drop(&mut self) {
         if !self.copied {
@@ -1776,6 +1865,8 @@ impl<C: ControlCtx> GenericControl<C> {
             ctl: self.ctl,
             copied: false,
             context,
+            propagators: std::mem::take(&mut self.propagators),
+            forward_observer: self.forward_observer.take(),
         }
     }
     /// Solve the currently grounded ([`Control::ground()`]) logic program
@@ -1832,6 +1923,9 @@ impl<C: ControlCtx> GenericControl<C> {
     /// * `assumptions` - array of assumptions to solve under
     /// * `handler` - implementing the trait [`SolveEventHandler`]
     ///
+    /// **Note:** With [`SolveMode::ASYNC`] the handler is called from the solver thread,
+    /// state shared with the handler has to be synchronized, e.g., via an [`Arc`](std::sync::Arc).
+    ///
     /// # Errors
     ///
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
@@ -1870,6 +1964,119 @@ impl<C: ControlCtx> GenericControl<C> {
             })?,
         }
     }
+    /// Solve the currently grounded ([`Control::ground()`]) logic program
+    /// enumerating its models without giving up the control object.
+    ///
+    /// The returned handle borrows the control object mutably,
+    /// it can be used again after the handle is closed or dropped.
+    /// This is convenient for multi-shot solving, e.g.,
+    /// grounding further parts or assigning externals between solve calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - configures the search mode
+    /// * `assumptions` - array of assumptions to solve under
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving could not be started
+    pub fn solve_mut(
+        &mut self,
+        mode: SolveMode,
+        assumptions: &[SolverLiteral],
+    ) -> Result<GenericSolveHandleRef<'_, C, defaults::Non>, ClingoError> {
+        self.solve_mut_with_event_handler(mode, assumptions, defaults::Non)
+    }
+    /// Solve the currently grounded ([`Control::ground()`]) logic program
+    /// enumerating its models without giving up the control object.
+    ///
+    /// See [`GenericControl::solve_mut()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - configures the search mode
+    /// * `assumptions` - array of assumptions to solve under
+    /// * `handler` - implementing the trait [`SolveEventHandler`]
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving could not be started
+    pub fn solve_mut_with_event_handler<T: SolveEventHandler>(
+        &mut self,
+        mode: SolveMode,
+        assumptions: &[SolverLiteral],
+        event_handler: T,
+    ) -> Result<GenericSolveHandleRef<'_, C, T>, ClingoError> {
+        let mut handle = std::ptr::null_mut();
+        let mut event_handler = Box::new(event_handler);
+        if !unsafe {
+            clingo_control_solve(
+                self.ctl.as_ptr(),
+                mode.bits(),
+                assumptions.as_ptr() as *const clingo_literal_t,
+                assumptions.len(),
+                Some(unsafe_solve_callback::<T> as SolveEventCallback),
+                event_handler.as_mut() as *mut T as *mut c_void,
+                &mut handle,
+            )
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_control_solve() failed",
+            ));
+        }
+        match NonNull::new(handle) {
+            Some(handle) => Ok(GenericSolveHandleRef {
+                handle,
+                _ctl: self,
+                _event_handler: event_handler,
+                closed: false,
+            }),
+            None => Err(ClingoError::FFIError {
+                msg: "Tried creating NonNull from a null pointer.",
+            })?,
+        }
+    }
+    /// Solve the currently grounded ([`Control::ground()`]) logic program in the background.
+    ///
+    /// The returned handle yields the models as futures, see [`AsyncSolveHandle::next_model()`].
+    /// The search uses [`SolveMode::ASYNC`] and [`SolveMode::YIELD`], i.e.,
+    /// it runs in a separate thread and pauses after each model until the next one is requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `assumptions` - array of assumptions to solve under
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving could not be started
+    pub fn solve_async(
+        self,
+        assumptions: &[SolverLiteral],
+    ) -> Result<AsyncSolveHandle<C>, ClingoError> {
+        let state = Arc::new(Mutex::new(AsyncSolveState {
+            ctl: Some(ControlPtr(self.ctl)),
+            model: None,
+            result: None,
+            error: None,
+            waker: None,
+        }));
+        let event_handler = AsyncSolveEventHandler {
+            state: state.clone(),
+        };
+        let handle = self.solve_with_event_handler(
+            SolveMode::ASYNC | SolveMode::YIELD,
+            assumptions,
+            event_handler,
+        )?;
+        Ok(AsyncSolveHandle {
+            handle: Some(handle),
+            state,
+            resume: false,
+        })
+    }
 
     // NODO: pub fn clingo_control_load(control: *mut Control, file: *const c_char) -> bool;
 
@@ -2033,6 +2240,51 @@ impl<C: ControlCtx> GenericControl<C> {
         Ok(())
     }
 
+    /// Register an additional propagator with the control object.
+    ///
+    /// In contrast to the propagator of the [`ControlCtx`], any number of propagators can be added this way.
+    /// The propagator has to be [`Send`] because it is called from the solver threads.
+    /// The control object takes ownership of the propagator,
+    /// state that has to be accessed after solving should be shared with the propagator, e.g., via an [`Arc`](std::sync::Arc).
+    ///
+    /// # Arguments
+    ///
+    /// * `propagator` - the propagator
+    /// * `sequential` - whether the propagator should be called sequentially when solving with multiple threads
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    pub fn add_propagator<P: Propagator + Send + 'static>(
+        &mut self,
+        propagator: P,
+        sequential: bool,
+    ) -> Result<(), ClingoError> {
+        let mut propagator = Box::new(propagator);
+        let clingo_propagator = clingo_propagator_t {
+            init: Some(unsafe_init::<P>),
+            propagate: Some(unsafe_propagate::<P>),
+            undo: Some(unsafe_undo::<P>),
+            check: Some(unsafe_check::<P>),
+            decide: Some(unsafe_decide::<P>),
+        };
+        if !unsafe {
+            clingo_control_register_propagator(
+                self.ctl.as_ptr(),
+                &clingo_propagator,
+                propagator.as_mut() as *mut P as *mut c_void,
+                sequential,
+            )
+        } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_control_register_propagator() failed",
+            ));
+        }
+        // the box keeps the address of the propagator stable
+        self.propagators.push(propagator);
+        Ok(())
+    }
+
     /// Check if the solver has determined that the internal program representation is conflicting.
     ///
     /// If this function returns true, solve calls will return immediately with an unsatisfiable solve result.
@@ -2238,42 +2490,55 @@ impl<C: ControlCtx> GenericControl<C> {
     /// **Returns** whether the call was successful
     fn register_observer(&mut self) -> Result<(), ClingoError> {
         let (observer, replace) = self.context.observer();
-        let gpo = clingo_ground_program_observer_t {
-            init_program: Some(unsafe_init_program::<C::O>),
-            begin_step: Some(unsafe_begin_step::<C::O>),
-            end_step: Some(unsafe_end_step::<C::O>),
-            rule: Some(unsafe_rule::<C::O>),
-            weight_rule: Some(unsafe_weight_rule::<C::O>),
-            minimize: Some(unsafe_minimize::<C::O>),
-            project: Some(unsafe_project::<C::O>),
-            output_atom: Some(unsafe_output_atom::<C::O>),
-            output_term: Some(unsafe_output_term::<C::O>),
-            external: Some(unsafe_external::<C::O>),
-            assume: Some(unsafe_assume::<C::O>),
-            heuristic: Some(unsafe_heuristic::<C::O>),
-            acyc_edge: Some(unsafe_acyc_edge::<C::O>),
-            theory_term_number: Some(unsafe_theory_term_number::<C::O>),
-            theory_term_string: Some(unsafe_theory_term_string::<C::O>),
-            theory_term_compound: Some(unsafe_theory_term_compound::<C::O>),
-            theory_element: Some(unsafe_theory_element::<C::O>),
-            theory_atom: Some(unsafe_theory_atom::<C::O>),
-            theory_atom_with_guard: Some(unsafe_theory_atom_with_guard::<C::O>),
-        };
-        if !unsafe {
-            clingo_control_register_observer(
-                self.ctl.as_ptr(),
-                &gpo,
-                replace,
-                observer as *mut C::O as *mut c_void,
-            )
-        } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_control_register_observer() failed",
-            ));
-        }
+        register_observer(self.ctl, observer, replace)?;
         self.copied = true;
         Ok(())
     }
+    /// Ground the selected parts like [`Control::ground()`] while passing the resulting ground
+    /// program to `observer`.
+    ///
+    /// In contrast to an observer of a [`ControlCtx`], the observer only needs to live for the
+    /// duration of the call and only sees the directives of this grounding step.
+    /// It is called in addition to the observer of the context.
+    ///
+    /// ```ignore
+    /// let mut printer = GroundProgramPrinter::new();
+    /// ctl.ground_with_observer(&[Part::new("base", vec![])?], &mut printer)?;
+    /// let program = printer.to_string();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` -  array of parts to ground
+    /// * `observer` - an implementation of [`GroundProgramObserver`], e.g., a
+    ///   [`GroundProgramPrinter`](printer::GroundProgramPrinter)
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    pub fn ground_with_observer<O: GroundProgramObserver>(
+        &mut self,
+        parts: &[Part],
+        observer: &mut O,
+    ) -> Result<(), ClingoError> {
+        if self.forward_observer.is_none() {
+            let mut forward = Box::new(ForwardObserver { target: None });
+            register_observer(self.ctl, forward.as_mut(), false)?;
+            self.forward_observer = Some(forward);
+        }
+        let target: *mut (dyn GroundProgramObserver + '_) = observer;
+        // the target is reset before returning, so it never outlives the borrow of `observer`
+        let target: *mut (dyn GroundProgramObserver + 'static) =
+            unsafe { std::mem::transmute(target) };
+        if let Some(forward) = self.forward_observer.as_mut() {
+            forward.target = Some(target);
+        }
+        let result = self.ground(parts);
+        if let Some(forward) = self.forward_observer.as_mut() {
+            forward.target = None;
+        }
+        result
+    }
     /// Get an object to add ground directives to the program.
     ///
     /// # Errors
@@ -2328,6 +2593,8 @@ impl<C: ControlCtx> GenericControl<C> {
     /// Covenience function that returns an iterator over the models.
     /// Uses [`Control::solve()`] with [SolveMode::YIELD] and empty assumptions.
     ///
+    /// The control object can be recovered with [`AllModels::close()`].
+    ///
     /// # Errors
     ///
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
@@ -2351,11 +2618,15 @@ impl<C: ControlCtx> GenericControl<C> {
             ));
         }
         match NonNull::new(handle) {
-            Some(handle) => Ok(AllModels(GenericSolveHandle {
-                handle,
-                ctl: self,
-                _event_handler: Box::new(defaults::Non),
-            })),
+            Some(handle) => Ok(AllModels {
+                handle: GenericSolveHandle {
+                    handle,
+                    ctl: self,
+                    _event_handler: Box::new(defaults::Non),
+                },
+                error: None,
+                done: false,
+            }),
             None => Err(ClingoError::FFIError {
                 msg: "Tried creating NonNull from a null pointer.",
             })?,
@@ -2365,6 +2636,8 @@ impl<C: ControlCtx> GenericControl<C> {
     /// Covenience function that returns an iterator over the optimal models.
     /// Uses [`Control::solve()`] with [SolveMode::YIELD] and empty assumptions.
     ///
+    /// The control object can be recovered with [`OptimalModels::close()`].
+    ///
     /// # Errors
     ///
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
@@ -2388,11 +2661,15 @@ impl<C: ControlCtx> GenericControl<C> {
             ));
         }
         match NonNull::new(handle) {
-            Some(handle) => Ok(OptimalModels(GenericSolveHandle {
-                handle,
-                ctl: self,
-                _event_handler: Box::new(defaults::Non),
-            })),
+            Some(handle) => Ok(OptimalModels {
+                handle: GenericSolveHandle {
+                    handle,
+                    ctl: self,
+                    _event_handler: Box::new(defaults::Non),
+                },
+                error: None,
+                done: false,
+            }),
             None => Err(ClingoError::FFIError {
                 msg: "Tried creating NonNull from a null pointer.",
             })?,
@@ -2454,6 +2731,8 @@ pub fn control(arguments: std::vec::Vec<String>) -> Result<Control, ClingoError>
             ctl,
             copied: false,
             context: Box::new(DefaultCtx { non: defaults::Non }),
+            propagators: vec![],
+            forward_observer: None,
         }),
         None => Err(ClingoError::FFIError {
             msg: "Tried creating NonNull from a null pointer.",
@@ -2516,6 +2795,8 @@ pub fn control_with_context<C: ControlCtx>(
                 ctl,
                 copied: false,
                 context: Box::new(context),
+                propagators: vec![],
+                forward_observer: None,
             };
             control.register_observer()?;
             control.register_propagator()?;
@@ -3381,6 +3662,133 @@ impl Statistics {
     pub fn value_set(&mut self, key: u64, value: f64) -> bool {
         unsafe { clingo_statistics_value_set(&mut self.0, key, value) }
     }
+
+    /// Get an owned copy of the whole statistics tree.
+    pub fn snapshot(&self) -> Result<StatsValue, ClingoError> {
+        self.snapshot_at(self.root()?)
+    }
+
+    /// Get an owned copy of the statistics tree below the given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the key
+    pub fn snapshot_at(&self, key: u64) -> Result<StatsValue, ClingoError> {
+        match self.statistics_type(key)? {
+            StatisticsType::Value => Ok(StatsValue::Value(self.value_get(key)?)),
+            StatisticsType::Array => {
+                let mut array = vec![];
+                for offset in 0..self.array_size(key)? {
+                    array.push(self.snapshot_at(self.array_at(key, offset)?)?);
+                }
+                Ok(StatsValue::Array(array))
+            }
+            StatisticsType::Map => {
+                let mut map = BTreeMap::new();
+                for offset in 0..self.map_size(key)? {
+                    let name = self.map_subkey_name(key, offset)?;
+                    let value = self.snapshot_at(self.map_at(key, name)?)?;
+                    map.insert(name.to_string(), value);
+                }
+                Ok(StatsValue::Map(map))
+            }
+            StatisticsType::Empty => Ok(StatsValue::Map(BTreeMap::new())),
+        }
+    }
+}
+
+/// Owned copy of a statistics tree, see [`Statistics::snapshot()`].
+///
+/// With the `serde` feature it (de)serializes as plain numbers, arrays and maps.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum StatsValue {
+    Value(f64),
+    Array(Vec<StatsValue>),
+    Map(BTreeMap<String, StatsValue>),
+}
+impl StatsValue {
+    /// Get the subtree at the given path.
+    ///
+    /// The path consists of map keys and array offsets separated by dots,
+    /// e.g., `"summary.times.total"` or `"solving.threads.0.choices"`.
+    /// The empty path refers to the value itself.
+    pub fn get(&self, path: &str) -> Option<&StatsValue> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split('.').try_fold(self, |value, key| match value {
+            StatsValue::Map(map) => map.get(key),
+            StatsValue::Array(array) => array.get(key.parse::<usize>().ok()?),
+            StatsValue::Value(_) => None,
+        })
+    }
+
+    /// Get the number at the given path, see [`StatsValue::get()`].
+    pub fn value(&self, path: &str) -> Option<f64> {
+        self.get(path)?.as_f64()
+    }
+
+    /// Get the number if this is a [`StatsValue::Value`].
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            StatsValue::Value(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Convert the tree to a JSON string.
+    ///
+    /// Maps become objects, arrays become arrays and values become numbers,
+    /// where values that are not finite are written as `null`. Finite values
+    /// are written exactly, a parser rounding correctly reads them back unchanged.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    fn write_json(&self, out: &mut String) {
+        match self {
+            // the shortest decimal reading back to the same value, integers without fraction
+            StatsValue::Value(value) if value.is_finite() => out.push_str(&value.to_string()),
+            StatsValue::Value(_) => out.push_str("null"),
+            StatsValue::Array(array) => {
+                out.push('[');
+                for (i, value) in array.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.write_json(out);
+                }
+                out.push(']');
+            }
+            StatsValue::Map(map) => {
+                out.push('{');
+                for (i, (key, value)) in map.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push('"');
+                    for c in key.chars() {
+                        match c {
+                            '"' => out.push_str("\\\""),
+                            '\\' => out.push_str("\\\\"),
+                            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                            c => out.push(c),
+                        }
+                    }
+                    out.push_str("\":");
+                    value.write_json(out);
+                }
+                out.push('}');
+            }
+        }
+    }
 }
 /// Container that stores symbolic atoms in a program -- the relevant Herbrand base
 /// gringo uses to instantiate programs.
@@ -3685,6 +4093,9 @@ impl TheoryAtoms {
                 "Call to clingo_theory_atoms_term_arguments() failed",
             ));
         }
+        if size == 0 {
+            return Ok(&[]);
+        }
         let arguments_ref = unsafe { std::slice::from_raw_parts(c_ptr as *const Id, size) };
         Ok(arguments_ref)
     }
@@ -3736,6 +4147,9 @@ impl TheoryAtoms {
                 "Call to clingo_theory_atoms_element_tuple() failed",
             ));
         }
+        if size == 0 {
+            return Ok(&[]);
+        }
         let tuple_ref = unsafe { std::slice::from_raw_parts(tuple_ptr as *const Id, size) };
         Ok(tuple_ref)
     }
@@ -3755,6 +4169,9 @@ impl TheoryAtoms {
                 "Call to clingo_theory_atoms_element_condition() failed",
             ));
         }
+        if size == 0 {
+            return Ok(&[]);
+        }
         let condition_ref =
             unsafe { std::slice::from_raw_parts(condition_ptr as *const SolverLiteral, size) };
         Ok(condition_ref)
@@ -3847,6 +4264,9 @@ impl TheoryAtoms {
                 "Call to clingo_theory_atoms_atom_elements() failed",
             ));
         }
+        if size == 0 {
+            return Ok(&[]);
+        }
         let elements = unsafe { std::slice::from_raw_parts(elements_ptr as *const Id, size) };
         Ok(elements)
     }
@@ -4030,6 +4450,42 @@ impl Model {
         Ok(symbols)
     }
 
+    /// Get the shown atoms of the given signature decoded into `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - the signature of the atoms to select
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if the symbols could not be obtained
+    /// - the error of [`FromSymbol::from_symbol()`] if an atom could not be decoded
+    ///
+    /// ```ignore
+    /// #[derive(FromSymbol)]
+    /// struct Edge(u32, u32);
+    ///
+    /// let edges: Vec<Edge> = model.atoms_of(Signature::new("edge", 2, true)?)?;
+    /// ```
+    pub fn atoms_of<T: FromSymbol>(&self, signature: Signature) -> Result<Vec<T>, ClingoError>
+    where
+        ClingoError: From<T::Error>,
+    {
+        let name = signature.name()?;
+        let mut atoms = vec![];
+        for symbol in self.symbols(ShowType::SHOWN)? {
+            if matches!(symbol.symbol_type()?, SymbolType::Function)
+                && symbol.is_positive()? == signature.is_positive()
+                && symbol.name()? == name
+                && symbol.arguments()?.len() == signature.arity() as usize
+            {
+                atoms.push(T::from_symbol(symbol)?);
+            }
+        }
+        Ok(atoms)
+    }
+
     /// Constant time lookup to test whether an atom is in a model.
     ///
     /// # Arguments
@@ -4958,6 +5414,9 @@ pub struct GenericSolveHandle<C: ControlCtx, E: SolveEventHandler> {
     _event_handler: Box<E>,
 }
 pub type SolveHandle = GenericSolveHandle<DefaultCtx, defaults::Non>;
+// The solve handle owns the control object and the event handler, which is called from the solver
+// thread when solving with SolveMode::ASYNC.
+unsafe impl<C: ControlCtx + Send, E: SolveEventHandler + Send> Send for GenericSolveHandle<C, E> {}
 impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// Get the next solve result.
     ///
@@ -4970,20 +5429,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     /// or [`ErrorCode::Runtime`] if solving fails
     pub fn get(&mut self) -> Result<SolveResult, ClingoError> {
-        let mut result = 0;
-        if !unsafe { clingo_solve_handle_get(self.handle.as_ptr(), &mut result) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_get() failed",
-            ));
-        }
-        if let Some(res) = SolveResult::from_bits(result) {
-            Ok(res)
-        } else {
-            eprintln!("Unknown bitflag in clingo_solve_result: {}.", result);
-            Err(ClingoError::FFIError {
-                msg: "Unknown bitflag in clingo_solve_result.",
-            })
-        }
+        solve_handle_get(self.handle)
     }
     /// Wait for the specified amount of time to check if the next result is ready.
     ///
@@ -4994,11 +5440,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     ///
     /// * `timeout` - the maximum time to wait
     pub fn wait(&mut self, timeout: Duration) -> bool {
-        let mut result = false;
-        let timeout_secs = timeout.as_secs_f64();
-        unsafe { clingo_solve_handle_wait(self.handle.as_ptr(), timeout_secs, &mut result) }
-
-        result
+        solve_handle_wait(self.handle, timeout)
     }
     /// Get the next model or None if there are no more models.
     ///
@@ -5007,13 +5449,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     /// or [`ErrorCode::Runtime`] if solving fails
     pub fn model(&mut self) -> Result<Option<&Model>, ClingoError> {
-        let mut model = std::ptr::null_mut() as *const clingo_model_t;
-        if !unsafe { clingo_solve_handle_model(self.handle.as_ptr(), &mut model) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_model() failed",
-            ));
-        }
-        Ok(unsafe { (model as *const Model).as_ref() })
+        solve_handle_model(self.handle)
     }
     /// Get the next model or None if there are no more models.
     ///
@@ -5022,13 +5458,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     /// or [`ErrorCode::Runtime`] if solving fails
     pub fn model_mut(&mut self) -> Result<Option<&mut Model>, ClingoError> {
-        let mut model = std::ptr::null_mut() as *const clingo_model_t;
-        if !unsafe { clingo_solve_handle_model(self.handle.as_ptr(), &mut model) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_model() failed",
-            ));
-        }
-        Ok(unsafe { (model as *mut Model).as_mut() })
+        solve_handle_model_mut(self.handle)
     }
     /// When a problem is unsatisfiable, get a subset of the assumptions that made the problem unsatisfiable.
     ///
@@ -5040,27 +5470,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     ///
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     pub fn core(&mut self) -> Result<Vec<SolverLiteral>, ClingoError> {
-        let mut literal_ptr = std::ptr::null();
-        let mut size: usize = 0;
-        if !unsafe { clingo_solve_handle_core(self.handle.as_ptr(), &mut literal_ptr, &mut size) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_core() failed",
-            ));
-        }
-        // let literals = unsafe {std::slice::from_raw_parts(literal_ptr, size)};
-
-        let mut literals = Vec::<SolverLiteral>::with_capacity(size);
-        for _ in 0..size {
-            if literal_ptr.is_null() {
-                return Err(ClingoError::FFIError {
-                    msg: "clingo_solve_handle_core() returned a null pointer.",
-                });
-            }
-            let nliteral = unsafe { *literal_ptr };
-            literals.push(SolverLiteral(nliteral));
-            literal_ptr = unsafe { literal_ptr.offset(1) };
-        }
-        Ok(literals)
+        solve_handle_core(self.handle)
     }
     /// Discards the last model and starts the search for the next one.
     ///
@@ -5074,12 +5484,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     /// or [`ErrorCode::Runtime`] if solving fails
     pub fn resume(&mut self) -> Result<(), ClingoError> {
-        if !unsafe { clingo_solve_handle_resume(self.handle.as_ptr()) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_resume() failed",
-            ));
-        }
-        Ok(())
+        solve_handle_resume(self.handle)
     }
     /// Stop the running search and block until done.
     ///
@@ -5088,12 +5493,7 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     /// or [`ErrorCode::Runtime`] if solving fails
     pub fn cancel(&mut self) -> Result<(), ClingoError> {
-        if !unsafe { clingo_solve_handle_cancel(self.handle.as_ptr()) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_cancel() failed",
-            ));
-        }
-        Ok(())
+        solve_handle_cancel(self.handle)
     }
     /// Stops the running search and releases the handle.
     ///
@@ -5105,75 +5505,542 @@ impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandle<C, E> {
     /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
     /// or [`ErrorCode::Runtime`] if solving fails
     pub fn close(self) -> Result<GenericControl<C>, ClingoError> {
-        if !unsafe { clingo_solve_handle_close(self.handle.as_ptr()) } {
-            return Err(ClingoError::new_internal(
-                "Call to clingo_solve_handle_close() failed",
-            ));
-        }
+        solve_handle_close(self.handle)?;
         Ok(self.ctl)
     }
 }
-pub struct OptimalModels<C: ControlCtx, E: SolveEventHandler>(GenericSolveHandle<C, E>);
-impl<C: ControlCtx, E: SolveEventHandler> Iterator for OptimalModels<C, E> {
-    type Item = MModel;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            self.0.resume().expect("Failed resume on solve handle.");
-            match self.0.model() {
-                Ok(Some(model)) => {
-                    if model.optimality_proven().unwrap() {
-                        let symbols = model.symbols(ShowType::SHOWN).unwrap();
-                        let cost = model.cost().unwrap();
-                        return Some(MModel {
-                            symbols,
-                            cost,
-                            model_type: model.model_type().unwrap(),
-                            number: model.number().unwrap(),
-                        });
-                    }
-                }
-                Ok(None) => {
-                    return None;
-                }
-                Err(e) => panic!("{}", e),
-            }
-        }
-    }
+// The following functions implement the methods shared by GenericSolveHandle and GenericSolveHandleRef.
+fn solve_handle_get(handle: NonNull<clingo_solve_handle_t>) -> Result<SolveResult, ClingoError> {
+    let mut result = 0;
+    if !unsafe { clingo_solve_handle_get(handle.as_ptr(), &mut result) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_get() failed",
+        ));
+    }
+    if let Some(res) = SolveResult::from_bits(result) {
+        Ok(res)
+    } else {
+        eprintln!("Unknown bitflag in clingo_solve_result: {}.", result);
+        Err(ClingoError::FFIError {
+            msg: "Unknown bitflag in clingo_solve_result.",
+        })
+    }
+}
+fn solve_handle_wait(handle: NonNull<clingo_solve_handle_t>, timeout: Duration) -> bool {
+    let mut result = false;
+    let timeout_secs = timeout.as_secs_f64();
+    unsafe { clingo_solve_handle_wait(handle.as_ptr(), timeout_secs, &mut result) }
+
+    result
+}
+fn solve_handle_model<'a>(
+    handle: NonNull<clingo_solve_handle_t>,
+) -> Result<Option<&'a Model>, ClingoError> {
+    let mut model = std::ptr::null_mut() as *const clingo_model_t;
+    if !unsafe { clingo_solve_handle_model(handle.as_ptr(), &mut model) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_model() failed",
+        ));
+    }
+    Ok(unsafe { (model as *const Model).as_ref() })
+}
+fn solve_handle_model_mut<'a>(
+    handle: NonNull<clingo_solve_handle_t>,
+) -> Result<Option<&'a mut Model>, ClingoError> {
+    let mut model = std::ptr::null_mut() as *const clingo_model_t;
+    if !unsafe { clingo_solve_handle_model(handle.as_ptr(), &mut model) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_model() failed",
+        ));
+    }
+    Ok(unsafe { (model as *mut Model).as_mut() })
+}
+fn solve_handle_core(
+    handle: NonNull<clingo_solve_handle_t>,
+) -> Result<Vec<SolverLiteral>, ClingoError> {
+    let mut literal_ptr = std::ptr::null();
+    let mut size: usize = 0;
+    if !unsafe { clingo_solve_handle_core(handle.as_ptr(), &mut literal_ptr, &mut size) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_core() failed",
+        ));
+    }
+    // let literals = unsafe {std::slice::from_raw_parts(literal_ptr, size)};
+
+    let mut literals = Vec::<SolverLiteral>::with_capacity(size);
+    for _ in 0..size {
+        if literal_ptr.is_null() {
+            return Err(ClingoError::FFIError {
+                msg: "clingo_solve_handle_core() returned a null pointer.",
+            });
+        }
+        let nliteral = unsafe { *literal_ptr };
+        literals.push(SolverLiteral(nliteral));
+        literal_ptr = unsafe { literal_ptr.offset(1) };
+    }
+    Ok(literals)
+}
+fn solve_handle_resume(handle: NonNull<clingo_solve_handle_t>) -> Result<(), ClingoError> {
+    if !unsafe { clingo_solve_handle_resume(handle.as_ptr()) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_resume() failed",
+        ));
+    }
+    Ok(())
+}
+fn solve_handle_cancel(handle: NonNull<clingo_solve_handle_t>) -> Result<(), ClingoError> {
+    if !unsafe { clingo_solve_handle_cancel(handle.as_ptr()) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_cancel() failed",
+        ));
+    }
+    Ok(())
+}
+fn solve_handle_close(handle: NonNull<clingo_solve_handle_t>) -> Result<(), ClingoError> {
+    if !unsafe { clingo_solve_handle_close(handle.as_ptr()) } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_solve_handle_close() failed",
+        ));
+    }
+    Ok(())
+}
+
+/// Search handle to a solve call that borrows the control object, see [`GenericControl::solve_mut()`].
+///
+/// Dropping the handle stops the search and releases the handle like [`GenericSolveHandleRef::close()`].
+#[derive(Debug)]
+pub struct GenericSolveHandleRef<'a, C: ControlCtx, E: SolveEventHandler> {
+    handle: NonNull<clingo_solve_handle_t>,
+    // the control object must not be used while the search is active
+    _ctl: &'a mut GenericControl<C>,
+    _event_handler: Box<E>,
+    closed: bool,
+}
+pub type SolveHandleRef<'a> = GenericSolveHandleRef<'a, DefaultCtx, defaults::Non>;
+// see GenericSolveHandle
+unsafe impl<C: ControlCtx + Send, E: SolveEventHandler + Send> Send
+    for GenericSolveHandleRef<'_, C, E>
+{
+}
+impl<C: ControlCtx, E: SolveEventHandler> GenericSolveHandleRef<'_, C, E> {
+    /// Get the next solve result.
+    ///
+    /// See [`GenericSolveHandle::get()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn get(&mut self) -> Result<SolveResult, ClingoError> {
+        solve_handle_get(self.handle)
+    }
+    /// Wait for the specified amount of time to check if the next result is ready.
+    ///
+    /// See [`GenericSolveHandle::wait()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - the maximum time to wait
+    pub fn wait(&mut self, timeout: Duration) -> bool {
+        solve_handle_wait(self.handle, timeout)
+    }
+    /// Get the next model or None if there are no more models.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn model(&mut self) -> Result<Option<&Model>, ClingoError> {
+        solve_handle_model(self.handle)
+    }
+    /// Get the next model or None if there are no more models.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn model_mut(&mut self) -> Result<Option<&mut Model>, ClingoError> {
+        solve_handle_model_mut(self.handle)
+    }
+    /// When a problem is unsatisfiable, get a subset of the assumptions that made the problem unsatisfiable.
+    ///
+    /// If the program is not unsatisfiable, an empty vector is returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    pub fn core(&mut self) -> Result<Vec<SolverLiteral>, ClingoError> {
+        solve_handle_core(self.handle)
+    }
+    /// Discards the last model and starts the search for the next one.
+    ///
+    /// See [`GenericSolveHandle::resume()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn resume(&mut self) -> Result<(), ClingoError> {
+        solve_handle_resume(self.handle)
+    }
+    /// Stop the running search and block until done.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn cancel(&mut self) -> Result<(), ClingoError> {
+        solve_handle_cancel(self.handle)
+    }
+    /// Stops the running search and releases the handle, afterwards the control object can be used again.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn close(mut self) -> Result<(), ClingoError> {
+        self.closed = true;
+        solve_handle_close(self.handle)
+    }
+}
+impl<C: ControlCtx, E: SolveEventHandler> Drop for GenericSolveHandleRef<'_, C, E> {
+    fn drop(&mut self) {
+        if !self.closed {
+            // errors can only be reported by calling close() explicitly
+            let _ = solve_handle_close(self.handle);
+        }
+    }
+}
+
+// pointer to a control object that can be used to interrupt a search from another thread
+#[derive(Debug)]
+struct ControlPtr(NonNull<clingo_control_t>);
+// clingo_control_interrupt() is thread-safe, the pointer is only used while the control is alive
+unsafe impl Send for ControlPtr {}
+
+#[derive(Debug)]
+struct AsyncSolveState {
+    // cleared before the control object is released
+    ctl: Option<ControlPtr>,
+    model: Option<MModel>,
+    result: Option<SolveResult>,
+    error: Option<ClingoError>,
+    waker: Option<std::task::Waker>,
+}
+fn lock_state(state: &Mutex<AsyncSolveState>) -> std::sync::MutexGuard<'_, AsyncSolveState> {
+    // the state stays consistent even if a thread panicked while holding the lock
+    state.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+#[derive(Debug)]
+struct AsyncSolveEventHandler {
+    state: Arc<Mutex<AsyncSolveState>>,
+}
+impl SolveEventHandler for AsyncSolveEventHandler {
+    fn on_solve_event(&mut self, event: SolveEvent, _goon: &mut bool) -> bool {
+        let mut state = lock_state(&self.state);
+        let ok = match event {
+            SolveEvent::Model(model) => match MModel::new(model) {
+                Ok(model) => {
+                    state.model = Some(model);
+                    true
+                }
+                Err(error) => {
+                    state.error = Some(error);
+                    false
+                }
+            },
+            SolveEvent::Finish(result) => {
+                state.result = Some(SolveResult::from_bits_retain(result.bits()));
+                true
+            }
+            _ => return true,
+        };
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        ok
+    }
+}
+
+/// Handle to a search running in the background, see [`GenericControl::solve_async()`].
+///
+/// The handle owns the control object, which is returned by [`AsyncSolveHandle::close()`].
+/// Dropping the handle cancels the search.
+#[derive(Debug)]
+pub struct AsyncSolveHandle<C: ControlCtx> {
+    handle: Option<GenericSolveHandle<C, AsyncSolveEventHandler>>,
+    state: Arc<Mutex<AsyncSolveState>>,
+    // whether a model has been returned and the search has to be resumed
+    resume: bool,
+}
+impl<C: ControlCtx> AsyncSolveHandle<C> {
+    /// Get a future that resolves to the next model or `None` if the search is finished.
+    ///
+    /// The search is paused after each model until the next future returned by this function is
+    /// polled.
+    pub fn next_model(&mut self) -> NextModel<'_, C> {
+        NextModel { handle: self }
+    }
+
+    /// Resume the search after the last model without blocking.
+    ///
+    /// Returns `false` if the handle is not ready yet, i.e., the event handler for the model has
+    /// not finished.
+    fn try_resume(&mut self) -> Result<bool, ClingoError> {
+        let handle = match self.handle.as_mut() {
+            Some(handle) => handle,
+            None => {
+                self.resume = false;
+                return Ok(true);
+            }
+        };
+        let mut ready = false;
+        unsafe { clingo_solve_handle_wait(handle.handle.as_ptr(), 0.0, &mut ready) };
+        if !ready {
+            return Ok(false);
+        }
+        // a zero timeout only polls, waiting on a ready handle returns immediately and
+        // acknowledges the model so that it can be resumed
+        unsafe { clingo_solve_handle_wait(handle.handle.as_ptr(), -1.0, &mut ready) };
+        self.resume = false;
+        handle.resume()?;
+        Ok(true)
+    }
+
+    /// Get an object that can be used to interrupt the search from another thread.
+    pub fn interrupter(&self) -> SolveInterrupter {
+        SolveInterrupter {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Get the result of the search if it is finished.
+    pub fn result(&self) -> Option<SolveResult> {
+        lock_state(&self.state)
+            .result
+            .as_ref()
+            .map(|result| SolveResult::from_bits_retain(result.bits()))
+    }
+
+    /// Stop the search if it is still running and return the control object.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn close(mut self) -> Result<GenericControl<C>, ClingoError> {
+        lock_state(&self.state).ctl = None;
+        match self.handle.take() {
+            Some(handle) => handle.close(),
+            None => Err(ClingoError::FFIError {
+                msg: "AsyncSolveHandle has already been closed.",
+            }),
+        }
+    }
+}
+impl<C: ControlCtx> Drop for AsyncSolveHandle<C> {
+    fn drop(&mut self) {
+        lock_state(&self.state).ctl = None;
+        if let Some(handle) = self.handle.take() {
+            // the search has to be stopped before the control object is released
+            let _ = handle.close();
+        }
+    }
+}
+
+/// Future returned by [`AsyncSolveHandle::next_model()`].
+///
+/// Polling never blocks, the search is resumed once clingo has finished handling the last model.
+#[derive(Debug)]
+pub struct NextModel<'a, C: ControlCtx> {
+    handle: &'a mut AsyncSolveHandle<C>,
+}
+impl<C: ControlCtx> std::future::Future for NextModel<'_, C> {
+    type Output = Result<Option<MModel>, ClingoError>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if self.handle.resume {
+            match self.handle.try_resume() {
+                Ok(true) => {}
+                Ok(false) => {
+                    // the handle becomes ready right after the event handler returns
+                    cx.waker().wake_by_ref();
+                    return std::task::Poll::Pending;
+                }
+                Err(error) => return std::task::Poll::Ready(Err(error)),
+            }
+        }
+        let mut state = lock_state(&self.handle.state);
+        if let Some(error) = state.error.take() {
+            return std::task::Poll::Ready(Err(error));
+        }
+        if let Some(model) = state.model.take() {
+            drop(state);
+            self.handle.resume = true;
+            return std::task::Poll::Ready(Ok(Some(model)));
+        }
+        if state.result.is_some() {
+            return std::task::Poll::Ready(Ok(None));
+        }
+        state.waker = Some(cx.waker().clone());
+        std::task::Poll::Pending
+    }
+}
+
+/// Interrupts a search running in the background, see [`AsyncSolveHandle::interrupter()`].
+///
+/// The interrupter can be sent to and cloned across threads.
+/// Interrupting a search that has already finished or whose handle has been closed has no effect.
+#[derive(Debug, Clone)]
+pub struct SolveInterrupter {
+    state: Arc<Mutex<AsyncSolveState>>,
+}
+impl SolveInterrupter {
+    /// Interrupt the search, the pending [`NextModel`] future resolves to `None`
+    /// and the result is [`SolveResult::INTERRUPTED`].
+    pub fn interrupt(&self) {
+        if let Some(ControlPtr(ctl)) = &lock_state(&self.state).ctl {
+            unsafe { clingo_control_interrupt(ctl.as_ptr()) }
+        }
+    }
+}
+
+/// Iterator over the optimal models, see [`GenericControl::optimal_models()`].
+///
+/// If solving fails, the iteration stops and the error is available via [`OptimalModels::error()`].
+/// The control object is returned by [`OptimalModels::close()`].
+#[derive(Debug)]
+pub struct OptimalModels<C: ControlCtx, E: SolveEventHandler> {
+    handle: GenericSolveHandle<C, E>,
+    error: Option<ClingoError>,
+    done: bool,
+}
+impl<C: ControlCtx, E: SolveEventHandler> OptimalModels<C, E> {
+    /// Get the error that stopped the iteration, if any.
+    pub fn error(&self) -> Option<&ClingoError> {
+        self.error.as_ref()
+    }
+    /// Stop the search and return the control object.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn close(self) -> Result<GenericControl<C>, ClingoError> {
+        self.handle.close()
+    }
+    fn next_model(&mut self) -> Result<Option<MModel>, ClingoError> {
+        loop {
+            self.handle.resume()?;
+            match self.handle.model()? {
+                Some(model) => {
+                    if model.optimality_proven()? {
+                        return MModel::new(model).map(Some);
+                    }
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+}
+impl<C: ControlCtx, E: SolveEventHandler> Iterator for OptimalModels<C, E> {
+    type Item = MModel;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_model() {
+            Ok(Some(model)) => Some(model),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                self.error = Some(error);
+                None
+            }
+        }
+    }
+}
+/// Iterator over the models, see [`GenericControl::all_models()`].
+///
+/// If solving fails, the iteration stops and the error is available via [`AllModels::error()`].
+/// The control object is returned by [`AllModels::close()`].
+#[derive(Debug)]
+pub struct AllModels<C: ControlCtx, E: SolveEventHandler> {
+    handle: GenericSolveHandle<C, E>,
+    error: Option<ClingoError>,
+    done: bool,
+}
+impl<C: ControlCtx, E: SolveEventHandler> AllModels<C, E> {
+    /// Get the error that stopped the iteration, if any.
+    pub fn error(&self) -> Option<&ClingoError> {
+        self.error.as_ref()
+    }
+    /// Stop the search and return the control object.
+    ///
+    /// # Errors
+    ///
+    /// - [`ClingoError::InternalError`] with [`ErrorCode::BadAlloc`]
+    ///   or [`ErrorCode::Runtime`] if solving fails
+    pub fn close(self) -> Result<GenericControl<C>, ClingoError> {
+        self.handle.close()
+    }
+    fn next_model(&mut self) -> Result<Option<MModel>, ClingoError> {
+        self.handle.resume()?;
+        match self.handle.model()? {
+            Some(model) => MModel::new(model).map(Some),
+            None => Ok(None),
+        }
+    }
 }
-pub struct AllModels<C: ControlCtx, E: SolveEventHandler>(GenericSolveHandle<C, E>);
 impl<C: ControlCtx, E: SolveEventHandler> Iterator for AllModels<C, E> {
     type Item = MModel;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            self.0.resume().expect("Failed resume on solve handle.");
-            match self.0.model() {
-                Ok(Some(model)) => {
-                    let symbols = model.symbols(ShowType::SHOWN).unwrap();
-                    let cost = model.cost().unwrap();
-                    return Some(MModel {
-                        symbols,
-                        cost,
-                        model_type: model.model_type().unwrap(),
-                        number: model.number().unwrap(),
-                    });
-                }
-                Ok(None) => {
-                    return None;
-                }
-                Err(e) => panic!("{}", e),
+        if self.done {
+            return None;
+        }
+        match self.next_model() {
+            Ok(Some(model)) => Some(model),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                self.error = Some(error);
+                None
             }
         }
     }
 }
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MModel {
     pub symbols: Vec<Symbol>,
     pub cost: Vec<i64>,
     pub model_type: ModelType,
     pub number: u64,
 }
+impl MModel {
+    fn new(model: &Model) -> Result<MModel, ClingoError> {
+        Ok(MModel {
+            symbols: model.symbols(ShowType::SHOWN)?,
+            cost: model.cost()?,
+            model_type: model.model_type()?,
+            number: model.number()?,
+        })
+    }
+}
 // #[doc = "! Callback to customize clingo main function."]
 // #[doc = "!"]
 // #[doc = "! @param[in] control corresponding control object"]
@@ -5765,6 +6632,170 @@ pub trait GroundProgramObserver {
         true
     }
 }
+/// Register `observer` as program observer of the control object.
+fn register_observer<O: GroundProgramObserver>(
+    ctl: NonNull<clingo_control_t>,
+    observer: &mut O,
+    replace: bool,
+) -> Result<(), ClingoError> {
+    let gpo = clingo_ground_program_observer_t {
+        init_program: Some(unsafe_init_program::<O>),
+        begin_step: Some(unsafe_begin_step::<O>),
+        end_step: Some(unsafe_end_step::<O>),
+        rule: Some(unsafe_rule::<O>),
+        weight_rule: Some(unsafe_weight_rule::<O>),
+        minimize: Some(unsafe_minimize::<O>),
+        project: Some(unsafe_project::<O>),
+        output_atom: Some(unsafe_output_atom::<O>),
+        output_term: Some(unsafe_output_term::<O>),
+        external: Some(unsafe_external::<O>),
+        assume: Some(unsafe_assume::<O>),
+        heuristic: Some(unsafe_heuristic::<O>),
+        acyc_edge: Some(unsafe_acyc_edge::<O>),
+        theory_term_number: Some(unsafe_theory_term_number::<O>),
+        theory_term_string: Some(unsafe_theory_term_string::<O>),
+        theory_term_compound: Some(unsafe_theory_term_compound::<O>),
+        theory_element: Some(unsafe_theory_element::<O>),
+        theory_atom: Some(unsafe_theory_atom::<O>),
+        theory_atom_with_guard: Some(unsafe_theory_atom_with_guard::<O>),
+    };
+    if !unsafe {
+        clingo_control_register_observer(
+            ctl.as_ptr(),
+            &gpo,
+            replace,
+            observer as *mut O as *mut c_void,
+        )
+    } {
+        return Err(ClingoError::new_internal(
+            "Call to clingo_control_register_observer() failed",
+        ));
+    }
+    Ok(())
+}
+
+// Observer forwarding to the observer passed to GenericControl::ground_with_observer().
+#[derive(Debug)]
+struct ForwardObserver {
+    target: Option<*mut dyn GroundProgramObserver>,
+}
+impl ForwardObserver {
+    fn forward(&mut self, f: impl FnOnce(&mut dyn GroundProgramObserver) -> bool) -> bool {
+        match self.target {
+            Some(target) => f(unsafe { &mut *target }),
+            None => true,
+        }
+    }
+}
+impl GroundProgramObserver for ForwardObserver {
+    fn init_program(&mut self, incremental: bool) -> bool {
+        self.forward(|o| o.init_program(incremental))
+    }
+    fn begin_step(&mut self) -> bool {
+        self.forward(|o| o.begin_step())
+    }
+    fn end_step(&mut self) -> bool {
+        self.forward(|o| o.end_step())
+    }
+    fn rule(&mut self, choice: bool, head: &[Atom], body: &[SolverLiteral]) -> bool {
+        self.forward(|o| o.rule(choice, head, body))
+    }
+    fn weight_rule(
+        &mut self,
+        choice: bool,
+        head: &[Atom],
+        lower_bound: i32,
+        body: &[WeightedLiteral],
+    ) -> bool {
+        self.forward(|o| o.weight_rule(choice, head, lower_bound, body))
+    }
+    fn minimize(&mut self, priority: i32, literals: &[WeightedLiteral]) -> bool {
+        self.forward(|o| o.minimize(priority, literals))
+    }
+    fn project(&mut self, atoms: &[Atom]) -> bool {
+        self.forward(|o| o.project(atoms))
+    }
+    fn output_atom(&mut self, symbol: Symbol, atom: Atom) -> bool {
+        self.forward(|o| o.output_atom(symbol, atom))
+    }
+    fn output_term(&mut self, symbol: Symbol, condition: &[SolverLiteral]) -> bool {
+        self.forward(|o| o.output_term(symbol, condition))
+    }
+    fn output_csp(&mut self, symbol: Symbol, value: i32, condition: &[SolverLiteral]) -> bool {
+        self.forward(|o| o.output_csp(symbol, value, condition))
+    }
+    fn external(&mut self, atom: Atom, type_: ExternalType) -> bool {
+        self.forward(|o| o.external(atom, type_))
+    }
+    fn assume(&mut self, literals: &[SolverLiteral]) -> bool {
+        self.forward(|o| o.assume(literals))
+    }
+    fn heuristic(
+        &mut self,
+        atom: Atom,
+        type_: HeuristicType,
+        bias: i32,
+        priority: u32,
+        condition: &[SolverLiteral],
+    ) -> bool {
+        self.forward(|o| o.heuristic(atom, type_, bias, priority, condition))
+    }
+    fn acyc_edge(&mut self, node_u: i32, node_v: i32, condition: &[SolverLiteral]) -> bool {
+        self.forward(|o| o.acyc_edge(node_u, node_v, condition))
+    }
+    fn theory_term_number(&mut self, term_id: Id, number: i32) -> bool {
+        self.forward(|o| o.theory_term_number(term_id, number))
+    }
+    fn theory_term_string(&mut self, term_id: Id, name: &str) -> bool {
+        self.forward(|o| o.theory_term_string(term_id, name))
+    }
+    fn theory_term_compound(
+        &mut self,
+        term_id: Id,
+        name_id_or_type: i32,
+        arguments: &[Id],
+    ) -> bool {
+        self.forward(|o| o.theory_term_compound(term_id, name_id_or_type, arguments))
+    }
+    fn theory_element(
+        &mut self,
+        element_id: Id,
+        terms: &[Id],
+        condition: &[SolverLiteral],
+    ) -> bool {
+        self.forward(|o| o.theory_element(element_id, terms, condition))
+    }
+    fn theory_atom(&mut self, atom_id_or_zero: Id, term_id: Id, elements: &[Id]) -> bool {
+        self.forward(|o| o.theory_atom(atom_id_or_zero, term_id, elements))
+    }
+    fn theory_atom_with_guard(
+        &mut self,
+        atom_id_or_zero: Id,
+        term_id: Id,
+        elements: &[Id],
+        operator_id: Id,
+        right_hand_side_id: Id,
+    ) -> bool {
+        self.forward(|o| {
+            o.theory_atom_with_guard(
+                atom_id_or_zero,
+                term_id,
+                elements,
+                operator_id,
+                right_hand_side_id,
+            )
+        })
+    }
+}
+
+// clingo passes a null pointer for empty arrays, which std::slice::from_raw_parts() does not accept
+unsafe fn raw_slice<'a, T>(data: *const T, size: usize) -> &'a [T] {
+    if size == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, size)
+    }
+}
 unsafe extern "C" fn unsafe_init_program<T: GroundProgramObserver>(
     incremental: bool,
     gpo: *mut c_void,
@@ -5814,8 +6845,8 @@ unsafe extern "C" fn unsafe_rule<T: GroundProgramObserver>(
         set_internal_error(ErrorType::Runtime, "unsafe_rule() got a null pointer.");
         return false;
     }
-    let head = std::slice::from_raw_parts(head as *const Atom, head_size);
-    let body = std::slice::from_raw_parts(body as *const SolverLiteral, body_size);
+    let head = raw_slice(head as *const Atom, head_size);
+    let body = raw_slice(body as *const SolverLiteral, body_size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.rule(choice, head, body)
@@ -5831,7 +6862,7 @@ unsafe extern "C" fn unsafe_minimize<T: GroundProgramObserver>(
         set_internal_error(ErrorType::Runtime, "unsafe_minimize() got a null pointer.");
         return false;
     }
-    let literals = std::slice::from_raw_parts(literals as *const WeightedLiteral, size);
+    let literals = raw_slice(literals as *const WeightedLiteral, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.minimize(priority, literals)
@@ -5846,7 +6877,7 @@ unsafe extern "C" fn unsafe_project<T: GroundProgramObserver>(
         set_internal_error(ErrorType::Runtime, "unsafe_project() got a null pointer.");
         return false;
     }
-    let atoms = std::slice::from_raw_parts(atoms as *const Atom, size);
+    let atoms = raw_slice(atoms as *const Atom, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.project(atoms)
@@ -5868,8 +6899,8 @@ unsafe extern "C" fn unsafe_weight_rule<T: GroundProgramObserver>(
         );
         return false;
     }
-    let head = std::slice::from_raw_parts(head as *const Atom, head_size);
-    let body = std::slice::from_raw_parts(body as *const WeightedLiteral, body_size);
+    let head = raw_slice(head as *const Atom, head_size);
+    let body = raw_slice(body as *const WeightedLiteral, body_size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.weight_rule(choice, head, lower_bound, body)
@@ -5903,7 +6934,7 @@ unsafe extern "C" fn unsafe_output_term<T: GroundProgramObserver>(
         );
         return false;
     }
-    let condition = std::slice::from_raw_parts(condition as *const SolverLiteral, size);
+    let condition = raw_slice(condition as *const SolverLiteral, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.output_term(Symbol(symbol), condition)
@@ -5939,7 +6970,7 @@ unsafe extern "C" fn unsafe_assume<T: GroundProgramObserver>(
         set_internal_error(ErrorType::Runtime, "unsafe_assume() got a null pointer.");
         return false;
     }
-    let literals = std::slice::from_raw_parts(literals as *const SolverLiteral, size);
+    let literals = raw_slice(literals as *const SolverLiteral, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.assume(literals)
@@ -5958,7 +6989,7 @@ unsafe extern "C" fn unsafe_heuristic<T: GroundProgramObserver>(
         set_internal_error(ErrorType::Runtime, "unsafe_heuristic() got a null pointer.");
         return false;
     }
-    let condition = std::slice::from_raw_parts(condition as *const SolverLiteral, size);
+    let condition = raw_slice(condition as *const SolverLiteral, size);
     let gpo = &mut *(gpo as *mut T);
 
     match HeuristicType::try_from(htype as u32) {
@@ -5982,7 +7013,7 @@ unsafe extern "C" fn unsafe_acyc_edge<T: GroundProgramObserver>(
         set_internal_error(ErrorType::Runtime, "unsafe_heuristic() got a null pointer.");
         return false;
     }
-    let condition = std::slice::from_raw_parts(condition as *const SolverLiteral, size);
+    let condition = raw_slice(condition as *const SolverLiteral, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.acyc_edge(node_u, node_v, condition)
@@ -6045,7 +7076,7 @@ unsafe extern "C" fn unsafe_theory_term_compound<T: GroundProgramObserver>(
         );
         return false;
     }
-    let arguments = std::slice::from_raw_parts(arguments as *const Id, size);
+    let arguments = raw_slice(arguments as *const Id, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.theory_term_compound(Id(term_id), name_id_or_type, arguments)
@@ -6069,8 +7100,8 @@ unsafe extern "C" fn unsafe_theory_element<T: GroundProgramObserver>(
         );
         return false;
     }
-    let terms = std::slice::from_raw_parts(terms as *const Id, terms_size);
-    let condition = std::slice::from_raw_parts(condition as *const SolverLiteral, condition_size);
+    let terms = raw_slice(terms as *const Id, terms_size);
+    let condition = raw_slice(condition as *const SolverLiteral, condition_size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.theory_element(Id(element_id), terms, condition)
@@ -6090,7 +7121,7 @@ unsafe extern "C" fn unsafe_theory_atom<T: GroundProgramObserver>(
         );
         return false;
     }
-    let elements = std::slice::from_raw_parts(elements as *const Id, size);
+    let elements = raw_slice(elements as *const Id, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.theory_atom(Id(atom_id_or_zero), Id(term_id), elements)
@@ -6112,7 +7143,7 @@ unsafe extern "C" fn unsafe_theory_atom_with_guard<T: GroundProgramObserver>(
         );
         return false;
     }
-    let elements = std::slice::from_raw_parts(elements as *const Id, size);
+    let elements = raw_slice(elements as *const Id, size);
     let gpo = &mut *(gpo as *mut T);
 
     gpo.theory_atom_with_guard(
@@ -6355,6 +7386,23 @@ impl<
         Symbol::create_function("", &tempvec, true)
     }
 }
+impl<T: ToSymbol> ToSymbol for Option<T> {
+    fn symbol(&self) -> Result<Symbol, ClingoError> {
+        match self {
+            Some(value) => Symbol::create_function("some", &[value.symbol()?], true),
+            None => Symbol::create_id("none", true),
+        }
+    }
+}
+impl<T: ToSymbol> ToSymbol for Vec<T> {
+    fn symbol(&self) -> Result<Symbol, ClingoError> {
+        let arguments = self
+            .iter()
+            .map(|value| value.symbol())
+            .collect::<Result<Vec<_>, _>>()?;
+        Symbol::create_function("", &arguments, true)
+    }
+}
 impl ToSymbol for bool {
     fn symbol(&self) -> Result<Symbol, ClingoError> {
         if *self {
@@ -6404,7 +7452,7 @@ impl ToSymbol for str {
         Symbol::create_string(self)
     }
 }
-impl<T: ToSymbol> ToSymbol for &T {
+impl<T: ToSymbol + ?Sized> ToSymbol for &T {
     fn symbol(&self) -> Result<Symbol, ClingoError> {
         (*self).symbol()
     }
@@ -6509,12 +7557,125 @@ impl FromSymbol for String {
         Ok(symbol.string()?.into())
     }
 }
-impl FromSymbol for &'static str {
+impl FromSymbol for &str {
     type Error = ClingoError;
     fn from_symbol(symbol: Symbol) -> Result<Self, Self::Error> {
         symbol.string()
     }
 }
+impl FromSymbol for bool {
+    type Error = ClingoError;
+    fn from_symbol(symbol: Symbol) -> Result<Self, Self::Error> {
+        if symbol.match_function("true", 0)?.is_some() {
+            Ok(true)
+        } else if symbol.match_function("false", 0)?.is_some() {
+            Ok(false)
+        } else {
+            Err(FromSymbolError {
+                msg: format!("expected `true` or `false` but found `{symbol}`"),
+            }
+            .into())
+        }
+    }
+}
+impl<T: FromSymbol> FromSymbol for Option<T>
+where
+    T::Error: fmt::Display,
+{
+    type Error = ClingoError;
+    fn from_symbol(symbol: Symbol) -> Result<Self, Self::Error> {
+        if symbol.match_function("none", 0)?.is_some() {
+            Ok(None)
+        } else if let Some(arguments) = symbol.match_function("some", 1)? {
+            Ok(Some(from_symbol_argument(&arguments, 0, "some")?))
+        } else {
+            Err(FromSymbolError {
+                msg: format!("expected `none` or some/1 but found `{symbol}`"),
+            }
+            .into())
+        }
+    }
+}
+impl<T: FromSymbol> FromSymbol for Vec<T>
+where
+    T::Error: fmt::Display,
+{
+    type Error = ClingoError;
+    fn from_symbol(symbol: Symbol) -> Result<Self, Self::Error> {
+        let arguments = match symbol.symbol_type()? {
+            SymbolType::Function if symbol.name()?.is_empty() && symbol.is_positive()? => {
+                symbol.arguments()?
+            }
+            _ => {
+                return Err(FromSymbolError {
+                    msg: format!("expected a tuple but found `{symbol}`"),
+                }
+                .into())
+            }
+        };
+        (0..arguments.len())
+            .map(|i| from_symbol_argument(&arguments, i, ""))
+            .collect()
+    }
+}
+
+// Tuples are decoded from function symbols with an empty name, see the ToSymbol implementations above.
+macro_rules! from_symbol_tuple {
+    ($arity:literal: $($index:tt $name:ident),+) => {
+        impl<$($name: FromSymbol),+> FromSymbol for ($($name,)+)
+        where
+            $($name::Error: fmt::Display),+
+        {
+            type Error = ClingoError;
+            fn from_symbol(symbol: Symbol) -> Result<Self, Self::Error> {
+                let arguments = symbol.expect_function("", $arity)?;
+                Ok(($(from_symbol_argument::<$name>(&arguments, $index, "")?,)+))
+            }
+        }
+    };
+}
+impl FromSymbol for () {
+    type Error = ClingoError;
+    fn from_symbol(symbol: Symbol) -> Result<Self, Self::Error> {
+        symbol.expect_function("", 0)?;
+        Ok(())
+    }
+}
+from_symbol_tuple!(2: 0 A, 1 B);
+from_symbol_tuple!(3: 0 A, 1 B, 2 C);
+from_symbol_tuple!(4: 0 A, 1 B, 2 C, 3 D);
+from_symbol_tuple!(5: 0 A, 1 B, 2 C, 3 D, 4 E);
+from_symbol_tuple!(6: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+from_symbol_tuple!(7: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+from_symbol_tuple!(8: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+from_symbol_tuple!(9: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+from_symbol_tuple!(10: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+from_symbol_tuple!(11: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+from_symbol_tuple!(12: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+/// Decode the argument at position `index` of a function symbol with the given name.
+///
+/// Used by `#[derive(FromSymbol)]`, errors mention the position of the argument and the signature of the function.
+pub fn from_symbol_argument<T: FromSymbol>(
+    arguments: &[Symbol],
+    index: usize,
+    name: &str,
+) -> Result<T, ClingoError>
+where
+    T::Error: fmt::Display,
+{
+    T::from_symbol(arguments[index]).map_err(|error| {
+        let function = if name.is_empty() {
+            format!("tuple of size {}", arguments.len())
+        } else {
+            format!("{name}/{}", arguments.len())
+        };
+        FromSymbolError {
+            msg: format!("could not decode argument {index} of {function}: {error}"),
+        }
+        .into()
+    })
+}
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct FactBase {
     facts: HashSet<Symbol>,
@@ -6543,6 +7704,24 @@ impl FactBase {
             self.facts.insert(*s);
         }
     }
+    /// Create a fact base from the shown atoms of a model.
+    pub fn from_model(model: &Model) -> Result<FactBase, ClingoError> {
+        Ok(FactBase {
+            facts: model.symbols(ShowType::SHOWN)?.into_iter().collect(),
+        })
+    }
+    pub fn contains(&self, fact: &dyn ToSymbol) -> Result<bool, ClingoError> {
+        Ok(self.facts.contains(&fact.symbol()?))
+    }
+    /// Get the facts as a logic program with one fact per line.
+    ///
+    /// The facts are sorted, so equal fact bases give equal programs.
+    /// The program can be added with [`GenericControl::add()`].
+    pub fn to_program(&self) -> String {
+        let mut facts: Vec<&Symbol> = self.facts.iter().collect();
+        facts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        facts.iter().map(|fact| format!("{fact}.\n")).collect()
+    }
     pub fn print(&self) {
         for fact in &self.facts {
             print!("{fact}.");
@@ -6640,7 +7819,7 @@ impl FactBase {
 //     ) -> *const ::std::os::raw::c_char;
 // }
 
-// Re-export #[derive(ToSymbol)].
+// Re-export #[derive(ToSymbol)] and #[derive(FromSymbol)].
 #[cfg(feature = "derive")]
 #[allow(unused_imports)]
 pub use clingo_derive::*;
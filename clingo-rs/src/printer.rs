@@ -0,0 +1,325 @@
+//! [`GroundProgramPrinter`] collects the ground program passed to the solver and prints it as
+//! readable ASP text, using the symbols of shown atoms instead of aspif atom ids.
+//!
+//! ```ignore
+//! let mut printer = GroundProgramPrinter::new();
+//! ctl.ground_with_observer(&[Part::new("base", vec![])?], &mut printer)?;
+//! let program: String = printer.to_string();
+//! ```
+//!
+//! Atoms without a symbol, e.g., auxiliary atoms introduced by the grounder or atoms hidden by
+//! `#show` statements, are printed as `__aux(n)` with their aspif atom `n`.
+//! Theory statements are not printed.
+use crate::{
+    Atom, ExternalType, GroundProgramObserver, HeuristicType, SolverLiteral, Symbol,
+    WeightedLiteral,
+};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+enum Body {
+    Normal(Vec<i32>),
+    Weight(i32, Vec<(i32, i32)>),
+}
+
+#[derive(Debug, Clone)]
+enum Statement {
+    Rule {
+        choice: bool,
+        head: Vec<u32>,
+        body: Body,
+    },
+    Minimize {
+        priority: i32,
+        literals: Vec<(i32, i32)>,
+    },
+    Project(Vec<u32>),
+    Show(Symbol, Vec<i32>),
+    External(u32, ExternalType),
+    Assume(Vec<i32>),
+    Heuristic {
+        atom: u32,
+        type_: HeuristicType,
+        bias: i32,
+        priority: u32,
+        condition: Vec<i32>,
+    },
+    Edge(i32, i32, Vec<i32>),
+}
+
+/// Observer printing the ground program as ASP text.
+///
+/// Statements are printed in the order they are passed to the solver after the shown facts.
+/// The symbols of atoms only become known at the end of a step,
+/// so the program should be printed after grounding, e.g., via its [`Display`](fmt::Display) implementation.
+#[derive(Debug, Clone, Default)]
+pub struct GroundProgramPrinter {
+    symbols: HashMap<u32, Symbol>,
+    facts: Vec<Symbol>,
+    statements: Vec<Statement>,
+}
+impl GroundProgramPrinter {
+    /// Create an empty printer.
+    pub fn new() -> GroundProgramPrinter {
+        GroundProgramPrinter::default()
+    }
+    /// Get the symbol associated with an aspif atom, if it is shown.
+    pub fn symbol(&self, atom: Atom) -> Option<Symbol> {
+        self.symbols.get(&atom.0).copied()
+    }
+    /// Number of collected statements, not counting the shown facts.
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+    /// Check whether no statements or facts have been collected.
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty() && self.facts.is_empty()
+    }
+
+    fn atom(&self, f: &mut fmt::Formatter, atom: u32) -> fmt::Result {
+        match self.symbols.get(&atom) {
+            Some(symbol) => write!(f, "{}", symbol),
+            None => write!(f, "__aux({})", atom),
+        }
+    }
+    fn literal(&self, f: &mut fmt::Formatter, literal: i32) -> fmt::Result {
+        if literal < 0 {
+            write!(f, "not ")?;
+        }
+        self.atom(f, literal.unsigned_abs())
+    }
+    fn literals(&self, f: &mut fmt::Formatter, literals: &[i32]) -> fmt::Result {
+        for (i, literal) in literals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            self.literal(f, *literal)?;
+        }
+        Ok(())
+    }
+    // print ` : condition` if the condition is not empty
+    fn condition(&self, f: &mut fmt::Formatter, condition: &[i32]) -> fmt::Result {
+        if !condition.is_empty() {
+            write!(f, " : ")?;
+            self.literals(f, condition)?;
+        }
+        Ok(())
+    }
+    fn statement(&self, f: &mut fmt::Formatter, statement: &Statement) -> fmt::Result {
+        match statement {
+            Statement::Rule { choice, head, body } => {
+                if *choice {
+                    write!(f, "{{ ")?;
+                }
+                for (i, atom) in head.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    self.atom(f, *atom)?;
+                }
+                if *choice {
+                    write!(f, " }}")?;
+                }
+                match body {
+                    Body::Normal(body) if body.is_empty() => {
+                        if head.is_empty() && !choice {
+                            write!(f, ":-")?;
+                        }
+                    }
+                    Body::Normal(body) => {
+                        if !head.is_empty() || *choice {
+                            write!(f, " ")?;
+                        }
+                        write!(f, ":- ")?;
+                        self.literals(f, body)?;
+                    }
+                    Body::Weight(lower_bound, body) => {
+                        if !head.is_empty() || *choice {
+                            write!(f, " ")?;
+                        }
+                        write!(f, ":- {} <= #sum {{ ", lower_bound)?;
+                        for (i, (literal, weight)) in body.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, "; ")?;
+                            }
+                            write!(f, "{},{}: ", weight, i)?;
+                            self.literal(f, *literal)?;
+                        }
+                        write!(f, " }}")?;
+                    }
+                }
+                writeln!(f, ".")
+            }
+            Statement::Minimize { priority, literals } => {
+                write!(f, "#minimize {{ ")?;
+                for (i, (literal, weight)) in literals.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}@{},{}: ", weight, priority, i)?;
+                    self.literal(f, *literal)?;
+                }
+                writeln!(f, " }}.")
+            }
+            Statement::Project(atoms) => {
+                for atom in atoms {
+                    write!(f, "#project ")?;
+                    self.atom(f, *atom)?;
+                    writeln!(f, ".")?;
+                }
+                Ok(())
+            }
+            Statement::Show(symbol, condition) => {
+                write!(f, "#show {}", symbol)?;
+                self.condition(f, condition)?;
+                writeln!(f, ".")
+            }
+            Statement::External(atom, type_) => {
+                write!(f, "#external ")?;
+                self.atom(f, *atom)?;
+                let value = match type_ {
+                    ExternalType::Free => "free",
+                    ExternalType::True => "true",
+                    ExternalType::False => "false",
+                    ExternalType::Release => "release",
+                };
+                writeln!(f, ". [{}]", value)
+            }
+            Statement::Assume(literals) => {
+                write!(f, "% assume ")?;
+                self.literals(f, literals)?;
+                writeln!(f)
+            }
+            Statement::Heuristic {
+                atom,
+                type_,
+                bias,
+                priority,
+                condition,
+            } => {
+                write!(f, "#heuristic ")?;
+                self.atom(f, *atom)?;
+                self.condition(f, condition)?;
+                let modifier = match type_ {
+                    HeuristicType::Level => "level",
+                    HeuristicType::Sign => "sign",
+                    HeuristicType::Factor => "factor",
+                    HeuristicType::Init => "init",
+                    HeuristicType::True => "true",
+                    HeuristicType::False => "false",
+                };
+                writeln!(f, ". [{}@{},{}]", bias, priority, modifier)
+            }
+            Statement::Edge(node_u, node_v, condition) => {
+                write!(f, "#edge ({},{})", node_u, node_v)?;
+                self.condition(f, condition)?;
+                writeln!(f, ".")
+            }
+        }
+    }
+}
+impl fmt::Display for GroundProgramPrinter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for fact in &self.facts {
+            writeln!(f, "{}.", fact)?;
+        }
+        for statement in &self.statements {
+            self.statement(f, statement)?;
+        }
+        Ok(())
+    }
+}
+
+fn atoms(atoms: &[Atom]) -> Vec<u32> {
+    atoms.iter().map(|atom| atom.0).collect()
+}
+fn literals(literals: &[SolverLiteral]) -> Vec<i32> {
+    literals.iter().map(|literal| literal.0).collect()
+}
+fn weighted(literals: &[WeightedLiteral]) -> Vec<(i32, i32)> {
+    literals
+        .iter()
+        .map(|literal| (literal.literal().0, literal.weight()))
+        .collect()
+}
+
+impl GroundProgramObserver for GroundProgramPrinter {
+    fn rule(&mut self, choice: bool, head: &[Atom], body: &[SolverLiteral]) -> bool {
+        self.statements.push(Statement::Rule {
+            choice,
+            head: atoms(head),
+            body: Body::Normal(literals(body)),
+        });
+        true
+    }
+    fn weight_rule(
+        &mut self,
+        choice: bool,
+        head: &[Atom],
+        lower_bound: i32,
+        body: &[WeightedLiteral],
+    ) -> bool {
+        self.statements.push(Statement::Rule {
+            choice,
+            head: atoms(head),
+            body: Body::Weight(lower_bound, weighted(body)),
+        });
+        true
+    }
+    fn minimize(&mut self, priority: i32, literals: &[WeightedLiteral]) -> bool {
+        self.statements.push(Statement::Minimize {
+            priority,
+            literals: weighted(literals),
+        });
+        true
+    }
+    fn project(&mut self, projected: &[Atom]) -> bool {
+        self.statements.push(Statement::Project(atoms(projected)));
+        true
+    }
+    fn output_atom(&mut self, symbol: Symbol, atom: Atom) -> bool {
+        if atom.0 == 0 {
+            self.facts.push(symbol);
+        } else {
+            self.symbols.entry(atom.0).or_insert(symbol);
+        }
+        true
+    }
+    fn output_term(&mut self, symbol: Symbol, condition: &[SolverLiteral]) -> bool {
+        self.statements
+            .push(Statement::Show(symbol, literals(condition)));
+        true
+    }
+    fn external(&mut self, atom: Atom, type_: ExternalType) -> bool {
+        self.statements.push(Statement::External(atom.0, type_));
+        true
+    }
+    fn assume(&mut self, assumptions: &[SolverLiteral]) -> bool {
+        self.statements
+            .push(Statement::Assume(literals(assumptions)));
+        true
+    }
+    fn heuristic(
+        &mut self,
+        atom: Atom,
+        type_: HeuristicType,
+        bias: i32,
+        priority: u32,
+        condition: &[SolverLiteral],
+    ) -> bool {
+        self.statements.push(Statement::Heuristic {
+            atom: atom.0,
+            type_,
+            bias,
+            priority,
+            condition: literals(condition),
+        });
+        true
+    }
+    fn acyc_edge(&mut self, node_u: i32, node_v: i32, condition: &[SolverLiteral]) -> bool {
+        self.statements
+            .push(Statement::Edge(node_u, node_v, literals(condition)));
+        true
+    }
+}
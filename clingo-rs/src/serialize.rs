@@ -0,0 +1,147 @@
+//! [`Symbol`] serializes as a string in clingo syntax, e.g. `"p(1,\"a\")"`, and deserializes with [`parse_term()`].
+//!
+//! The structured form [`TaggedSymbol`] can be selected per field with `#[serde(with = "clingo::serialize::tagged")]`:
+//!
+//! ```json
+//! {"type": "function", "name": "p", "arguments": [{"type": "number", "value": 1}], "positive": true}
+//! ```
+//!
+//! [`FactBase`] serializes as a sorted sequence of symbols.
+//! [`MModel`], [`SolveResult`] and [`StatsValue`] snapshots of the statistics derive their implementations.
+//!
+//! [`MModel`]: crate::MModel
+//! [`SolveResult`]: crate::SolveResult
+//! [`StatsValue`]: crate::StatsValue
+use crate::{parse_term, ClingoError, FactBase, Symbol, SymbolType};
+use serde::de::{self, Deserializer};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let term = String::deserialize(deserializer)?;
+        parse_term(&term)
+            .map_err(|_| de::Error::custom(format!("could not parse `{term}` as a symbol")))
+    }
+}
+
+impl Serialize for FactBase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut facts: Vec<&Symbol> = self.facts.iter().collect();
+        facts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        serializer.collect_seq(facts)
+    }
+}
+impl<'de> Deserialize<'de> for FactBase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let facts = Vec::<Symbol>::deserialize(deserializer)?;
+        Ok(FactBase {
+            facts: facts.into_iter().collect(),
+        })
+    }
+}
+
+/// Structured representation of a [`Symbol`] that is tagged with the symbol type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TaggedSymbol {
+    Number {
+        value: i32,
+    },
+    String {
+        value: String,
+    },
+    Function {
+        name: String,
+        #[serde(default)]
+        arguments: Vec<TaggedSymbol>,
+        #[serde(default = "positive")]
+        positive: bool,
+    },
+    #[serde(rename = "sup")]
+    Supremum,
+    #[serde(rename = "inf")]
+    Infimum,
+}
+fn positive() -> bool {
+    true
+}
+impl TryFrom<Symbol> for TaggedSymbol {
+    type Error = ClingoError;
+
+    fn try_from(symbol: Symbol) -> Result<Self, Self::Error> {
+        Ok(match symbol.symbol_type()? {
+            SymbolType::Number => TaggedSymbol::Number {
+                value: symbol.number()?,
+            },
+            SymbolType::String => TaggedSymbol::String {
+                value: symbol.string()?.to_string(),
+            },
+            SymbolType::Function => TaggedSymbol::Function {
+                name: symbol.name()?.to_string(),
+                arguments: symbol
+                    .arguments()?
+                    .into_iter()
+                    .map(TaggedSymbol::try_from)
+                    .collect::<Result<_, _>>()?,
+                positive: symbol.is_positive()?,
+            },
+            SymbolType::Supremum => TaggedSymbol::Supremum,
+            SymbolType::Infimum => TaggedSymbol::Infimum,
+        })
+    }
+}
+impl TryFrom<&TaggedSymbol> for Symbol {
+    type Error = ClingoError;
+
+    fn try_from(symbol: &TaggedSymbol) -> Result<Self, Self::Error> {
+        match symbol {
+            TaggedSymbol::Number { value } => Ok(Symbol::create_number(*value)),
+            TaggedSymbol::String { value } => Symbol::create_string(value),
+            TaggedSymbol::Function {
+                name,
+                arguments,
+                positive,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(Symbol::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Symbol::create_function(name, &arguments, *positive)
+            }
+            TaggedSymbol::Supremum => Ok(Symbol::create_supremum()),
+            TaggedSymbol::Infimum => Ok(Symbol::create_infimum()),
+        }
+    }
+}
+
+/// (De)serialize a [`Symbol`] in its [`TaggedSymbol`] form.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Answer {
+///     #[serde(with = "clingo::serialize::tagged")]
+///     atom: Symbol,
+/// }
+/// ```
+pub mod tagged {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(symbol: &Symbol, serializer: S) -> Result<S::Ok, S::Error> {
+        TaggedSymbol::try_from(*symbol)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Symbol, D::Error> {
+        let symbol = TaggedSymbol::deserialize(deserializer)?;
+        Symbol::try_from(&symbol).map_err(de::Error::custom)
+    }
+}
@@ -6,6 +6,8 @@ use super::{GenericControl, Id, Model, Options, Statistics, Symbol};
 use crate::{ast, ControlCtx};
 use std::fmt;
 
+pub mod difference_logic;
+
 pub trait Theory<'a> {
     /// registers the theory with the control
     fn register<C>(&mut self, ctl: &mut GenericControl<C>) -> bool
@@ -45,14 +47,14 @@ pub trait Theory<'a> {
 }
 #[derive(Copy, Clone, Debug)]
 pub enum TheoryValue {
-    IntNumber(u64),
+    IntNumber(i64),
     DoubleNumber(f64),
     Symbol(Symbol),
 }
 impl fmt::Display for TheoryValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            TheoryValue::IntNumber(int) => u64::fmt(int, f),
+            TheoryValue::IntNumber(int) => i64::fmt(int, f),
             TheoryValue::DoubleNumber(double) => f64::fmt(double, f),
             TheoryValue::Symbol(sym) => Symbol::fmt(sym, f),
         }
@@ -0,0 +1,515 @@
+//! A difference logic theory.
+//!
+//! The theory handles constraints of form `&diff{ u - v } <= k`,
+//! where `u` and `v` are ground terms naming integer variables and `k` is an integer.
+//! A constraint is enforced whenever its theory atom is true.
+//! Each constraint is an edge `v -> u` with weight `k` in a graph over the variables,
+//! an assignment satisfying all constraints exists iff the graph has no negative cycle.
+//!
+//! **Example:**
+//!
+//! ```ignore
+//! let mut theory = DifferenceLogic::new();
+//! let mut ctl = control(vec![])?;
+//! theory.register(&mut ctl);
+//! ctl.add("base", &[], "&diff{ a - b } <= -1. &diff{ b - 0 } <= 3.")?;
+//! ```
+use crate::theory::{Theory, TheoryValue};
+use crate::{
+    ast, set_error, ClauseType, ClingoError, ControlCtx, ErrorType, GenericControl, Id, Model,
+    Options, PropagateControl, PropagateInit, Propagator, PropagatorCheckMode, SolverLiteral,
+    Statistics, StatisticsType, Symbol, TheoryAtoms, TheoryTermType,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// The theory definition of the difference logic theory.
+pub const THEORY: &str = "#theory dl {
+    term {
+        - : 3, unary;
+        - : 1, binary, left
+    };
+    &diff/0 : term, {<=}, term, any
+}.";
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: usize,
+    to: usize,
+    weight: i64,
+    literal: SolverLiteral,
+}
+
+#[derive(Debug, Default)]
+struct ThreadState {
+    // indices of the edges whose literals are true
+    active: Vec<usize>,
+    // the assignment of the last total assignment
+    assignment: Vec<i64>,
+}
+
+/// the statistics of the current solve call
+#[derive(Debug, Default, Clone, Copy)]
+struct DLStatistics {
+    /// edges added by the solve call
+    edges: usize,
+    propagations: usize,
+    conflicts: usize,
+}
+
+#[derive(Debug, Default)]
+struct DLState {
+    vertices: Vec<Symbol>,
+    vertex_indices: HashMap<Symbol, usize>,
+    edges: Vec<Edge>,
+    literal_edges: HashMap<SolverLiteral, Vec<usize>>,
+    threads: Vec<ThreadState>,
+    statistics: DLStatistics,
+}
+impl DLState {
+    fn vertex(&mut self, symbol: Symbol) -> usize {
+        if let Some(index) = self.vertex_indices.get(&symbol) {
+            return *index;
+        }
+        let index = self.vertices.len();
+        self.vertices.push(symbol);
+        self.vertex_indices.insert(symbol, index);
+        index
+    }
+
+    fn init(&mut self, init: &mut PropagateInit) -> Result<(), ClingoError> {
+        // the propagator is initialized before each solve call
+        self.statistics = DLStatistics::default();
+        let mut constraints = vec![];
+        {
+            let atoms = init.theory_atoms()?;
+            for atom in atoms.iter() {
+                if atoms.term_name(atoms.atom_term(atom)?)? != "diff" {
+                    continue;
+                }
+                let (u, v, weight) = parse_atom(atoms, atom)?;
+                let literal = atoms.atom_literal(atom)?;
+                constraints.push((u, v, weight, literal));
+            }
+        }
+        // clingo only passes the theory atoms added since the last solve call
+        self.statistics.edges = constraints.len();
+        for (u, v, weight, literal) in constraints {
+            let literal = init.solver_literal(literal)?;
+            let (from, to) = (self.vertex(v), self.vertex(u));
+            let index = self.edges.len();
+            self.edges.push(Edge {
+                from,
+                to,
+                weight,
+                literal,
+            });
+            let edges = self.literal_edges.entry(literal).or_default();
+            if edges.is_empty() {
+                init.add_watch(literal)?;
+            }
+            edges.push(index);
+        }
+        // edges that were true at the top level in previous solve calls stay active
+        self.threads
+            .resize_with(init.number_of_threads(), ThreadState::default);
+        init.set_check_mode(PropagatorCheckMode::Total);
+        Ok(())
+    }
+
+    /// Look for a negative cycle through the given active edge.
+    ///
+    /// Returns the edges of the cycle.
+    fn negative_cycle(&self, thread: usize, edge: usize) -> Option<Vec<usize>> {
+        let Edge {
+            from, to, weight, ..
+        } = self.edges[edge];
+        // shortest paths from the target of the edge, the graph without the edge has no negative cycle
+        let mut dist: Vec<Option<i64>> = vec![None; self.vertices.len()];
+        let mut pred: Vec<Option<usize>> = vec![None; self.vertices.len()];
+        dist[to] = Some(0);
+        for _ in 0..self.vertices.len() {
+            let mut changed = false;
+            for &index in &self.threads[thread].active {
+                if index == edge {
+                    continue;
+                }
+                let e = &self.edges[index];
+                if let Some(d) = dist[e.from] {
+                    if dist[e.to].is_none_or(|x| d + e.weight < x) {
+                        dist[e.to] = Some(d + e.weight);
+                        pred[e.to] = Some(index);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        match dist[from] {
+            Some(d) if d + weight < 0 => {
+                let mut cycle = vec![edge];
+                let mut vertex = from;
+                while vertex != to {
+                    let index = pred[vertex]?;
+                    cycle.push(index);
+                    vertex = self.edges[index].from;
+                }
+                Some(cycle)
+            }
+            _ => None,
+        }
+    }
+
+    /// Compute an assignment satisfying the active edges.
+    fn assignment(&self, thread: usize) -> Vec<i64> {
+        let mut dist = vec![0; self.vertices.len()];
+        for _ in 0..self.vertices.len() {
+            let mut changed = false;
+            for &index in &self.threads[thread].active {
+                let e = &self.edges[index];
+                if dist[e.from] + e.weight < dist[e.to] {
+                    dist[e.to] = dist[e.from] + e.weight;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        // the vertex 0 is assigned zero if it exists
+        if let Some(&zero) = self.vertex_indices.get(&Symbol::create_number(0)) {
+            let offset = dist[zero];
+            for value in dist.iter_mut() {
+                *value -= offset;
+            }
+        }
+        dist
+    }
+}
+
+// get the variables and the bound of a `&diff{ u - v } <= k` atom
+fn parse_atom(atoms: &TheoryAtoms, atom: Id) -> Result<(Symbol, Symbol, i64), ClingoError> {
+    let elements = atoms.atom_elements(atom)?;
+    if elements.len() != 1 || !atoms.element_condition(elements[0])?.is_empty() {
+        return Err(ClingoError::new_external(
+            "difference constraints must have exactly one unconditional element",
+        ));
+    }
+    let tuple = atoms.element_tuple(elements[0])?;
+    if tuple.len() != 1 {
+        return Err(ClingoError::new_external(
+            "difference constraints must have exactly one unconditional element",
+        ));
+    }
+    let term = tuple[0];
+    if !matches!(atoms.term_type(term)?, TheoryTermType::Function)
+        || atoms.term_name(term)? != "-"
+        || atoms.term_arguments(term)?.len() != 2
+    {
+        return Err(ClingoError::new_external(
+            "difference constraints must be of form &diff{ u - v } <= k",
+        ));
+    }
+    let arguments = atoms.term_arguments(term)?;
+    let u = term_to_symbol(atoms, arguments[0])?;
+    let v = term_to_symbol(atoms, arguments[1])?;
+    if !atoms.atom_has_guard(atom)? {
+        return Err(ClingoError::new_external(
+            "difference constraints must have a guard",
+        ));
+    }
+    let (operator, bound) = atoms.atom_guard(atom)?;
+    if operator != "<=" {
+        return Err(ClingoError::new_external(
+            "difference constraints only support the operator <=",
+        ));
+    }
+    let bound = match term_to_symbol(atoms, bound)?.number() {
+        Ok(bound) => bound as i64,
+        Err(_) => {
+            return Err(ClingoError::new_external(
+                "the bound of a difference constraint must be an integer",
+            ))
+        }
+    };
+    Ok((u, v, bound))
+}
+
+// evaluate a ground theory term
+fn term_to_symbol(atoms: &TheoryAtoms, term: Id) -> Result<Symbol, ClingoError> {
+    match atoms.term_type(term)? {
+        TheoryTermType::Number => Ok(Symbol::create_number(atoms.term_number(term)?)),
+        TheoryTermType::Symbol => {
+            let name = atoms.term_name(term)?;
+            if name.starts_with('"') && name.ends_with('"') && name.len() > 1 {
+                Symbol::create_string(&name[1..name.len() - 1])
+            } else {
+                Symbol::create_id(name, true)
+            }
+        }
+        TheoryTermType::Function => {
+            let name = atoms.term_name(term)?;
+            let arguments = atoms
+                .term_arguments(term)?
+                .iter()
+                .map(|argument| term_to_symbol(atoms, *argument))
+                .collect::<Result<Vec<Symbol>, ClingoError>>()?;
+            if name == "-" && arguments.len() == 1 {
+                if let Ok(number) = arguments[0].number() {
+                    return Ok(Symbol::create_number(-number));
+                }
+            }
+            Symbol::create_function(name, &arguments, true)
+        }
+        TheoryTermType::Tuple => {
+            let arguments = atoms
+                .term_arguments(term)?
+                .iter()
+                .map(|argument| term_to_symbol(atoms, *argument))
+                .collect::<Result<Vec<Symbol>, ClingoError>>()?;
+            Symbol::create_function("", &arguments, true)
+        }
+        _ => Err(ClingoError::new_external(
+            "difference constraints must not contain sets or lists",
+        )),
+    }
+}
+
+/// Propagator checking the difference constraints, registered by [`DifferenceLogic::register()`].
+struct DLPropagator {
+    state: Arc<Mutex<DLState>>,
+}
+impl DLPropagator {
+    fn state(&self) -> MutexGuard<'_, DLState> {
+        // a panic while holding the lock does not leave the state inconsistent
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+impl Propagator for DLPropagator {
+    fn init(&mut self, init: &mut PropagateInit) -> bool {
+        match self.state().init(init) {
+            Ok(()) => true,
+            Err(e) => {
+                let _ = set_error(ErrorType::Runtime, &e.to_string());
+                false
+            }
+        }
+    }
+
+    fn propagate(&mut self, control: &mut PropagateControl, changes: &[SolverLiteral]) -> bool {
+        let mut state = self.state();
+        let thread = control.thread_id() as usize;
+        state.statistics.propagations += 1;
+        for literal in changes {
+            let edges = match state.literal_edges.get(literal) {
+                Some(edges) => edges.clone(),
+                None => continue,
+            };
+            for edge in edges {
+                state.threads[thread].active.push(edge);
+                if let Some(cycle) = state.negative_cycle(thread, edge) {
+                    state.statistics.conflicts += 1;
+                    let clause: Vec<SolverLiteral> = cycle
+                        .iter()
+                        .map(|index| state.edges[*index].literal.negate())
+                        .collect();
+                    drop(state);
+                    // the clause is conflicting, the solver backtracks afterwards
+                    return match control.add_clause(&clause, ClauseType::Learnt) {
+                        Ok(true) => control.propagate().is_ok(),
+                        Ok(false) => true,
+                        Err(_) => false,
+                    };
+                }
+            }
+        }
+        true
+    }
+
+    fn undo(&mut self, control: &mut PropagateControl, changes: &[SolverLiteral]) {
+        let mut state = self.state();
+        let thread = control.thread_id() as usize;
+        for literal in changes {
+            let edges = match state.literal_edges.get(literal) {
+                Some(edges) => edges.clone(),
+                None => continue,
+            };
+            let active = &mut state.threads[thread].active;
+            for edge in edges {
+                if let Some(position) = active.iter().rposition(|x| *x == edge) {
+                    active.remove(position);
+                }
+            }
+        }
+    }
+
+    fn check(&mut self, control: &mut PropagateControl) -> bool {
+        let mut state = self.state();
+        let thread = control.thread_id() as usize;
+        let assignment = state.assignment(thread);
+        state.threads[thread].assignment = assignment;
+        true
+    }
+}
+
+/// A difference logic theory implemented on top of a [`Propagator`].
+///
+/// Cloning the theory shares its state,
+/// e.g., to access the assignment from a [`SolveEventHandler`](crate::SolveEventHandler).
+#[derive(Debug, Clone, Default)]
+pub struct DifferenceLogic {
+    state: Arc<Mutex<DLState>>,
+}
+impl DifferenceLogic {
+    /// Create a new difference logic theory.
+    pub fn new() -> DifferenceLogic {
+        DifferenceLogic::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, DLState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Get the assignment of the last model found by the given thread.
+    pub fn values(&self, thread_id: Id) -> Vec<(Symbol, i64)> {
+        let state = self.state();
+        match state.threads.get(thread_id.get_integer() as usize) {
+            Some(thread) => state
+                .vertices
+                .iter()
+                .zip(thread.assignment.iter())
+                .map(|(symbol, value)| (*symbol, *value))
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+impl<'a> Theory<'a> for DifferenceLogic {
+    fn register<C>(&mut self, ctl: &mut GenericControl<C>) -> bool
+    where
+        C: ControlCtx,
+    {
+        let propagator = DLPropagator {
+            state: self.state.clone(),
+        };
+        ctl.add("base", &[], THEORY).is_ok() && ctl.add_propagator(propagator, true).is_ok()
+    }
+    fn rewrite_statement(
+        &mut self,
+        stm: &ast::Statement,
+        builder: &mut ast::ProgramBuilder,
+    ) -> bool {
+        builder.add(stm).is_ok()
+    }
+    fn prepare<C>(&mut self, _ctl: &mut GenericControl<C>) -> bool
+    where
+        C: ControlCtx,
+    {
+        true
+    }
+    fn register_options(&mut self, _options: &mut Options) -> bool {
+        true
+    }
+    fn validate_options(&mut self) -> bool {
+        true
+    }
+    /// Add the assignment as `dl(u, value)` atoms to the model.
+    fn on_model(&mut self, model: &mut Model) -> bool {
+        let thread_id = match model.thread_id() {
+            Ok(thread_id) => thread_id,
+            Err(_) => return false,
+        };
+        let mut symbols = vec![];
+        for (symbol, value) in self.values(thread_id) {
+            let value = match i32::try_from(value) {
+                Ok(value) => value,
+                Err(_) => {
+                    let _ = set_error(
+                        ErrorType::Runtime,
+                        &format!(
+                            "value {} of {} does not fit into a clingo number",
+                            value, symbol
+                        ),
+                    );
+                    return false;
+                }
+            };
+            match Symbol::create_function("dl", &[symbol, Symbol::create_number(value)], true) {
+                Ok(symbol) => symbols.push(symbol),
+                Err(_) => return false,
+            }
+        }
+        model.extend(&symbols)
+    }
+    /// Set the statistics of the theory under the key `DifferenceLogic`.
+    ///
+    /// The values of the current solve call are set in the step statistics and added to the
+    /// accumulated ones.
+    fn on_statistics(&mut self, step: &mut Statistics, akku: &mut Statistics) -> bool {
+        let statistics = self.state().statistics;
+        let values = [
+            ("Edges", statistics.edges),
+            ("Propagations", statistics.propagations),
+            ("Conflicts", statistics.conflicts),
+        ];
+        set_statistics(step, &values, false).is_ok() && set_statistics(akku, &values, true).is_ok()
+    }
+    fn lookup_symbol(&mut self, symbol: Symbol, index: &mut usize) -> bool {
+        match self.state().vertex_indices.get(&symbol) {
+            Some(x) => {
+                *index = *x;
+                true
+            }
+            None => false,
+        }
+    }
+    fn get_symbol(&mut self, index: usize) -> Symbol {
+        self.state().vertices[index]
+    }
+    fn assignment(&'a self, thread_id: Id) -> Box<dyn Iterator<Item = (Symbol, TheoryValue)> + 'a> {
+        Box::new(
+            self.values(thread_id)
+                .into_iter()
+                .map(|(symbol, value)| (symbol, TheoryValue::IntNumber(value))),
+        )
+    }
+    /// The theory has no configuration options.
+    fn configure(&mut self, _key: &str, _value: &str) -> bool {
+        false
+    }
+}
+
+/// set the `values` under the key `DifferenceLogic`, or add them to the present ones if `add` is set
+fn set_statistics(
+    stats: &mut Statistics,
+    values: &[(&str, usize)],
+    add: bool,
+) -> Result<(), ClingoError> {
+    let root = stats.root()?;
+    let key = if stats.map_has_subkey(root, "DifferenceLogic")? {
+        stats.map_at(root, "DifferenceLogic")?
+    } else {
+        stats.map_add_subkey(root, "DifferenceLogic", StatisticsType::Map)?
+    };
+    for (name, value) in values {
+        let (subkey, present) = if stats.map_has_subkey(key, name)? {
+            let subkey = stats.map_at(key, name)?;
+            (subkey, stats.value_get(subkey)?)
+        } else {
+            (stats.map_add_subkey(key, name, StatisticsType::Value)?, 0.0)
+        };
+        let value = if add {
+            present + *value as f64
+        } else {
+            *value as f64
+        };
+        if !stats.value_set(subkey, value) {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_statistics_value_set() failed",
+            ));
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 #[cfg(feature = "derive")]
 pub mod derive {
     use clingo::ClingoError;
-    use clingo::Symbol;
+    use clingo::FromSymbol;
     use clingo::ToSymbol;
 
     #[derive(Copy, Clone, ToSymbol)]
@@ -10,6 +10,40 @@ pub mod derive {
     #[derive(Copy, Clone, ToSymbol)]
     struct Test2;
 
+    #[derive(Debug, Clone, PartialEq, ToSymbol, FromSymbol)]
+    struct Unit;
+
+    #[derive(Debug, Clone, PartialEq, ToSymbol, FromSymbol)]
+    struct Edge {
+        from: u32,
+        to: u32,
+        label: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ToSymbol, FromSymbol)]
+    struct Wrapper(Unit, i32);
+
+    #[derive(Debug, Clone, PartialEq, ToSymbol, FromSymbol)]
+    struct Containers<'a> {
+        path: Vec<u32>,
+        weight: Option<i32>,
+        pair: (bool, &'a str),
+        edges: Vec<Edge>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ToSymbol, FromSymbol)]
+    struct Generic<T> {
+        value: T,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ToSymbol, FromSymbol)]
+    enum Shape {
+        Empty,
+        Point(i32, i32),
+        Circle { center: (i32, i32), radius: u32 },
+        Group(Vec<Shape>),
+    }
+
     #[derive(ToSymbol)]
     struct Bla<'a> {
         test: Test,
@@ -80,4 +114,147 @@ pub mod derive {
         let s2 = clingo::parse_term("plus(3,(4,\"HHHR\"))").unwrap();
         assert_eq!(s1, s2);
     }
+
+    fn round_trip<T>(value: T, expected: &str)
+    where
+        T: ToSymbol + FromSymbol<Error = ClingoError> + PartialEq + std::fmt::Debug,
+    {
+        let symbol = value.symbol().unwrap();
+        assert_eq!(symbol, clingo::parse_term(expected).unwrap());
+        assert_eq!(T::from_symbol(symbol).unwrap(), value);
+    }
+
+    fn from_symbol_error<T>(term: &str) -> String
+    where
+        T: FromSymbol<Error = ClingoError> + std::fmt::Debug,
+    {
+        let symbol = clingo::parse_term(term).unwrap();
+        T::from_symbol(symbol).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn from_symbol() {
+        round_trip(Unit, "unit");
+        round_trip(
+            Edge {
+                from: 1,
+                to: 2,
+                label: "a".to_string(),
+            },
+            "edge(1,2,\"a\")",
+        );
+        round_trip(Wrapper(Unit, -3), "wrapper(unit,-3)");
+        round_trip(
+            Containers {
+                path: vec![1, 2, 3],
+                weight: Some(-4),
+                pair: (true, "x"),
+                edges: vec![],
+            },
+            "containers((1,2,3),some(-4),(true,\"x\"),())",
+        );
+        round_trip(
+            Containers {
+                path: vec![7],
+                weight: None,
+                pair: (false, ""),
+                edges: vec![Edge {
+                    from: 0,
+                    to: 1,
+                    label: "b".to_string(),
+                }],
+            },
+            "containers((7,),none,(false,\"\"),(edge(0,1,\"b\"),))",
+        );
+        round_trip(Generic { value: Unit }, "generic(unit)");
+        round_trip(Generic { value: (1, 2) }, "generic((1,2))");
+        round_trip(Shape::Empty, "empty");
+        round_trip(Shape::Point(1, -1), "point(1,-1)");
+        round_trip(
+            Shape::Circle {
+                center: (0, 0),
+                radius: 5,
+            },
+            "circle((0,0),5)",
+        );
+        round_trip(
+            Shape::Group(vec![Shape::Empty, Shape::Point(2, 3)]),
+            "group((empty,point(2,3)))",
+        );
+    }
+
+    #[test]
+    fn from_symbol_errors() {
+        assert_eq!(
+            from_symbol_error::<Edge>("edge(1,2)"),
+            "FromSymbolError: expected edge/3 but found `edge(1,2)`"
+        );
+        assert_eq!(
+            from_symbol_error::<Edge>("-edge(1,2,\"a\")"),
+            "FromSymbolError: expected edge/3 but found `-edge(1,2,\"a\")`"
+        );
+        assert_eq!(
+            from_symbol_error::<Unit>("5"),
+            "FromSymbolError: expected unit/0 but found `5`"
+        );
+        assert!(from_symbol_error::<Edge>("edge(1,b,\"a\")").starts_with(
+            "FromSymbolError: could not decode argument 1 of edge/3: \
+             InternalError: Call to clingo_symbol_number() failed"
+        ));
+        assert_eq!(
+            from_symbol_error::<Wrapper>("wrapper(other,1)"),
+            "FromSymbolError: could not decode argument 0 of wrapper/2: \
+             FromSymbolError: expected unit/0 but found `other`"
+        );
+        assert!(
+            from_symbol_error::<Containers>("containers((1,a),none,(true,\"x\"),())").starts_with(
+                "FromSymbolError: could not decode argument 0 of containers/4: \
+                 FromSymbolError: could not decode argument 1 of tuple of size 2: \
+                 InternalError: Call to clingo_symbol_number() failed"
+            )
+        );
+        assert_eq!(
+            from_symbol_error::<Shape>("point(1)"),
+            "FromSymbolError: expected one of empty/0, point/2, circle/2, group/1 \
+             but found `point(1)`"
+        );
+        assert_eq!(
+            from_symbol_error::<Generic<bool>>("generic(yes)"),
+            "FromSymbolError: could not decode argument 0 of generic/1: \
+             FromSymbolError: expected `true` or `false` but found `yes`"
+        );
+        assert_eq!(
+            from_symbol_error::<Generic<Option<u32>>>("generic(some(1,2))"),
+            "FromSymbolError: could not decode argument 0 of generic/1: \
+             FromSymbolError: expected `none` or some/1 but found `some(1,2)`"
+        );
+    }
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn model_atoms_of() {
+    use clingo::*;
+
+    #[derive(Debug, PartialEq, FromSymbol)]
+    struct Edge(u32, u32);
+
+    let mut ctl = control(vec![]).unwrap();
+    ctl.add(
+        "base",
+        &[],
+        "node(1..3). edge(X,X+1) :- node(X), node(X+1).",
+    )
+    .unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let mut handle = ctl.solve(SolveMode::YIELD, &[]).unwrap();
+    handle.resume().unwrap();
+    let model = handle.model().unwrap().unwrap();
+    let mut edges: Vec<Edge> = model
+        .atoms_of(Signature::new("edge", 2, true).unwrap())
+        .unwrap();
+    edges.sort_by_key(|edge| edge.0);
+    assert_eq!(edges, vec![Edge(1, 2), Edge(2, 3)]);
+    handle.close().unwrap();
 }
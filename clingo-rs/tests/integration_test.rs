@@ -319,14 +319,9 @@ fn ast_head_literal() {
         format!("{}", hlit),
         "&tset { test: toast } theory_operator test"
     );
-    
-    let ta_term : Term = tatom.term();
-    assert_eq!(
-        format!("{}", ta_term),
-        "tset"
-    );
 
-    
+    let ta_term: Term = tatom.term().unwrap();
+    assert_eq!(format!("{}", ta_term), "tset");
 }
 #[test]
 fn ast_body_literal() {
@@ -618,7 +613,7 @@ fn ast_rule() {
 
     let stm = rule1.clone().into();
     test_statement(&stm, "#true.");
-    let head = rule1.head();
+    let head = rule1.head().unwrap();
     assert_eq!(format!("{}", head), "#true");
 
     let stm = rule2.into();
@@ -639,7 +634,7 @@ fn ast_rule_body() {
     let lit = basic_literal_from_symbolic_atom(&loc, Sign::NoSign, atom).unwrap();
     let body = [lit.clone().into()];
     let rule = rule(&loc, lit, &body).unwrap();
-    let h = rule.head();
+    let h = rule.head().unwrap();
     assert_eq!(format!("{}", h), "test");
     drop(h);
     let stm = rule.into();
@@ -652,6 +647,417 @@ fn ast_program() {
     let stm = prg.into();
     test_statement(&stm, "#program base.");
 }
+
+struct RuleRewriter<F: FnMut(&mut Rule)> {
+    rewrite: F,
+    rules: Vec<String>,
+}
+impl<F: FnMut(&mut Rule)> StatementHandler for RuleRewriter<F> {
+    fn on_statement(&mut self, stm: &Statement) -> bool {
+        if let Ok(StatementIsA::Rule(mut rule)) = stm.clone().is_a() {
+            (self.rewrite)(&mut rule);
+            self.rules.push(format!("{}", rule));
+        }
+        true
+    }
+}
+fn rewrite_rules<F: FnMut(&mut Rule)>(program: &str, rewrite: F) -> Vec<String> {
+    let mut rewriter = RuleRewriter {
+        rewrite,
+        rules: vec![],
+    };
+    parse_string_with_statement_handler(program, &mut rewriter).unwrap();
+    rewriter.rules
+}
+#[test]
+fn ast_accessors_rule() {
+    let rules = rewrite_rules("p(X, f(1)) :- q(X), not r(X), X > 1.", |rule| {
+        assert_eq!(rule.location().unwrap().begin_line(), 1);
+        assert_eq!(rule.body().size().unwrap(), 3);
+
+        let head = rule.head().unwrap();
+        let lit = match head.is_a().unwrap() {
+            HeadIsA::Literal(lit) => lit,
+            x => panic!("unexpected head: {:?}", x),
+        };
+        assert_eq!(lit.sign().unwrap(), Sign::NoSign);
+        let atom = match lit.atom().unwrap().is_a().unwrap() {
+            AtomIsA::SymbolicAtom(atom) => atom,
+            x => panic!("unexpected atom: {:?}", x),
+        };
+        let mut fun = match atom.symbol().unwrap().is_a().unwrap() {
+            TermIsA::Function(fun) => fun,
+            x => panic!("unexpected term: {:?}", x),
+        };
+        assert_eq!(fun.name().unwrap(), "p");
+        assert!(!fun.external().unwrap());
+        let arguments = fun.arguments().unwrap();
+        assert_eq!(arguments.len(), 2);
+        assert_eq!(format!("{}", arguments[0]), "X");
+        match arguments[1].clone().is_a().unwrap() {
+            TermIsA::Function(f) => assert_eq!(f.arguments().unwrap().len(), 1),
+            x => panic!("unexpected term: {:?}", x),
+        }
+
+        // rewrite the head in place, sub nodes share the AST of the rule
+        fun.set_name("s").unwrap();
+        fun.set_arguments(&arguments[..1]).unwrap();
+        assert_eq!(format!("{}", rule), "s(X) :- q(X); not r(X); X > 1.");
+
+        let body: Vec<BodyLiteral> = rule.body().collect();
+        match body[1].clone().is_a().unwrap() {
+            BodyLiteralIsA::Literal(lit) => assert_eq!(lit.sign().unwrap(), Sign::Negation),
+            x => panic!("unexpected body literal: {:?}", x),
+        }
+        match body[2].clone().is_a().unwrap() {
+            BodyLiteralIsA::Literal(lit) => match lit.atom().unwrap().is_a().unwrap() {
+                AtomIsA::Comparison(cmp) => {
+                    let guards = cmp.guards().unwrap();
+                    assert_eq!(guards.len(), 1);
+                    assert_eq!(
+                        guards[0].comparison().unwrap(),
+                        ComparisonOperator::GreaterThan
+                    );
+                    assert_eq!(format!("{}", cmp.term().unwrap()), "X");
+                }
+                x => panic!("unexpected atom: {:?}", x),
+            },
+            x => panic!("unexpected body literal: {:?}", x),
+        }
+        rule.set_body(&body[..1]).unwrap();
+    });
+    assert_eq!(rules, vec!["s(X) :- q(X)."]);
+}
+fn unpool_program(program: &str, unpool_type: UnpoolType) -> Vec<String> {
+    struct Unpooler {
+        unpool_type: UnpoolType,
+        statements: Vec<String>,
+    }
+    impl StatementHandler for Unpooler {
+        fn on_statement(&mut self, stm: &Statement) -> bool {
+            for stm in unpool(stm, self.unpool_type).unwrap() {
+                self.statements.push(stm.to_string().unwrap());
+            }
+            true
+        }
+    }
+    let mut unpooler = Unpooler {
+        unpool_type,
+        statements: vec![],
+    };
+    parse_string_with_statement_handler(program, &mut unpooler).unwrap();
+    // drop the implicit `#program base.`
+    unpooler.statements.remove(0);
+    unpooler.statements
+}
+#[test]
+fn ast_unpool_other() {
+    let stms = unpool_program("p(1;2). a :- b(X;Y), c : d(1;2).", UnpoolType::Other);
+    assert_eq!(
+        stms,
+        vec![
+            "p(1).",
+            "p(2).",
+            "a :- b(X); c: d(1); c: d(2).",
+            "a :- b(Y); c: d(1); c: d(2).",
+        ]
+    );
+}
+#[test]
+fn ast_unpool_condition() {
+    // conditions are only reached through the enclosing statement when it is unpooled as well
+    let stms = unpool_program("p(1;2). a :- b(X;Y), c : d(1;2).", UnpoolType::Condition);
+    assert_eq!(stms, vec!["p(1;2).", "a :- b(X;Y); c: d(1;2)."]);
+}
+#[test]
+fn ast_unpool_all() {
+    let stms = unpool_program("p(1;2). a :- b(X;Y), c : d(1;2).", UnpoolType::All);
+    assert_eq!(
+        stms,
+        vec![
+            "p(1).",
+            "p(2).",
+            "a :- b(X); c: d(1); c: d(2).",
+            "a :- b(Y); c: d(1); c: d(2).",
+        ]
+    );
+    let stms = unpool_program("a :- #count{ X: d(X;Y) } > 0.", UnpoolType::All);
+    assert_eq!(stms, vec!["a :- 0 < #count { X: d(X); X: d(Y) }."]);
+    // statements without pools are passed through
+    let stms = unpool_program("a :- b.", UnpoolType::All);
+    assert_eq!(stms, vec!["a :- b."]);
+}
+struct VariableCollector(Vec<String>);
+impl<'a> Visitor<'a> for VariableCollector {
+    fn visit_variable(&mut self, variable: &Variable<'a>) -> Result<(), ClingoError> {
+        self.0.push(variable.name()?);
+        Ok(())
+    }
+    // do not descend into conditions of aggregate elements
+    fn visit_body_aggregate_element(
+        &mut self,
+        element: &BodyAggregateElement<'a>,
+    ) -> Result<(), ClingoError> {
+        for term in element.terms()? {
+            self.visit(&Node::try_from(&term)?)?;
+        }
+        Ok(())
+    }
+}
+struct VisitingHandler(Vec<String>);
+impl StatementHandler for VisitingHandler {
+    fn on_statement(&mut self, stm: &Statement) -> bool {
+        let mut collector = VariableCollector(vec![]);
+        collector.visit_statement(stm).unwrap();
+        self.0.append(&mut collector.0);
+        true
+    }
+}
+#[test]
+fn ast_visitor() {
+    let mut handler = VisitingHandler(vec![]);
+    parse_string_with_statement_handler(
+        "p(X, f(Y)) :- q(X, Y), not r(Z), #count{ W: s(V) } > X.",
+        &mut handler,
+    )
+    .unwrap();
+    // the guard `> X` is stored as left guard `X <` and visited first
+    assert_eq!(handler.0, vec!["X", "Y", "X", "Y", "Z", "X", "W"]);
+}
+// appends a suffix to all variables and replaces `f(..)` by the constant `a`
+struct Renamer;
+impl<'a> Transformer<'a> for Renamer {
+    fn transform_variable(&mut self, variable: Variable<'a>) -> Result<Node<'a>, ClingoError> {
+        let name = format!("{}_", variable.name()?);
+        Ok(ast::variable(&variable.location()?, &name)?.into())
+    }
+    fn transform_function(&mut self, function: Function<'a>) -> Result<Node<'a>, ClingoError> {
+        if function.name()? == "f" {
+            let sym = Symbol::create_id("a", true)?;
+            return Ok(ast::symbolic_term(&function.location()?, &sym)?.into());
+        }
+        self.transform_children(function.into())
+    }
+}
+struct TransformingHandler<'b> {
+    builder: ast::ProgramBuilder<'b>,
+    original: Vec<String>,
+    transformed: Vec<String>,
+}
+impl<'b> StatementHandler for TransformingHandler<'b> {
+    fn on_statement(&mut self, stm: &Statement) -> bool {
+        let new = Renamer.transform_statement(stm).unwrap();
+        self.original.push(stm.to_string().unwrap());
+        self.transformed.push(new.to_string().unwrap());
+        self.builder.add(&new).is_ok()
+    }
+}
+#[test]
+fn ast_transformer() {
+    let mut ctl = control(vec![]).unwrap();
+    let mut handler = TransformingHandler {
+        builder: ast::ProgramBuilder::from(&mut ctl).unwrap(),
+        original: vec![],
+        transformed: vec![],
+    };
+    parse_string_with_statement_handler("q(1). p(X, f(X)) :- q(X).", &mut handler).unwrap();
+    let TransformingHandler {
+        builder,
+        original,
+        transformed,
+    } = handler;
+    builder.end().unwrap();
+    // the parsed statements are left untouched
+    assert_eq!(original[2], "p(X,f(X)) :- q(X).");
+    assert_eq!(transformed[1], "q(1).");
+    assert_eq!(transformed[2], "p(X_,a) :- q(X_).");
+
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let mut handle = ctl.solve(SolveMode::YIELD, &[]).unwrap();
+    handle.resume().unwrap();
+    let model = handle.model().unwrap().unwrap();
+    let atoms: Vec<String> = model
+        .symbols(ShowType::SHOWN)
+        .unwrap()
+        .iter()
+        .map(|sym| sym.to_string())
+        .collect();
+    assert_eq!(atoms, vec!["q(1)", "p(1,a)"]);
+    handle.close().unwrap();
+}
+#[test]
+fn ast_transformer_statement_type() {
+    // replacing a statement by a term is reported as an error
+    struct Replacer;
+    impl<'a> Transformer<'a> for Replacer {
+        fn transform_rule(&mut self, rule: Rule<'a>) -> Result<Node<'a>, ClingoError> {
+            Ok(ast::variable(&rule.location()?, "X")?.into())
+        }
+    }
+    struct Handler(Vec<bool>);
+    impl StatementHandler for Handler {
+        fn on_statement(&mut self, stm: &Statement) -> bool {
+            self.0.push(Replacer.transform_statement(stm).is_ok());
+            true
+        }
+    }
+    let mut handler = Handler(vec![]);
+    parse_string_with_statement_handler("a.", &mut handler).unwrap();
+    assert_eq!(handler.0, vec![true, false]);
+}
+struct StatementCollector(Vec<String>);
+impl StatementHandler for StatementCollector {
+    fn on_statement(&mut self, stm: &Statement) -> bool {
+        let kind = match stm.clone().is_a() {
+            Ok(StatementIsA::Program(_)) => return true,
+            Ok(StatementIsA::TheoryDefinition(x)) => format!("theory {}", x.name().unwrap()),
+            Ok(StatementIsA::Comment(x)) => {
+                format!("{:?} {}", x.comment_type().unwrap(), x.value().unwrap())
+            }
+            Ok(StatementIsA::ShowSignature(x)) => format!("show {}", x.name().unwrap()),
+            Ok(StatementIsA::Rule(x)) => format!("rule {}", x),
+            Ok(_) => "other".to_string(),
+            Err(e) => panic!("{}", e),
+        };
+        self.0.push(kind);
+        true
+    }
+}
+#[test]
+fn ast_statement_types() {
+    let mut collector = StatementCollector(vec![]);
+    parse_string_with_statement_handler(
+        "#theory t { term { + : 1, unary }; &a/0 : term, any }.\n\
+         % a line comment\n\
+         %* a block\ncomment *%\n\
+         #show p/1.\n\
+         p(1).",
+        &mut collector,
+    )
+    .unwrap();
+    assert_eq!(
+        collector.0,
+        vec![
+            "theory t",
+            "Line % a line comment",
+            "Block %* a block\ncomment *%",
+            "show p",
+            "rule p(1).",
+        ]
+    );
+}
+#[test]
+fn ast_statement_handler_error() {
+    struct Failing(usize);
+    impl StatementHandler for Failing {
+        fn on_statement(&mut self, _stm: &Statement) -> bool {
+            self.0 += 1;
+            false
+        }
+    }
+    let mut handler = Failing(0);
+    let res = parse_string_with_statement_handler("a. b.", &mut handler);
+    assert!(matches!(res, Err(ClingoError::InternalError { .. })));
+    assert_eq!(handler.0, 1);
+}
+#[test]
+fn ast_parse_with_logger() {
+    struct Messages(Vec<(Warning, String)>);
+    impl Logger for Messages {
+        fn log(&mut self, code: Warning, message: &str) {
+            self.0.push((code, message.to_string()));
+        }
+    }
+    let mut collector = StatementCollector(vec![]);
+    let mut logger = Messages(vec![]);
+    parse_string_with_logger("a.\nb :- c.", &mut collector, &mut logger, 10).unwrap();
+    assert_eq!(collector.0, vec!["rule a.", "rule b :- c."]);
+    assert!(logger.0.is_empty());
+
+    let res = parse_string_with_logger("a.\nb :- ).\nc :- ).", &mut collector, &mut logger, 10);
+    assert!(matches!(res, Err(ClingoError::InternalError { .. })));
+    assert_eq!(logger.0.len(), 2);
+    assert!(matches!(logger.0[0].0, Warning::RuntimeError));
+    assert!(logger.0[0]
+        .1
+        .starts_with("<string>:2:6-7: error: syntax error"));
+    assert!(logger.0[1]
+        .1
+        .starts_with("<string>:3:6-7: error: syntax error"));
+}
+#[test]
+fn ast_comment() {
+    let loc = Location::default();
+    let mut stm = comment(&loc, "% hello", CommentType::Line).unwrap();
+    assert_eq!(stm.value().unwrap(), "% hello");
+    stm.set_comment_type(CommentType::Block).unwrap();
+    stm.set_value("%* hello *%").unwrap();
+    let stm: Statement = stm.into();
+    assert_eq!(stm.to_string().unwrap(), "%* hello *%");
+}
+#[test]
+fn ast_accessors_theory_atom() {
+    let loc = Location::default();
+    let sym = Symbol::create_id("test", true).unwrap();
+    let term = symbolic_term(&loc, &sym).unwrap();
+    let tuple = [term.clone().into()];
+    let element = theory_atom_element(&tuple, &[]).unwrap();
+    let elements = [element];
+    let mut tatom = theory_atom(&loc, term.clone(), &elements, None).unwrap();
+
+    assert!(tatom.guard().unwrap().is_none());
+    let elements = tatom.elements().unwrap();
+    assert_eq!(elements.len(), 1);
+    assert_eq!(elements[0].terms().unwrap().len(), 1);
+    assert!(elements[0].condition().unwrap().is_empty());
+
+    let guard = theory_guard("<=", term.clone()).unwrap();
+    tatom.set_guard(Some(&guard)).unwrap();
+    let guard = tatom.guard().unwrap().unwrap();
+    assert_eq!(guard.operator_name().unwrap(), "<=");
+
+    let sym = Symbol::create_id("diff", true).unwrap();
+    let mut new_term = symbolic_term(&loc, &sym).unwrap();
+    assert_eq!(new_term.symbol().unwrap(), sym);
+    new_term
+        .set_symbol(Symbol::create_id("sum", true).unwrap())
+        .unwrap();
+    tatom.set_term(new_term).unwrap();
+    assert_eq!(format!("{}", tatom.term().unwrap()), "sum");
+    tatom.set_elements(&[]).unwrap();
+    tatom.set_guard(None).unwrap();
+    assert_eq!(tatom.to_string().unwrap(), "&sum { }");
+}
+#[test]
+fn ast_accessors_statements() {
+    let loc = Location::new("<test>", "<test>", 3, 3, 1, 7).unwrap();
+    let mut sig = show_signature(&Location::default(), "p", 2, true, false).unwrap();
+    assert_eq!(sig.name().unwrap(), "p");
+    assert_eq!(sig.arity().unwrap(), 2);
+    assert!(sig.positive().unwrap());
+    sig.set_name("q").unwrap();
+    sig.set_arity(1).unwrap();
+    sig.set_positive(false).unwrap();
+    sig.set_location(&loc).unwrap();
+    assert_eq!(sig.location().unwrap().begin_line(), 3);
+    assert_eq!(sig.location().unwrap().begin_file().unwrap(), "<test>");
+    assert_eq!(format!("{}", Statement::from(sig)), "#show -q/1.");
+
+    let mut prg = program(&loc, "base", &[]).unwrap();
+    let param = id(&loc, "k").unwrap();
+    prg.set_parameters(&[param]).unwrap();
+    prg.set_name("step").unwrap();
+    assert_eq!(prg.parameters().unwrap()[0].name().unwrap(), "k");
+    assert_eq!(format!("{}", Statement::from(prg)), "#program step(k).");
+
+    let operators = theory_guard_definition(&["<", ">"], "term").unwrap();
+    assert_eq!(operators.operators().unwrap(), vec!["<", ">"]);
+    assert_eq!(operators.term().unwrap(), "term");
+    let mut operators = operators;
+    operators.set_operators(&["="]).unwrap();
+    assert_eq!(operators.operators().unwrap(), vec!["="]);
+}
 // #[test]
 // fn ui() {
 //     let t = trybuild::TestCases::new();
@@ -681,3 +1087,844 @@ fn ast_program() {
 //     t.compile_fail("tests/ui/ast_statement_from_rule.rs");
 //     //check builder.add(stmt)
 // }
+struct DLHandler {
+    theory: theory::difference_logic::DifferenceLogic,
+    models: std::sync::Arc<std::sync::Mutex<Vec<Vec<String>>>>,
+}
+impl SolveEventHandler for DLHandler {
+    fn on_solve_event(&mut self, event: SolveEvent, _goon: &mut bool) -> bool {
+        use theory::Theory;
+        match event {
+            SolveEvent::Model(model) => {
+                if !self.theory.on_model(model) {
+                    return false;
+                }
+                let mut symbols: Vec<String> = model
+                    .symbols(ShowType::THEORY)
+                    .unwrap()
+                    .iter()
+                    .map(|sym| sym.to_string())
+                    .collect();
+                symbols.sort();
+                self.models.lock().unwrap().push(symbols);
+                true
+            }
+            SolveEvent::Statistics { step, akku } => self.theory.on_statistics(step, akku),
+            _ => true,
+        }
+    }
+}
+fn solve_difference_logic(program: &str) -> (Vec<Vec<String>>, Control) {
+    use theory::Theory;
+    let mut theory = theory::difference_logic::DifferenceLogic::new();
+    let mut ctl = control(vec!["0".to_string()]).unwrap();
+    assert!(theory.register(&mut ctl));
+    ctl.add("base", &[], program).unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let models = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let handler = DLHandler {
+        theory,
+        models: models.clone(),
+    };
+    let mut handle = ctl
+        .solve_with_event_handler(SolveMode::YIELD, &[], handler)
+        .unwrap();
+    loop {
+        handle.resume().unwrap();
+        if handle.model().unwrap().is_none() {
+            break;
+        }
+    }
+    handle.get().unwrap();
+    let ctl = handle.close().unwrap();
+    let models = models.lock().unwrap().clone();
+    (models, ctl)
+}
+#[test]
+fn difference_logic() {
+    let (models, ctl) =
+        solve_difference_logic("&diff{ a - b } <= -1. &diff{ b - 0 } <= 3. &diff{ 0 - b } <= -2.");
+    assert_eq!(models, vec![vec!["dl(0,0)", "dl(a,1)", "dl(b,2)"]]);
+
+    let stats = ctl.statistics().unwrap();
+    let root = stats.root().unwrap();
+    let step = stats.map_at(root, "user_step").unwrap();
+    let dl = stats.map_at(step, "DifferenceLogic").unwrap();
+    let edges = stats.map_at(dl, "Edges").unwrap();
+    assert_eq!(stats.value_get(edges).unwrap(), 3.0);
+}
+#[test]
+fn difference_logic_accumulated_statistics() {
+    use theory::Theory;
+    let mut theory = theory::difference_logic::DifferenceLogic::new();
+    let mut ctl = control(vec![]).unwrap();
+    assert!(theory.register(&mut ctl));
+    ctl.add("base", &[], "&diff{ a - b } <= -1. &diff{ b - 0 } <= 3.")
+        .unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    // the step statistics of the theory count the current solve call, they add up in the
+    // accumulated statistics
+    let models = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    for _ in 0..2 {
+        let handler = DLHandler {
+            theory: theory.clone(),
+            models: models.clone(),
+        };
+        let mut handle = ctl
+            .solve_with_event_handler(SolveMode::empty(), &[], handler)
+            .unwrap();
+        handle.get().unwrap();
+        ctl = handle.close().unwrap();
+    }
+    // the edges of the first solve call are kept in the second one
+    let models = models.lock().unwrap();
+    assert_eq!(models.len(), 2);
+    assert_eq!(models[0], models[1]);
+    // the second solve call adds no edges
+    assert_eq!(dl_statistic(&ctl, "user_step", "Edges"), 0.0);
+    assert_eq!(dl_statistic(&ctl, "user_accu", "Edges"), 2.0);
+}
+// the statistic `name` of the difference logic theory in `user_step` or `user_accu`
+fn dl_statistic(ctl: &Control, user: &str, name: &str) -> f64 {
+    let stats = ctl.statistics().unwrap();
+    let root = stats.root().unwrap();
+    let user = stats.map_at(root, user).unwrap();
+    let dl = stats.map_at(user, "DifferenceLogic").unwrap();
+    stats.value_get(stats.map_at(dl, name).unwrap()).unwrap()
+}
+#[test]
+fn difference_logic_step_statistics() {
+    use theory::Theory;
+    let mut theory = theory::difference_logic::DifferenceLogic::new();
+    let mut ctl = control(vec!["0".to_string()]).unwrap();
+    assert!(theory.register(&mut ctl));
+    ctl.add(
+        "base",
+        &[],
+        "1 { p; q } 1. &diff{ a - b } <= -1 :- p. &diff{ b - a } <= -1 :- q.",
+    )
+    .unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let mut steps = vec![];
+    for _ in 0..2 {
+        let handler = DLHandler {
+            theory: theory.clone(),
+            models: Default::default(),
+        };
+        let mut handle = ctl
+            .solve_with_event_handler(SolveMode::empty(), &[], handler)
+            .unwrap();
+        handle.get().unwrap();
+        ctl = handle.close().unwrap();
+        steps.push(dl_statistic(&ctl, "user_step", "Propagations"));
+    }
+    assert!(steps.iter().all(|propagations| *propagations > 0.0));
+    let accumulated = dl_statistic(&ctl, "user_accu", "Propagations");
+    assert_eq!(accumulated, steps[0] + steps[1]);
+    assert_ne!(accumulated, steps[1]);
+}
+#[test]
+fn difference_logic_overflow() {
+    // the assignment of a is -4000000000, which is no clingo number
+    use theory::Theory;
+    let mut theory = theory::difference_logic::DifferenceLogic::new();
+    let mut ctl = control(vec![]).unwrap();
+    assert!(theory.register(&mut ctl));
+    ctl.add(
+        "base",
+        &[],
+        "&diff{ a - b } <= -2000000000. &diff{ b - 0 } <= -2000000000.",
+    )
+    .unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let handler = DLHandler {
+        theory,
+        models: Default::default(),
+    };
+    let mut handle = ctl
+        .solve_with_event_handler(SolveMode::YIELD, &[], handler)
+        .unwrap();
+    assert!(handle.resume().and_then(|_| handle.get()).is_err());
+}
+#[test]
+fn difference_logic_conflict() {
+    // the cycle a -> b -> a has weight -1
+    let (models, _) =
+        solve_difference_logic("{ p }. &diff{ a - b } <= -1 :- p. &diff{ b - a } <= 0.");
+    assert_eq!(models, vec![vec!["dl(a,0)", "dl(b,0)"]]);
+
+    let (models, _) = solve_difference_logic("&diff{ a - b } <= -1. &diff{ b - a } <= 0.");
+    assert!(models.is_empty());
+}
+#[test]
+fn difference_logic_assignment() {
+    use theory::{Theory, TheoryValue};
+    let mut theory = theory::difference_logic::DifferenceLogic::new();
+    let mut ctl = control(vec![]).unwrap();
+    assert!(theory.register(&mut ctl));
+    ctl.add("base", &[], "&diff{ x - y } <= -5.").unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let mut handle = ctl.solve(SolveMode::YIELD, &[]).unwrap();
+    handle.resume().unwrap();
+    let thread_id = handle.model().unwrap().unwrap().thread_id().unwrap();
+    let mut assignment: Vec<(String, i64)> = theory
+        .assignment(thread_id)
+        .map(|(sym, value)| match value {
+            TheoryValue::IntNumber(value) => (sym.to_string(), value),
+            _ => panic!("unexpected theory value"),
+        })
+        .collect();
+    assignment.sort();
+    assert_eq!(
+        assignment,
+        vec![("x".to_string(), -5), ("y".to_string(), 0)]
+    );
+    let mut index = 0;
+    assert!(theory.lookup_symbol(Symbol::create_id("y", true).unwrap(), &mut index));
+    assert_eq!(theory.get_symbol(index).to_string(), "y");
+    handle.close().unwrap();
+}
+fn first_model<T>(program: &str, f: impl Fn(&Model) -> T) -> T {
+    let mut ctl = control(vec![]).unwrap();
+    ctl.add("base", &[], program).unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let mut handle = ctl.solve(SolveMode::YIELD, &[]).unwrap();
+    handle.resume().unwrap();
+    let result = f(handle.model().unwrap().unwrap());
+    handle.close().unwrap();
+    result
+}
+#[test]
+fn model_atoms_of() {
+    let program = "edge(1,2). edge(2,3). -edge(3,1). edge(a). node(1..3).";
+    let (positive, negative, constants) = first_model(program, |model| {
+        let to_strings = |symbols: Vec<Symbol>| -> Vec<String> {
+            let mut strings: Vec<String> = symbols.iter().map(|s| s.to_string()).collect();
+            strings.sort();
+            strings
+        };
+        (
+            to_strings(
+                model
+                    .atoms_of(Signature::new("edge", 2, true).unwrap())
+                    .unwrap(),
+            ),
+            to_strings(
+                model
+                    .atoms_of(Signature::new("edge", 2, false).unwrap())
+                    .unwrap(),
+            ),
+            to_strings(
+                model
+                    .atoms_of(Signature::new("node", 0, true).unwrap())
+                    .unwrap(),
+            ),
+        )
+    });
+    assert_eq!(positive, vec!["edge(1,2)", "edge(2,3)"]);
+    assert_eq!(negative, vec!["-edge(3,1)"]);
+    assert!(constants.is_empty());
+
+    // decoding errors are passed on
+    let result = first_model("p(1). p(a).", |model| {
+        model.atoms_of::<i32>(Signature::new("p", 1, true).unwrap())
+    });
+    assert!(result.is_err());
+}
+#[test]
+fn fact_base_round_trip() {
+    let facts = first_model("p(1..3). q(X+1) :- p(X). #show p/1. #show q/1.", |model| {
+        FactBase::from_model(model).unwrap()
+    });
+    assert_eq!(facts.len(), 6);
+    assert!(facts
+        .contains(&Symbol::create_function("q", &[Symbol::create_number(4)], true).unwrap())
+        .unwrap());
+    let program = facts.to_program();
+    assert_eq!(program, "p(1).\np(2).\np(3).\nq(2).\nq(3).\nq(4).\n");
+
+    let again = first_model(&program, |model| FactBase::from_model(model).unwrap());
+    assert_eq!(again, facts);
+    assert_eq!(again.to_program(), program);
+}
+// minimal executor that parks the current thread until the future is woken
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+    let waker = std::task::Waker::from(std::sync::Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            std::task::Poll::Ready(output) => return output,
+            std::task::Poll::Pending => std::thread::park(),
+        }
+    }
+}
+// pigeon hole problem that takes long to refute
+const PIGEON_HOLE: &str =
+    "p(1..30). h(1..29). 1 { in(P,H): h(H) } 1 :- p(P). :- in(P,H), in(Q,H), P < Q.";
+fn grounded_control(program: &str) -> Control {
+    let mut ctl = control(vec!["0".to_string()]).unwrap();
+    ctl.add("base", &[], program).unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    ctl
+}
+#[test]
+fn solve_async() {
+    let ctl = grounded_control("{ a; b }.");
+    let mut handle = ctl.solve_async(&[]).unwrap();
+    let mut models = vec![];
+    while let Some(model) = block_on(handle.next_model()).unwrap() {
+        assert!(handle.result().is_none());
+        let mut symbols: Vec<String> = model.symbols.iter().map(|s| s.to_string()).collect();
+        symbols.sort();
+        models.push(symbols.join(" "));
+    }
+    models.sort();
+    assert_eq!(models, vec!["", "a", "a b", "b"]);
+    // the handle keeps returning None after the search finished
+    assert!(block_on(handle.next_model()).unwrap().is_none());
+    let result = handle.result().unwrap();
+    assert!(result.contains(SolveResult::SATISFIABLE | SolveResult::EXHAUSTED));
+    let ctl = handle.close().unwrap();
+
+    // the control object can be used again
+    let mut handle = ctl.solve_async(&[]).unwrap();
+    assert!(block_on(handle.next_model()).unwrap().is_some());
+    handle.close().unwrap();
+}
+#[test]
+fn solve_async_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Control>();
+    assert_send::<SolveHandle>();
+    assert_send::<AsyncSolveHandle<DefaultCtx>>();
+    assert_send::<NextModel<'static, DefaultCtx>>();
+    assert_send::<SolveInterrupter>();
+
+    // ground in one thread, solve in another
+    let ctl = grounded_control("p(1..3).");
+    let count = std::thread::spawn(move || {
+        let mut handle = ctl.solve_async(&[]).unwrap();
+        let mut count = 0;
+        while let Some(model) = block_on(handle.next_model()).unwrap() {
+            count += model.symbols.len();
+        }
+        handle.close().unwrap();
+        count
+    })
+    .join()
+    .unwrap();
+    assert_eq!(count, 3);
+}
+#[test]
+fn solve_async_interrupt() {
+    let ctl = grounded_control(PIGEON_HOLE);
+    let mut handle = ctl.solve_async(&[]).unwrap();
+    let interrupter = handle.interrupter();
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        interrupter.interrupt();
+    });
+    assert!(block_on(handle.next_model()).unwrap().is_none());
+    thread.join().unwrap();
+    let result = handle.result().unwrap();
+    assert!(result.contains(SolveResult::INTERRUPTED));
+    assert!(!result.contains(SolveResult::EXHAUSTED));
+    let ctl = handle.close().unwrap();
+
+    // interrupting a closed handle has no effect
+    let mut handle = ctl.solve_async(&[]).unwrap();
+    let interrupter = handle.interrupter();
+    handle.next_model();
+    drop(handle);
+    interrupter.interrupt();
+}
+#[test]
+fn solve_async_interrupt_waiting_thread() {
+    // the future is awaited in another thread while this thread interrupts the search
+    let ctl = grounded_control(PIGEON_HOLE);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let thread = std::thread::spawn(move || {
+        let mut handle = ctl.solve_async(&[]).unwrap();
+        sender.send(handle.interrupter()).unwrap();
+        let model = block_on(handle.next_model()).unwrap();
+        (model.is_none(), handle.result().unwrap().bits())
+    });
+    let interrupter = receiver.recv().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    interrupter.interrupt();
+    let (no_model, result) = thread.join().unwrap();
+    assert!(no_model);
+    assert_eq!(
+        result & SolveResult::INTERRUPTED.bits(),
+        SolveResult::INTERRUPTED.bits()
+    );
+}
+#[test]
+fn solve_async_poll() {
+    // the first model is found immediately, the second one requires refuting the pigeon hole problem
+    let ctl = grounded_control(&format!(
+        "{{ x }}. {}",
+        PIGEON_HOLE.replace("p(1..30).", "p(1..30) :- x.")
+    ));
+    let mut handle = ctl.solve_async(&[]).unwrap();
+    assert!(block_on(handle.next_model()).unwrap().is_some());
+    // polling resumes the search but does not wait for the next model
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    let mut future = handle.next_model();
+    let start = std::time::Instant::now();
+    for _ in 0..10 {
+        assert!(std::future::Future::poll(std::pin::Pin::new(&mut future), &mut cx).is_pending());
+    }
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    drop(future);
+    handle.interrupter().interrupt();
+    assert!(block_on(handle.next_model()).unwrap().is_none());
+    assert!(handle.result().unwrap().contains(SolveResult::INTERRUPTED));
+}
+fn external_literal(ctl: &Control, name: &str) -> SolverLiteral {
+    let symbol = Symbol::create_id(name, true).unwrap();
+    let atoms = ctl.symbolic_atoms().unwrap();
+    let mut atoms_it = atoms.iter().unwrap();
+    let atom = atoms_it.find(|atom| atom.symbol().unwrap() == symbol);
+    atom.unwrap().literal().unwrap()
+}
+fn count_models(handle: &mut SolveHandleRef) -> usize {
+    let mut count = 0;
+    loop {
+        handle.resume().unwrap();
+        match handle.model().unwrap() {
+            Some(_) => count += 1,
+            None => return count,
+        }
+    }
+}
+#[test]
+fn solve_mut_multi_shot() {
+    let mut ctl = grounded_control("{ a; b }. #external e. :- e, not a.");
+    let e = external_literal(&ctl, "e");
+
+    let mut handle = ctl.solve_mut(SolveMode::YIELD, &[]).unwrap();
+    assert_eq!(count_models(&mut handle), 4);
+    handle.close().unwrap();
+
+    ctl.assign_external(e, TruthValue::True).unwrap();
+    let mut handle = ctl.solve_mut(SolveMode::YIELD, &[]).unwrap();
+    assert_eq!(count_models(&mut handle), 2);
+    // dropping the handle releases the control object as well
+    drop(handle);
+
+    ctl.add("more", &[], ":- b.").unwrap();
+    let part = Part::new("more", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let mut handle = ctl.solve_mut(SolveMode::YIELD, &[]).unwrap();
+    assert_eq!(count_models(&mut handle), 1);
+    assert!(handle.get().unwrap().contains(SolveResult::SATISFIABLE));
+    handle.close().unwrap();
+
+    // the search can be abandoned after the first model
+    let mut handle = ctl.solve_mut(SolveMode::YIELD, &[]).unwrap();
+    handle.resume().unwrap();
+    assert!(handle.model().unwrap().is_some());
+    handle.cancel().unwrap();
+    handle.close().unwrap();
+}
+#[test]
+fn solve_mut_core() {
+    let mut ctl = grounded_control("{ a; b }. :- a, b.");
+    let a = external_literal(&ctl, "a");
+    let b = external_literal(&ctl, "b");
+    let mut handle = ctl.solve_mut(SolveMode::empty(), &[a, b]).unwrap();
+    assert!(handle.get().unwrap().contains(SolveResult::UNSATISFIABLE));
+    let mut core = handle.core().unwrap();
+    core.sort_by_key(|literal| literal.get_integer());
+    let mut expected = vec![a, b];
+    expected.sort_by_key(|literal| literal.get_integer());
+    assert_eq!(core, expected);
+    handle.close().unwrap();
+    assert_eq!(ctl.all_models().unwrap().count(), 3);
+}
+#[test]
+fn model_iterators_close() {
+    let ctl = grounded_control("{ a; b }.");
+    let mut models = ctl.all_models().unwrap();
+    assert!(models.next().is_some());
+    assert!(models.error().is_none());
+    let ctl = models.close().unwrap();
+
+    let mut models = ctl.all_models().unwrap();
+    assert_eq!(models.by_ref().count(), 4);
+    assert!(models.next().is_none());
+    let mut ctl = models.close().unwrap();
+
+    ctl.add("opt", &[], "#minimize { 1,a: a; 1,b: b }.")
+        .unwrap();
+    let part = Part::new("opt", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let ctl = enumerate_optimal(ctl);
+    let mut models = ctl.optimal_models().unwrap();
+    let optimal: Vec<MModel> = models.by_ref().collect();
+    assert_eq!(optimal.len(), 1);
+    assert_eq!(optimal[0].cost, vec![0]);
+    assert!(models.error().is_none());
+    models.close().unwrap();
+}
+fn enumerate_optimal(mut ctl: Control) -> Control {
+    let conf = ctl.configuration_mut().unwrap();
+    let root = conf.root().unwrap();
+    let key = conf.map_at(root, "solve.opt_mode").unwrap();
+    conf.value_set(key, "optN").unwrap();
+    ctl
+}
+fn ids(names: &[&str], positive: bool) -> Vec<cores::Assumption> {
+    names
+        .iter()
+        .map(|name| (Symbol::create_id(name, true).unwrap(), positive))
+        .collect()
+}
+#[test]
+fn cores_minimize() {
+    let mut ctl = grounded_control("{ a; b; c; d; e }. :- a, c, e. :- b, d. :- not c, not d.");
+
+    let mut minimizer = cores::CoreMinimizer::new(&mut ctl).unwrap();
+    assert!(minimizer
+        .is_satisfiable(&ids(&["a", "b", "c"], true))
+        .unwrap());
+    assert_eq!(minimizer.core(&ids(&["a", "c"], true)).unwrap(), None);
+
+    let assumptions = ids(&["a", "b", "c", "d", "e"], true);
+    let core = minimizer.core(&assumptions).unwrap().unwrap();
+    assert!(core
+        .iter()
+        .all(|assumption| assumptions.contains(assumption)));
+    let minimal = minimizer.minimize_deletion(&assumptions).unwrap().unwrap();
+    assert!(minimal == ids(&["a", "c", "e"], true) || minimal == ids(&["b", "d"], true));
+    let minimal = minimizer.quick_xplain(&assumptions).unwrap().unwrap();
+    assert!(minimal == ids(&["a", "c", "e"], true) || minimal == ids(&["b", "d"], true));
+
+    // negative assumptions
+    let mut assumptions = ids(&["c", "d"], false);
+    assumptions.extend(ids(&["a"], true));
+    let expected = ids(&["c", "d"], false);
+    assert_eq!(
+        minimizer.minimize_deletion(&assumptions).unwrap(),
+        Some(expected.clone())
+    );
+    assert_eq!(
+        minimizer.quick_xplain(&assumptions).unwrap(),
+        Some(expected)
+    );
+
+    // atoms that are not part of the program are false
+    let mut assumptions = ids(&["a"], true);
+    assumptions.extend(ids(&["x"], false));
+    assert!(minimizer.is_satisfiable(&assumptions).unwrap());
+    assumptions.extend(ids(&["y"], true));
+    assert_eq!(
+        minimizer.quick_xplain(&assumptions).unwrap(),
+        Some(ids(&["y"], true))
+    );
+    assert!(minimizer.solve_calls() > 0);
+
+    // the control object can be used afterwards
+    assert_eq!(ctl.all_models().unwrap().count(), 13);
+}
+#[test]
+fn cores_symbols() {
+    let mut ctl = grounded_control("{ a; b; c }. :- a, not b.");
+    let a = external_literal(&ctl, "a");
+    let b = external_literal(&ctl, "b");
+    let c = external_literal(&ctl, "c");
+    let mut handle = ctl
+        .solve_mut(SolveMode::empty(), &[c, a, b.negate()])
+        .unwrap();
+    assert!(handle.get().unwrap().contains(SolveResult::UNSATISFIABLE));
+    let core = handle.core().unwrap();
+    handle.close().unwrap();
+    let mut symbols = cores::core_symbols(&ctl, &core).unwrap();
+    symbols.retain(|(symbol, _)| symbol.name().unwrap() != "c");
+    let mut expected = ids(&["a"], true);
+    expected.extend(ids(&["b"], false));
+    assert!(symbols
+        .iter()
+        .all(|assumption| expected.contains(assumption)));
+    assert_eq!(symbols.len(), 2);
+}
+#[derive(Debug, Clone, Default)]
+struct SharedBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+struct AspifCtx {
+    non: defaults::Non,
+    writer: aspif::AspifWriter<SharedBuffer>,
+}
+impl ControlCtx for AspifCtx {
+    type L = defaults::Non;
+    type P = defaults::Non;
+    type O = aspif::AspifWriter<SharedBuffer>;
+    type F = defaults::Non;
+
+    fn logger(&mut self) -> (&mut Self::L, u32) {
+        (&mut self.non, 0)
+    }
+    fn propagator(&mut self) -> (&mut Self::P, bool) {
+        (&mut self.non, false)
+    }
+    fn observer(&mut self) -> (&mut Self::O, bool) {
+        (&mut self.writer, false)
+    }
+    fn function_handler(&mut self) -> &mut Self::F {
+        &mut self.non
+    }
+}
+fn sorted_models<C: ControlCtx>(ctl: GenericControl<C>) -> Vec<Vec<String>> {
+    let mut models: Vec<Vec<String>> = ctl
+        .all_models()
+        .unwrap()
+        .map(|model| {
+            let mut symbols: Vec<String> = model.symbols.iter().map(|s| s.to_string()).collect();
+            symbols.sort();
+            symbols
+        })
+        .collect();
+    models.sort();
+    models
+}
+fn enumerate_all() -> Vec<String> {
+    vec!["0".to_string(), "--opt-mode=ignore".to_string()]
+}
+#[test]
+fn aspif_round_trip() {
+    let program = "
+        a :- not b. b :- not a. { c; d }. e.
+        :- c, d.
+        f :- 2 { a; c; d }.
+        #minimize { 1,c: c }.
+        #external x. #show x/0.
+        #show a/0. #show b/0. #show c/0. #show e/0. #show f/0. #show t(1) : d.
+        #project c/0.
+        #heuristic c. [1,level]
+    ";
+    let buffer = SharedBuffer::default();
+    let ctx = AspifCtx {
+        non: defaults::Non,
+        writer: aspif::AspifWriter::new(buffer.clone()),
+    };
+    let mut ctl = control_with_context(enumerate_all(), ctx).unwrap();
+    ctl.add("base", &[], program).unwrap();
+    let part = Part::new("base", vec![]).unwrap();
+    ctl.ground(&[part]).unwrap();
+    let expected = sorted_models(ctl);
+    assert_eq!(expected.len(), 6);
+    // the step ends when solving starts
+    let aspif = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    assert!(aspif.starts_with("asp 1 0 0 incremental\n"));
+    assert!(aspif.ends_with("\n0\n"));
+    assert!(aspif.contains("\n4 1 e 0\n"));
+
+    let mut ctl = control(enumerate_all()).unwrap();
+    let mut backend = ctl.backend().unwrap();
+    aspif::read_aspif(&aspif, &mut backend).unwrap();
+    drop(backend);
+    assert_eq!(sorted_models(ctl), expected);
+}
+#[test]
+fn aspif_errors() {
+    let mut ctl = control(vec![]).unwrap();
+    let mut backend = ctl.backend().unwrap();
+    let error = |program: &str, backend: &mut Backend| match aspif::read_aspif(program, backend) {
+        Err(ClingoError::AspifError(error)) => error,
+        result => panic!("unexpected result {:?}", result),
+    };
+    assert_eq!(error("", &mut backend).line, 1);
+    assert_eq!(error("asp 2 0 0\n", &mut backend).line, 1);
+    let e = error("asp 1 0 0\n1 0 1 x 0 0\n0\n", &mut backend);
+    assert_eq!(
+        (e.line, e.msg.as_str()),
+        (2, "expected a number but found `x`")
+    );
+    let e = error("asp 1 0 0\n1 0 1 1 0 2 1\n", &mut backend);
+    assert_eq!((e.line, e.msg.as_str()), (2, "unexpected end of statement"));
+    let e = error("asp 1 0 0\n4 5 a 0\n", &mut backend);
+    assert_eq!(e.msg, "string is shorter than its length");
+    let e = error("asp 1 0 0\n9 0 1 5\n", &mut backend);
+    assert_eq!(e.msg, "theory statements are not supported");
+    let e = error("asp 1 0 0\n1 0 1 1 0 0 7\n", &mut backend);
+    assert_eq!(e.msg, "unexpected `7`");
+    aspif::read_aspif(
+        "asp 1 0 0\n10 a comment\n1 0 1 1 0 0\n4 1 a 1 1\n0\n",
+        &mut backend,
+    )
+    .unwrap();
+}
+#[test]
+fn ground_with_observer_printer() {
+    let mut ctl = control(vec![]).unwrap();
+    ctl.add(
+        "base",
+        &[],
+        "a. { b; c }. d :- b, not c. :- c, d. e :- 2 <= #sum { 1: b; 2: c }. #minimize { 3@1: b }.",
+    )
+    .unwrap();
+    let mut printer = printer::GroundProgramPrinter::new();
+    ctl.ground_with_observer(&[Part::new("base", vec![]).unwrap()], &mut printer)
+        .unwrap();
+    let program = printer.to_string();
+    assert!(program.contains("a.\n"), "{}", program);
+    assert!(program.contains("{ b; c }.\n"), "{}", program);
+    // the order of body literals is up to the grounder
+    let has_line = |a: &str, b: &str| program.lines().any(|line| line == a || line == b);
+    assert!(has_line("d :- b, not c.", "d :- not c, b."), "{}", program);
+    assert!(has_line(":- c, d.", ":- d, c."), "{}", program);
+    assert!(
+        program.contains(" :- 2 <= #sum { 1,0: b; 2,1: c }.\n"),
+        "{}",
+        program
+    );
+    assert!(program.contains("#minimize { 3@1,0: b }.\n"), "{}", program);
+
+    // the observer is only called during the call
+    ctl.add("more", &[], "f :- b.").unwrap();
+    ctl.ground(&[Part::new("more", vec![]).unwrap()]).unwrap();
+    assert!(!printer.to_string().contains("f :- b"));
+    let mut printer = printer::GroundProgramPrinter::new();
+    ctl.add("last", &[], "g :- c.").unwrap();
+    ctl.ground_with_observer(&[Part::new("last", vec![]).unwrap()], &mut printer)
+        .unwrap();
+    assert_eq!(printer.len(), 1);
+    let mut handle = ctl.solve(SolveMode::empty(), &[]).unwrap();
+    assert!(handle.get().unwrap().contains(SolveResult::SATISFIABLE));
+    handle.close().unwrap();
+}
+#[test]
+fn statistics_path() {
+    let mut ctl = control(vec!["0".into()]).unwrap();
+    ctl.add("base", &[], "a :- not b. b :- not a.").unwrap();
+    ctl.ground(&[Part::new("base", vec![]).unwrap()]).unwrap();
+    let mut handle = ctl.solve(SolveMode::empty(), &[]).unwrap();
+    handle.get().unwrap();
+    let ctl = handle.close().unwrap();
+
+    let stats = ctl.statistics().unwrap().snapshot().unwrap();
+    assert!(stats.value("summary.times.total").unwrap() >= 0.0);
+    assert_eq!(stats.value("summary.models.enumerated"), Some(2.0));
+    assert!(matches!(
+        stats.get("summary.times"),
+        Some(StatsValue::Map(_))
+    ));
+    assert_eq!(stats.get(""), Some(&stats));
+    assert_eq!(stats.get("summary.missing"), None);
+    assert_eq!(stats.value("summary.times"), None);
+    assert_eq!(stats.get("summary.times.total.more"), None);
+
+    let array = StatsValue::Array(vec![StatsValue::Value(1.5), StatsValue::Value(f64::NAN)]);
+    assert_eq!(array.value("0"), Some(1.5));
+    assert_eq!(array.get("2"), None);
+    assert_eq!(array.get("x"), None);
+    let mut map = std::collections::BTreeMap::new();
+    map.insert("a\"b".to_string(), array);
+    map.insert("c".to_string(), StatsValue::Value(3.0));
+    assert_eq!(
+        StatsValue::Map(map).to_json(),
+        r#"{"a\"b":[1.5,null],"c":3}"#
+    );
+}
+#[test]
+fn solver_config_round_trip() {
+    use config::*;
+    let config = SolverConfig::new()
+        .models(3)
+        .parallel(2, ParallelMode::Split)
+        .opt_mode(OptMode::OptN)
+        .enum_mode(EnumMode::Record)
+        .opt_strategy(OptStrategy::Usc)
+        .heuristic(Heuristic::Vmtf)
+        .seed(42)
+        .sign_def(SignDef::Neg);
+    let mut ctl = control(vec![]).unwrap();
+    config.apply(&mut ctl).unwrap();
+    assert_eq!(SolverConfig::from_control(&ctl).unwrap(), config);
+
+    // the raw api sees the same values
+    let conf = ctl.configuration().unwrap();
+    let root = conf.root().unwrap();
+    let key = conf.map_at(root, "solve.parallel_mode").unwrap();
+    assert_eq!(conf.value_get(key).unwrap(), "2,split");
+
+    // raw options are applied after the typed ones
+    let config = SolverConfig::new()
+        .models(0)
+        .set("solve.models", "5")
+        .set("solver.restarts", "L,100");
+    config.apply(&mut ctl).unwrap();
+    let conf = ctl.configuration().unwrap();
+    let key = conf.map_at(root, "solver.restarts").unwrap();
+    assert_eq!(conf.value_get(key).unwrap(), "l,100");
+    let read = SolverConfig::from_control(&ctl).unwrap();
+    assert_eq!(read, read.clone().models(5));
+    assert_eq!(
+        config.entries()[0],
+        ("solve.models".to_string(), "0".to_string())
+    );
+}
+#[test]
+fn solver_config_models() {
+    let mut ctl = control(vec![]).unwrap();
+    config::SolverConfig::new()
+        .models(0)
+        .apply(&mut ctl)
+        .unwrap();
+    ctl.add("base", &[], "{ a; b }.").unwrap();
+    ctl.ground(&[Part::new("base", vec![]).unwrap()]).unwrap();
+    let mut handle = ctl.solve_mut(SolveMode::YIELD, &[]).unwrap();
+    assert_eq!(count_models(&mut handle), 4);
+}
+#[test]
+fn solver_config_errors() {
+    use config::*;
+    let mut ctl = control(vec![]).unwrap();
+    let error = |config: SolverConfig, ctl: &mut Control| match config.apply(ctl) {
+        Err(ClingoError::ConfigError(error)) => error,
+        result => panic!("unexpected result {:?}", result),
+    };
+    let e = error(SolverConfig::new().set("solve.nothing", "1"), &mut ctl);
+    assert_eq!(
+        (e.key.as_str(), e.msg.as_str()),
+        ("solve.nothing", "unknown option")
+    );
+    let e = error(SolverConfig::new().set("solve", "1"), &mut ctl);
+    assert_eq!(e.msg, "not a value");
+    let e = error(SolverConfig::new().set("solver.heuristic", "foo"), &mut ctl);
+    assert!(e
+        .msg
+        .starts_with("invalid value `foo`, expected: Configure decision heuristic"));
+    assert!(e.msg.contains("{Berkmin|Vmtf|Vsids|Domain|Unit|None}"));
+    // failing options leave the configuration unchanged
+    let config = SolverConfig::from_control(&ctl).unwrap();
+    assert_eq!(
+        config,
+        config
+            .clone()
+            .heuristic(Heuristic::Vsids)
+            .opt_mode(OptMode::Opt)
+    );
+}
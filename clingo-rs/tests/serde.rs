@@ -0,0 +1,126 @@
+#[cfg(feature = "serde")]
+pub mod serde {
+    use clingo::serialize::TaggedSymbol;
+    use clingo::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    fn symbol(term: &str) -> Symbol {
+        parse_term(term).unwrap()
+    }
+
+    #[test]
+    fn symbol_string_form() {
+        let sym = symbol("p(1,\"a\",-q,(x,#sup))");
+        let value = serde_json::to_value(sym).unwrap();
+        assert_eq!(value, json!("p(1,\"a\",-q,(x,#sup))"));
+        let back: Symbol = serde_json::from_value(value).unwrap();
+        assert_eq!(back, sym);
+
+        assert!(serde_json::from_value::<Symbol>(json!("p(")).is_err());
+        assert!(serde_json::from_value::<Symbol>(json!(5)).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Answer {
+        #[serde(with = "clingo::serialize::tagged")]
+        atom: Symbol,
+    }
+
+    #[test]
+    fn symbol_tagged_form() {
+        let answer = Answer {
+            atom: symbol("-p(1,\"a\",c,#inf,#sup)"),
+        };
+        let value = serde_json::to_value(&answer).unwrap();
+        assert_eq!(
+            value,
+            json!({"atom": {
+                "type": "function",
+                "name": "p",
+                "arguments": [
+                    {"type": "number", "value": 1},
+                    {"type": "string", "value": "a"},
+                    {"type": "function", "name": "c", "arguments": [], "positive": true},
+                    {"type": "inf"},
+                    {"type": "sup"},
+                ],
+                "positive": false,
+            }})
+        );
+        let back: Answer = serde_json::from_value(value).unwrap();
+        assert_eq!(back, answer);
+
+        // arguments and sign may be omitted
+        let tagged: TaggedSymbol =
+            serde_json::from_value(json!({"type": "function", "name": "c"})).unwrap();
+        assert_eq!(Symbol::try_from(&tagged).unwrap(), symbol("c"));
+        assert!(serde_json::from_value::<TaggedSymbol>(json!({"type": "float"})).is_err());
+    }
+
+    #[test]
+    fn fact_base() {
+        let mut facts = FactBase::new();
+        facts.insert(&symbol("q(2)"));
+        facts.insert(&symbol("p(1)"));
+        facts.insert(&symbol("q(1)"));
+        let value = serde_json::to_value(&facts).unwrap();
+        assert_eq!(value, json!(["p(1)", "q(1)", "q(2)"]));
+        let back: FactBase = serde_json::from_value(value).unwrap();
+        assert_eq!(back, facts);
+    }
+
+    #[test]
+    fn models_and_statistics() {
+        let mut ctl = control(vec!["0".to_string()]).unwrap();
+        ctl.add("base", &[], "a. #minimize{1: a}.").unwrap();
+        let part = Part::new("base", vec![]).unwrap();
+        ctl.ground(&[part]).unwrap();
+
+        let models: Vec<MModel> = ctl.all_models().unwrap().collect();
+        let value = serde_json::to_value(&models).unwrap();
+        assert_eq!(
+            value,
+            json!([{"symbols": ["a"], "cost": [1], "model_type": "StableModel", "number": 1}])
+        );
+        let back: Vec<MModel> = serde_json::from_value(value).unwrap();
+        assert_eq!(back[0].symbols, models[0].symbols);
+
+        let result = SolveResult::SATISFIABLE | SolveResult::EXHAUSTED;
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(value, json!("SATISFIABLE | EXHAUSTED"));
+        let back: SolveResult = serde_json::from_value(value).unwrap();
+        assert_eq!(back.bits(), result.bits());
+    }
+
+    #[test]
+    fn statistics_snapshot() {
+        let mut ctl = control(vec![]).unwrap();
+        ctl.add("base", &[], "a :- not b. b :- not a.").unwrap();
+        let part = Part::new("base", vec![]).unwrap();
+        ctl.ground(&[part]).unwrap();
+        let handle = ctl.solve(SolveMode::YIELD, &[]).unwrap();
+        let ctl = handle.close().unwrap();
+
+        let snapshot = ctl.statistics().unwrap().snapshot().unwrap();
+        let value = serde_json::to_value(&snapshot).unwrap();
+        assert!(value["summary"]["times"]["total"].is_number());
+        assert!(value["solving"]["solvers"]["choices"].is_number());
+        let back: StatsValue = serde_json::from_value(value).unwrap();
+        assert_eq!(back, snapshot);
+
+        // the hand-written conversion agrees with serde
+        let parsed: StatsValue = serde_json::from_str(&snapshot.to_json()).unwrap();
+        assert_eq!(parsed, snapshot);
+
+        // times like these are read back exactly
+        let times = StatsValue::Array(
+            [0.000111, 0.1 + 0.2, 1e-300, 1.7976931348623157e308, 3.0]
+                .into_iter()
+                .map(StatsValue::Value)
+                .collect(),
+        );
+        let parsed: StatsValue = serde_json::from_str(&times.to_json()).unwrap();
+        assert_eq!(parsed, times);
+    }
+}
@@ -1420,6 +1420,14 @@ fn bindgen_test_layout_clingo_propagator() {
 }
 #[doc = "! An instance of this struct has to be registered with a solver to implement a custom propagator.\n!\n! Not all callbacks have to be implemented and can be set to NULL if not needed.\n! @see Propagator"]
 pub type clingo_propagator_t = clingo_propagator;
+#[doc = "!< Comments."]
+pub const clingo_comment_type_e_clingo_comment_type_line: clingo_comment_type_e = 0;
+#[doc = "!< Block comments."]
+pub const clingo_comment_type_e_clingo_comment_type_block: clingo_comment_type_e = 1;
+#[doc = "! Enumeration of comment types."]
+pub type clingo_comment_type_e = ::std::os::raw::c_uint;
+#[doc = "! Corresponding type to ::clingo_theory_sequence_type_e."]
+pub type clingo_comment_type_t = ::std::os::raw::c_int;
 #[doc = "!< Theory tuples \"(t1,...,tn)\"."]
 pub const clingo_theory_sequence_type_e_clingo_theory_sequence_type_tuple:
     clingo_theory_sequence_type_e = 0;
@@ -2910,6 +2918,7 @@ pub const clingo_ast_type_e_clingo_ast_type_project_atom: clingo_ast_type_e = 41
 pub const clingo_ast_type_e_clingo_ast_type_project_signature: clingo_ast_type_e = 42;
 pub const clingo_ast_type_e_clingo_ast_type_defined: clingo_ast_type_e = 43;
 pub const clingo_ast_type_e_clingo_ast_type_theory_definition: clingo_ast_type_e = 44;
+pub const clingo_ast_type_e_clingo_ast_type_comment: clingo_ast_type_e = 45;
 #[doc = "! Enumeration of AST types."]
 pub type clingo_ast_type_e = ::std::os::raw::c_uint;
 #[doc = "! Corresponding type to ::clingo_ast_type_e."]
@@ -2986,6 +2995,7 @@ pub const clingo_ast_attribute_e_clingo_ast_attribute_terms: clingo_ast_attribut
 pub const clingo_ast_attribute_e_clingo_ast_attribute_value: clingo_ast_attribute_e = 41;
 pub const clingo_ast_attribute_e_clingo_ast_attribute_variable: clingo_ast_attribute_e = 42;
 pub const clingo_ast_attribute_e_clingo_ast_attribute_weight: clingo_ast_attribute_e = 43;
+pub const clingo_ast_attribute_e_clingo_ast_attribute_comment_type: clingo_ast_attribute_e = 44;
 #[doc = "! Enumeration of attributes used by the AST."]
 pub type clingo_ast_attribute_e = ::std::os::raw::c_uint;
 #[doc = "! Corresponding type to ::clingo_ast_attribute_e."]
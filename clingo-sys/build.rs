@@ -4,7 +4,7 @@ fn main() {
     // update clingo submodule
     // git submodule update --init --recursive
 
-    // // create bindings
+    // // create bindings, see generate_bindings.sh for the pinned versions
     // let bindings = bindgen::Builder::default()
     //     .header("clingo/libclingo/clingo.h")
     //     .no_copy("clingo_solve_control")
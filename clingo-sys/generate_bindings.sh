@@ -0,0 +1,38 @@
+#!/bin/sh
+# Regenerate bindings.rs from the header of the clingo submodule.
+#
+# The versions are pinned, the bindings have to be generated by this bindgen
+# version from this clingo version and must not be edited by hand.
+set -e
+
+BINDGEN_VERSION="0.68.1"
+CLINGO_VERSION="5.8.0"
+
+cd "$(dirname "$0")"
+
+if ! bindgen --version | grep -q "^bindgen $BINDGEN_VERSION$"; then
+    echo "bindgen $BINDGEN_VERSION is required: cargo install bindgen-cli --version $BINDGEN_VERSION" >&2
+    exit 1
+fi
+if ! grep -q "#define CLINGO_VERSION \"$CLINGO_VERSION\"" clingo/libclingo/clingo.h; then
+    echo "the clingo submodule has to be at version $CLINGO_VERSION" >&2
+    exit 1
+fi
+
+bindgen clingo/libclingo/clingo.h \
+    --no-copy clingo_solve_control \
+    --no-copy clingo_model \
+    --no-copy clingo_solve_handle \
+    --no-copy clingo_program_builder \
+    --no-copy clingo_control \
+    --no-copy clingo_options \
+    --no-copy clingo_symbolic_atoms \
+    --no-copy clingo_theory_atoms \
+    --no-copy clingo_assignment \
+    --no-copy clingo_propagate_init \
+    --no-copy clingo_propagate_control \
+    --no-copy clingo_backend \
+    --no-copy clingo_configuration \
+    --no-copy clingo_statistic \
+    --blocklist-type max_align_t \
+    --output bindings.rs
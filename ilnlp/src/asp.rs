@@ -1,17 +1,62 @@
 
-use clingo::{Part, ShowType, SolveMode};
+use clingo::{Part, ShowType, SolveMode, Symbol, cores::CoreMinimizer};
 
-use crate::{Lit, Literal, Rule, LitSet, error::IlnlpError};
+use std::sync::Mutex;
 
-/// compute models for a given set of rules and facts 
+use crate::{Lit, Literal, LitSet, Signature, Statement, error::IlnlpError, stat::Stat};
+
+/// compute models for a given set of rules and facts, at most `limit` ones
 
 pub fn compute_models(
-    rules: &[&Rule],
+    rules: &[&Statement],
     facts: &LitSet,
     builder: & crate::LitBuilder,
     limit: usize,
+    stat: &Mutex<Stat>,
 ) -> Result<Vec<LitSet>, IlnlpError> {
-    let mut ctl = clingo::control(vec![])?;
+    // clingo stops after the first model by default, `0` enumerates all of them
+    let models = if limit == usize::MAX { 0 } else { limit };
+    let arguments = vec![format!("--models={}", models)];
+    solve(arguments, rules, facts, builder, limit, stat)
+}
+
+/// Compute `count` random models of a given set of rules and facts, differing in the shown atoms.
+///
+/// Unlike sampling from all models, the search stops after `count` ones. Decisions and signs are
+/// random, and the search restarts after each model, recording the found ones to avoid them.
+pub fn sample_models(
+    rules: &[&Statement],
+    facts: &LitSet,
+    builder: &crate::LitBuilder,
+    count: usize,
+    seed: u32,
+    stat: &Mutex<Stat>,
+) -> Result<Vec<LitSet>, IlnlpError> {
+    if count == 0 {
+        return Err(IlnlpError::NoModel);
+    }
+    let arguments = vec![
+        format!("--models={}", count),
+        format!("--seed={}", seed),
+        "--rand-freq=1".to_string(),
+        "--sign-def=rnd".to_string(),
+        "--project=show".to_string(),
+        "--enum-mode=record".to_string(),
+        "--restart-on-model".to_string(),
+    ];
+    solve(arguments, rules, facts, builder, count, stat)
+}
+
+/// the models of rules and facts with clingo called by `arguments`, at most `limit` ones
+fn solve(
+    arguments: Vec<String>,
+    rules: &[&Statement],
+    facts: &LitSet,
+    builder: &crate::LitBuilder,
+    limit: usize,
+    stat: &Mutex<Stat>,
+) -> Result<Vec<LitSet>, IlnlpError> {
+    let mut ctl = clingo::control(arguments)?;
     let mut program = String::new();
     let mut models = Vec::new();
     rules.iter().for_each(|rule| {
@@ -58,7 +103,8 @@ pub fn compute_models(
     }
 
     // close the solve handle
-    handle.close()?;
+    let ctl = handle.close()?;
+    stat.lock().unwrap().record_clingo_stats(ctl.statistics()?.snapshot()?);
     if models.is_empty() {
         Err(IlnlpError::NoModel)
     } else {
@@ -67,7 +113,7 @@ pub fn compute_models(
 }
 
 pub fn ground_literals(
-    rules: &[Rule],
+    rules: &[&Statement],
     facts1: &LitSet,
     facts2: &LitSet,
     builder: & crate::LitBuilder,
@@ -114,3 +160,68 @@ pub fn ground_literals(
     }
     Ok(())
 }
+
+fn symbol_to_literal(s: &Symbol) -> Result<Literal, IlnlpError> {
+    let name = s.name()?;
+    let d: Vec<String> = s.arguments()?.iter().map(|x| x.to_string()).collect();
+    Ok(Literal::new(name.to_string(), d))
+}
+
+/// check whether `output` can be an answer set of the rules and facts extended by further rules,
+/// i.e., whether it is a model of the rules and facts.
+/// If not, returns a minimal set of literals (with their truth value in `output`)
+/// that no answer set can agree with.
+/// The facts are given, `output` only fixes the other atoms, and only the atoms of the shown
+/// predicates if `shown` is not empty.
+pub fn explain_uncoverable(
+    rules: &[&Statement],
+    facts: &LitSet,
+    output: &LitSet,
+    shown: &[Signature],
+    builder: &crate::LitBuilder,
+    stat: &Mutex<Stat>,
+) -> Result<Option<Vec<(Literal, bool)>>, IlnlpError> {
+    let mut ctl = clingo::control(vec![])?;
+    let mut program = String::new();
+    rules.iter().for_each(|rule| {
+        program.push_str(&rule.to_string());
+        program.push('\n');
+    });
+    for lit in facts.iter() {
+        let l = builder.get_literal(*lit)?;
+        program.push_str(&format!("{}.\n", l));
+    }
+    // every atom of the output may be chosen, all other atoms have to stay false
+    for lit in output.iter() {
+        let l = builder.get_literal(*lit)?;
+        program.push_str(&format!("{{ {} }}.\n", l));
+    }
+    ctl.add("base", &[], &program)?;
+    let part = Part::new("base", vec![])?;
+    ctl.ground(&[part])?;
+
+    let mut assumptions = Vec::new();
+    for atom in ctl.symbolic_atoms()?.iter()? {
+        let s = atom.symbol()?;
+        let literal = symbol_to_literal(&s)?;
+        if !shown.is_empty() && !shown.iter().any(|signature| signature.matches(&literal)) {
+            continue;
+        }
+        let lit = builder.create_literal(literal);
+        if facts.contains(&lit) {
+            continue;
+        }
+        assumptions.push((s, output.contains(&lit)));
+    }
+    let mut minimizer = CoreMinimizer::new(&mut ctl)?;
+    let core = minimizer.quick_xplain(&assumptions)?;
+    stat.lock().unwrap().record_clingo_stats(ctl.statistics()?.snapshot()?);
+    match core {
+        Some(core) => Ok(Some(
+            core.iter()
+                .map(|(s, value)| Ok((symbol_to_literal(s)?, *value)))
+                .collect::<Result<_, IlnlpError>>()?,
+        )),
+        None => Ok(None),
+    }
+}
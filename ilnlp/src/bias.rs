@@ -0,0 +1,206 @@
+//! limits of the hypothesis space passed to ILASP
+//!
+//! A [`BiasConfig`] is read from directives in the task file, e.g., `#maxv(3).`,
+//! and from command line flags, which take precedence.
+
+use crate::{Signature, error::IlnlpError};
+
+/// ILASP versions supporting meta-level `#bias` constraints
+const BIAS_VERSIONS: [&str; 2] = ["3", "4"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct BiasConfig {
+    /// maximum number of variables in a rule, `#maxv(n).`
+    pub max_vars: Option<usize>,
+    /// maximum number of body literals in a rule, `#max_body(n).`
+    pub max_body: Option<usize>,
+    /// maximum penalty of a hypothesis, `#max_penalty(n).`
+    pub max_penalty: Option<usize>,
+    /// maximum number of rules in a hypothesis, `#max_rules(n).`
+    ///
+    /// ILASP only limits the penalty, so only `0`, i.e., the empty hypothesis, is supported.
+    pub max_rules: Option<usize>,
+    /// whether a rule may use its head predicate in the body, `#no_recursion.` disables it
+    pub recursion: Option<bool>,
+}
+
+impl BiasConfig {
+    /// set the limits set in `other`, keeping the others
+    pub fn merge(&mut self, other: &BiasConfig) {
+        self.max_vars = other.max_vars.or(self.max_vars);
+        self.max_body = other.max_body.or(self.max_body);
+        self.max_penalty = other.max_penalty.or(self.max_penalty);
+        self.max_rules = other.max_rules.or(self.max_rules);
+        self.recursion = other.recursion.or(self.recursion);
+    }
+
+    /// whether no hypothesis but the empty one is allowed
+    pub fn forbids_rules(&self) -> bool {
+        self.max_rules == Some(0) || self.max_penalty == Some(0)
+    }
+
+    /// Check that ILASP `version`, e.g., `4` or `2i`, can enforce the limits.
+    pub fn validate(&self, version: Option<&str>) -> Result<(), IlnlpError> {
+        if let Some(rules) = self.max_rules
+            && rules > 0
+        {
+            return Err(IlnlpError::InvalidBias(format!(
+                "max_rules {} cannot be enforced, ILASP only limits the penalty of the hypothesis (use max_penalty, or max_rules 0 for the empty hypothesis)",
+                rules
+            )));
+        }
+        if let Some(version) = version
+            && self.recursion == Some(false)
+            && !BIAS_VERSIONS.contains(&version)
+        {
+            return Err(IlnlpError::InvalidBias(format!(
+                "disabling recursion needs #bias constraints, which ILASP version {} does not support (use --version={})",
+                version,
+                BIAS_VERSIONS.join(" or --version=")
+            )));
+        }
+        Ok(())
+    }
+
+    /// the ILASP directives of the limits, `heads` are the predicates that may occur in heads
+    pub fn directives(&self, heads: &[Signature]) -> Vec<String> {
+        let mut directives = vec![];
+        if let Some(n) = self.max_vars {
+            directives.push(format!("#maxv({}).", n));
+        }
+        if let Some(n) = self.max_body {
+            directives.push(format!("#max_body({}).", n));
+        }
+        // only the empty hypothesis has no penalty
+        let max_penalty = if self.max_rules == Some(0) {
+            Some(0)
+        } else {
+            self.max_penalty
+        };
+        if let Some(n) = max_penalty {
+            directives.push(format!("#max_penalty({}).", n));
+        }
+        if self.recursion == Some(false) {
+            for head in heads {
+                let atom = if head.arity == 0 {
+                    head.name.clone()
+                } else {
+                    format!("{}({})", head.name, vec!["_"; head.arity].join(","))
+                };
+                directives.push(format!("#bias(\":- in_head({0}), in_body({0}).\").", atom));
+            }
+        }
+        directives
+    }
+}
+
+/// the directives of the task file setting the limits, one per line
+impl std::fmt::Display for BiasConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let limits = [
+            ("#maxv", self.max_vars),
+            ("#max_body", self.max_body),
+            ("#max_penalty", self.max_penalty),
+            ("#max_rules", self.max_rules),
+        ];
+        for (directive, n) in limits {
+            if let Some(n) = n {
+                writeln!(f, "{}({}).", directive, n)?;
+            }
+        }
+        if self.recursion == Some(false) {
+            writeln!(f, "#no_recursion.")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_directives() {
+        let bias = BiasConfig {
+            max_vars: Some(3),
+            max_body: Some(2),
+            max_penalty: Some(10),
+            max_rules: None,
+            recursion: Some(false),
+        };
+        let heads = [Signature::new("p".into(), 2), Signature::new("q".into(), 0)];
+        assert_eq!(
+            bias.directives(&heads),
+            vec![
+                "#maxv(3).",
+                "#max_body(2).",
+                "#max_penalty(10).",
+                "#bias(\":- in_head(p(_,_)), in_body(p(_,_)).\").",
+                "#bias(\":- in_head(q), in_body(q).\").",
+            ]
+        );
+        assert!(BiasConfig::default().directives(&heads).is_empty());
+    }
+
+    #[test]
+    fn renders_no_rules_as_zero_penalty() {
+        let bias = BiasConfig {
+            max_penalty: Some(10),
+            max_rules: Some(0),
+            ..Default::default()
+        };
+        assert!(bias.forbids_rules());
+        assert_eq!(bias.directives(&[]), vec!["#max_penalty(0)."]);
+        assert!(bias.validate(Some("4")).is_ok());
+    }
+
+    #[test]
+    fn validates_limits() {
+        let rules = BiasConfig {
+            max_penalty: Some(1),
+            max_rules: Some(2),
+            ..Default::default()
+        };
+        assert!(matches!(
+            rules.validate(None),
+            Err(IlnlpError::InvalidBias(_))
+        ));
+        let penalty = BiasConfig {
+            max_penalty: Some(1),
+            ..Default::default()
+        };
+        assert!(penalty.validate(None).is_ok());
+        let recursion = BiasConfig {
+            recursion: Some(false),
+            ..Default::default()
+        };
+        assert!(recursion.validate(Some("4")).is_ok());
+        assert!(recursion.validate(None).is_ok());
+        assert!(matches!(
+            recursion.validate(Some("2i")),
+            Err(IlnlpError::InvalidBias(_))
+        ));
+    }
+
+    #[test]
+    fn merges_flags_over_directives() {
+        let mut bias = BiasConfig {
+            max_vars: Some(3),
+            max_body: Some(2),
+            ..Default::default()
+        };
+        bias.merge(&BiasConfig {
+            max_vars: Some(4),
+            recursion: Some(false),
+            ..Default::default()
+        });
+        assert_eq!(
+            (bias.max_vars, bias.max_body, bias.recursion),
+            (Some(4), Some(2), Some(false))
+        );
+        assert_eq!(
+            bias.to_string(),
+            "#maxv(4).\n#max_body(2).\n#no_recursion.\n"
+        );
+    }
+}
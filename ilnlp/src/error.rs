@@ -1,9 +1,8 @@
-use std::{cell::RefCell, rc::Rc};
+use std::fmt;
 
 use clingo::ClingoError;
-use nom_locate::LocatedSpan;
 
-use crate::{Lit, Task};
+use crate::Lit;
 
 #[derive(thiserror::Error, Debug)]
 pub enum IlnlpError {
@@ -15,28 +14,148 @@ pub enum IlnlpError {
     IncompatibleThree,
     #[error("Clingo error: {0}")]
     Clingo(#[from] ClingoError),
+    #[error("Uncoverable example: {0}")]
+    Uncoverable(String),
     #[error("No model found")]
     NoModel,
     #[error("Invalid literal: {0}")]
     InvalidLit(Lit),
-    #[error("{0}")]
-    ParserError(String),
+    #[error("Invalid bias: {0}")]
+    InvalidBias(String),
+    #[error("{}", Diagnostics(.0))]
+    Parse(Vec<Diagnostic>),
     #[error("{0}")]
     TeraError(#[from]tera::Error),
 }
 
-impl From<nom::Err<nom::error::Error<LocatedSpan<&str, Rc<RefCell<Task>>>>>> for IlnlpError {
-    fn from(value: nom::Err<nom::error::Error<LocatedSpan<&str, Rc<RefCell<Task>>>>>) -> Self {
-        match value {
-            nom::Err::Error(e) | nom::Err::Failure(e) => IlnlpError::ParserError(format!(
-                "Parse error at line {}, column {}: {:?}",
-                e.input.location_line(),
-                e.input.get_utf8_column(),
-                e.code
-            )),
-            nom::Err::Incomplete(_) => {
-                IlnlpError::ParserError("Unexpected end of input".to_string())
-            }
+/// An error at a position of the input, printed with the source line and a caret underline
+///
+/// ```text
+/// error: expected `O:` after input facts
+///  --> line 3, column 13
+///   |
+/// 3 | I: q(1) q(2) {p(1)}
+///   |              ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1-based line
+    pub line: u32,
+    /// 1-based column in characters
+    pub column: usize,
+    /// number of underlined characters
+    pub length: usize,
+    pub message: String,
+    /// the source line without line break
+    pub source_line: String,
+}
+
+impl Diagnostic {
+    /// create a diagnostic at `line` and `column` of `source`
+    pub fn new(
+        source: &str,
+        line: u32,
+        column: usize,
+        length: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let source_line = source
+            .lines()
+            .nth((line as usize).saturating_sub(1))
+            .unwrap_or_default()
+            .to_string();
+        Diagnostic {
+            line,
+            column: column.max(1),
+            length: length.max(1),
+            message: message.into(),
+            source_line,
+        }
+    }
+
+    /// the diagnostic printed as a warning, e.g., about a part of the input that is left out
+    pub fn warning(&self) -> impl fmt::Display + '_ {
+        Warning(self)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, severity: &str) -> fmt::Result {
+        let line = self.line.to_string();
+        let gutter = " ".repeat(line.len());
+        // keep tabs so that the caret lines up with the source line
+        let indent: String = self
+            .source_line
+            .chars()
+            .chain(std::iter::repeat(' '))
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        writeln!(f, "{severity}: {}", self.message)?;
+        writeln!(f, "{gutter}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {}", self.source_line)?;
+        write!(f, "{gutter} | {indent}{}", "^".repeat(self.length))
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, "error")
+    }
+}
+
+struct Warning<'a>(&'a Diagnostic);
+
+impl fmt::Display for Warning<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write(f, "warning")
+    }
+}
+
+struct Diagnostics<'a>(&'a [Diagnostic]);
+
+impl fmt::Display for Diagnostics<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diagnostic in self.0 {
+            writeln!(f, "{diagnostic}\n")?;
+        }
+        match self.0.len() {
+            1 => write!(f, "could not parse the task due to the previous error"),
+            n => write!(f, "could not parse the task due to {n} previous errors"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn underlines_the_token() {
+        let diagnostic = Diagnostic::new("a.\n\tI: q(1 O: {p}", 2, 8, 2, "expected `,` or `)`");
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: expected `,` or `)`\n --> line 2, column 8\n  |\n2 | \tI: q(1 O: {p}\n  | \t      ^^"
+        );
+    }
+
+    #[test]
+    fn prints_warnings() {
+        let diagnostic = Diagnostic::new("#neg(@3, {}, {}).", 1, 6, 2, "left out");
+        assert_eq!(
+            diagnostic.warning().to_string(),
+            "warning: left out\n --> line 1, column 6\n  |\n1 | #neg(@3, {}, {}).\n  |      ^^"
+        );
+    }
+
+    #[test]
+    fn counts_the_errors() {
+        let diagnostic = Diagnostic::new("a", 1, 0, 0, "missing `.`");
+        assert_eq!((diagnostic.column, diagnostic.length), (1, 1));
+        let error = IlnlpError::Parse(vec![diagnostic.clone(), diagnostic]);
+        assert!(
+            error
+                .to_string()
+                .ends_with("could not parse the task due to 2 previous errors")
+        );
+    }
+}
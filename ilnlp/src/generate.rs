@@ -0,0 +1,248 @@
+//! generation of I/O examples from a reference program
+//!
+//! The inputs are the instances `I: ...` of a file, or the answer sets of a generator program,
+//! e.g., one choosing the edges of a graph by `{ e(X, Y) : v(X), v(Y) }.`. The outputs of an input
+//! are the answer sets of the reference program extended by the input.
+
+use std::sync::Mutex;
+
+use crate::{Example, LitSet, Statement, Task, asp, error::IlnlpError, parser, stat::Stat};
+
+#[derive(Debug, Clone, Default)]
+pub struct GenConfig {
+    /// number of inputs sampled from the instances or the answer sets of the generator, all if unset
+    pub count: Option<usize>,
+    /// maximum number of answer sets sampled per input, sampled outputs are brave (`O?:`)
+    pub max_outputs: Option<usize>,
+    /// seed of the sampling, random if unset
+    pub seed: Option<u64>,
+}
+
+/// The answer sets of `rules` extended by `facts`, none if there are no answer sets.
+///
+/// A `sample` of a count and a seed computes only that many random answer sets.
+fn answer_sets(
+    rules: &[&Statement],
+    facts: &LitSet,
+    task: &Task,
+    sample: Option<(usize, u32)>,
+    stat: &Mutex<Stat>,
+) -> Result<Vec<LitSet>, IlnlpError> {
+    let models = match sample {
+        Some((count, seed)) => {
+            asp::sample_models(rules, facts, &task.lit_builder, count, seed, stat)
+        }
+        None => asp::compute_models(rules, facts, &task.lit_builder, usize::MAX, stat),
+    };
+    let models = match models {
+        Ok(models) => models,
+        Err(IlnlpError::NoModel) => vec![],
+        Err(e) => return Err(e),
+    };
+    // models may only differ in hidden atoms
+    let mut answer_sets: Vec<LitSet> = vec![];
+    for model in models {
+        if !answer_sets.contains(&model) {
+            answer_sets.push(model);
+        }
+    }
+    Ok(answer_sets)
+}
+
+/// `amount` random items, keeping their order
+fn sample<T>(rng: &mut fastrand::Rng, items: Vec<T>, amount: usize) -> Vec<T> {
+    let mut sampled = rng.choose_multiple(0..items.len(), amount);
+    sampled.sort();
+    let mut sampled = sampled.into_iter().peekable();
+    let mut result = Vec::with_capacity(amount.min(items.len()));
+    for (i, item) in items.into_iter().enumerate() {
+        if sampled.next_if_eq(&i).is_some() {
+            result.push(item);
+        }
+    }
+    result
+}
+
+/// Generate the examples of the reference `program` for the `inputs`.
+///
+/// `inputs` is a file of instances if it has lines starting with `I:`, its background is kept in
+/// the task. Otherwise it is a generator program whose shown atoms form the inputs.
+pub fn generate(
+    program: &[Statement],
+    inputs: &str,
+    config: &GenConfig,
+    stat: &Mutex<Stat>,
+) -> Result<Task, IlnlpError> {
+    let mut rng = match config.seed {
+        Some(seed) => fastrand::Rng::with_seed(seed),
+        None => fastrand::Rng::new(),
+    };
+    let mut task = if parser::has_examples(inputs) {
+        let mut task = parser::parse_instances(inputs)?;
+        if let Some(count) = config.count {
+            let examples = std::mem::take(&mut task.examples);
+            task.examples = sample(&mut rng, examples, count);
+        }
+        task
+    } else {
+        let generator = parser::parse_program(inputs)?;
+        let generator: Vec<&Statement> = generator.iter().collect();
+        let mut task = Task::default();
+        // stop after `count` random answer sets instead of enumerating all of them
+        let sample = config.count.map(|count| (count, rng.u32(..)));
+        for input in answer_sets(&generator, &LitSet::default(), &task, sample, stat)? {
+            task.push_example(Example {
+                input,
+                context: vec![],
+                output: vec![],
+                partial_outputs: vec![],
+                partial: false,
+            });
+        }
+        task
+    };
+    let mut examples = std::mem::take(&mut task.examples);
+    for example in &mut examples {
+        let rules: Vec<&Statement> = program
+            .iter()
+            .chain(example.program(&task.background))
+            .collect();
+        let mut output = answer_sets(&rules, &example.input, &task, None, stat)?;
+        if let Some(max) = config.max_outputs
+            && output.len() > max
+        {
+            output = sample(&mut rng, output, max);
+            example.partial = true;
+        }
+        example.output = output;
+    }
+    task.examples = examples;
+    // the outputs are projected by the `#show` of the program, so the task compares them on these
+    for statement in program {
+        if let Statement::Show(signature) = statement {
+            task.push_background(Statement::Show(signature.clone()));
+        }
+    }
+    Ok(task)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use std::sync::Arc;
+
+    const GENERATOR: &str = "v(1..6).\n{ e(X, Y) : v(X), v(Y) }.\n#show e/2.";
+    const REACHABLE: &str = "r(X, Y) :- e(X, Y).\nr(X, Z) :- r(X, Y), e(Y, Z).";
+
+    fn generate(program: &str, inputs: &str, config: GenConfig) -> Task {
+        let program = parser::parse_program(program).unwrap();
+        super::generate(&program, inputs, &config, &Mutex::new(Stat::new())).unwrap()
+    }
+
+    fn inputs(task: &Task) -> Vec<&LitSet> {
+        task.examples.iter().map(|e| &e.input).collect()
+    }
+
+    #[test]
+    fn samples_answer_sets_of_generators() {
+        // 2^36 answer sets, only the sampled ones are computed
+        let config = GenConfig {
+            count: Some(3),
+            seed: Some(1),
+            ..Default::default()
+        };
+        let task = generate(REACHABLE, GENERATOR, config.clone());
+        let sampled = inputs(&task);
+        assert_eq!(sampled.len(), 3);
+        assert!(sampled.iter().all_unique());
+        assert!(
+            task.examples
+                .iter()
+                .all(|e| e.output.len() == 1 && !e.partial)
+        );
+        assert_eq!(
+            generate(REACHABLE, GENERATOR, config).to_string(),
+            task.to_string()
+        );
+    }
+
+    #[test]
+    fn takes_all_answer_sets_of_small_generators() {
+        let generator = "{ a; b }.";
+        let all = generate("c :- a.", generator, GenConfig::default());
+        assert_eq!(all.examples.len(), 4);
+        let config = GenConfig {
+            count: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(generate("c :- a.", generator, config).examples.len(), 4);
+        let config = GenConfig {
+            count: Some(0),
+            ..Default::default()
+        };
+        assert!(generate("c :- a.", generator, config).examples.is_empty());
+    }
+
+    #[test]
+    fn samples_instances_in_their_order() {
+        let instances = "b.\nI: a(1)\nI: a(2)\nI: a(3)\nI: a(4)\n";
+        let config = GenConfig {
+            count: Some(2),
+            seed: Some(7),
+            ..Default::default()
+        };
+        let task = generate("c(X) :- a(X), b.", instances, config);
+        assert_eq!(task.background.len(), 1);
+        let written = task.to_string();
+        let sampled: Vec<_> = written.lines().filter(|l| l.starts_with("I:")).collect();
+        assert_eq!(sampled.len(), 2);
+        assert!(sampled[0] < sampled[1]);
+        assert!(
+            sampled
+                .iter()
+                .all(|l| l.contains("O: {") && l.contains("c("))
+        );
+    }
+
+    #[test]
+    fn samples_outputs_as_partial_outputs() {
+        let program = "{ p; q }.\nr :- s.";
+        let task = generate(program, "I: s\nI: t\n", GenConfig::default());
+        assert_eq!(task.examples[0].output.len(), 4);
+        assert!(!task.examples[0].partial);
+        let config = GenConfig {
+            max_outputs: Some(2),
+            ..Default::default()
+        };
+        let task = generate(program, "I: s\n", config);
+        assert_eq!(task.examples[0].output.len(), 2);
+        assert!(task.to_string().contains("O?:"));
+        // inputs without answer sets have no outputs
+        let task = generate(":- s.", "I: s\n", GenConfig::default());
+        assert!(task.examples[0].output.is_empty());
+        assert!(!task.examples[0].partial);
+    }
+
+    #[test]
+    fn keeps_the_shown_predicates_of_the_program() {
+        let program = "p(X) :- a(X).\nh(X) :- a(X).\n#show p/1.";
+        let task = generate(program, "I: a(1)\nI: a(2)\n", GenConfig::default());
+        let written = task.to_string();
+        assert!(written.contains("#show p/1."));
+        assert!(written.contains("I: a(1) O: {p(1)}"));
+        let mut reparsed = parser::parse_task(&written).unwrap();
+        assert_eq!(reparsed.to_string(), written);
+        assert_eq!(reparsed.shown(), task.shown());
+        assert!(
+            reparsed
+                .check_compatibility(Arc::new(Mutex::new(Stat::new())))
+                .is_ok()
+        );
+        assert!(
+            reparsed
+                .check_coverable(Arc::new(Mutex::new(Stat::new())))
+                .is_ok()
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use serde::ser::SerializeMap;
 
-use crate::error::IlnlpError;
+use crate::{Signature, bias::BiasConfig, error::IlnlpError};
 
 macro_rules! serialize_seq_field {
     ($map:expr, $field:expr, $key:expr) => {{
@@ -11,11 +11,69 @@ macro_rules! serialize_seq_field {
     }};
 }
 
+/// a mode declaration `#modeh(recall, atom, options).` or `#modeb(...)`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ModeDeclaration {
+    pub head: bool,
+    pub recall: Option<usize>,
+    /// the atom with placeholders like `var(node)` or `const(color)`, e.g., `e(var(node), var(node))`
+    pub atom: String,
+    /// options like `(positive)`
+    pub options: Option<String>,
+}
+
+impl ModeDeclaration {
+    /// the signature of the atom, if it is no comparison
+    pub fn signature(&self) -> Option<Signature> {
+        let atom = self.atom.trim();
+        let (name, args) = match atom.split_once('(') {
+            Some((name, args)) => (name.trim(), args.strip_suffix(')')?),
+            None => (atom, ""),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        let mut depth = 0;
+        let mut arity = usize::from(!args.trim().is_empty());
+        for c in args.chars() {
+            match c {
+                '(' => depth += 1,
+                // the parenthesis of the atom is closed before the end, e.g., `var(t) < var(t)`
+                ')' if depth == 0 => return None,
+                ')' => depth -= 1,
+                ',' if depth == 0 => arity += 1,
+                _ => {}
+            }
+        }
+        Some(Signature::new(name.to_string(), arity))
+    }
+}
+
+impl std::fmt::Display for ModeDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#mode{}(", if self.head { "h" } else { "b" })?;
+        if let Some(recall) = self.recall {
+            write!(f, "{}, ", recall)?;
+        }
+        write!(f, "{}", self.atom)?;
+        if let Some(options) = &self.options {
+            write!(f, ", {}", options)?;
+        }
+        write!(f, ").")
+    }
+}
+
 #[derive(Debug)]
 pub struct ILSearchSpace<T> {
     positive_body: Vec<T>,
     general_body: Vec<T>,
     head: Vec<T>,
+    /// declared in the task file
+    modes: Vec<ModeDeclaration>,
+    /// `#constant(type, value).`
+    constants: Vec<(String, String)>,
+    /// `#bias("...").`
+    biases: Vec<String>,
 }
 
 impl<T> Default for ILSearchSpace<T> {
@@ -24,10 +82,38 @@ impl<T> Default for ILSearchSpace<T> {
             positive_body: Default::default(),
             general_body: Default::default(),
             head: Default::default(),
+            modes: Default::default(),
+            constants: Default::default(),
+            biases: Default::default(),
         }
     }
 }
 
+impl<T> ILSearchSpace<T> {
+    pub fn push_mode(&mut self, mode: ModeDeclaration) {
+        self.modes.push(mode);
+    }
+    pub fn push_constant(&mut self, type_: String, value: String) {
+        self.constants.push((type_, value));
+    }
+    pub fn push_bias(&mut self, bias: String) {
+        self.biases.push(bias);
+    }
+    pub fn modes(&self) -> &[ModeDeclaration] {
+        &self.modes
+    }
+    pub fn constants(&self) -> &[(String, String)] {
+        &self.constants
+    }
+    pub fn biases(&self) -> &[String] {
+        &self.biases
+    }
+    /// whether mode declarations are given, instead of being inferred from the examples
+    pub fn is_declared(&self) -> bool {
+        !self.modes.is_empty()
+    }
+}
+
 impl<T: Ord> ILSearchSpace<T> {
     fn rebuild(&mut self) {
         self.positive_body.sort();
@@ -36,6 +122,9 @@ impl<T: Ord> ILSearchSpace<T> {
         self.general_body.dedup();
         self.head.sort();
         self.head.dedup();
+        self.modes.dedup();
+        self.constants.dedup();
+        self.biases.dedup();
     }
 }
 impl<T: ToString> serde::ser::Serialize for ILSearchSpace<T> {
@@ -43,10 +132,19 @@ impl<T: ToString> serde::ser::Serialize for ILSearchSpace<T> {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(3))?;
+        let mut map = serializer.serialize_map(Some(6))?;
         serialize_seq_field!(map, self.positive_body, "positive_body");
         serialize_seq_field!(map, self.general_body, "general_body");
         serialize_seq_field!(map, self.head, "head");
+        serialize_seq_field!(map, self.modes, "modes");
+        let constants: Vec<String> = self
+            .constants
+            .iter()
+            .map(|(type_, value)| format!("#constant({}, {}).", type_, value))
+            .collect();
+        map.serialize_entry("constants", &constants)?;
+        let biases: Vec<String> = self.biases.iter().map(|b| format!("#bias({}).", b)).collect();
+        map.serialize_entry("biases", &biases)?;
         map.end()
     }
 }
@@ -57,6 +155,8 @@ pub struct ILTaskBuilder<T: Debug, R: Debug> {
     neg_examples: Vec<ILExample<T>>,
     search_space: ILSearchSpace<T>,
     background: Vec<R>,
+    bias: BiasConfig,
+    bias_directives: Vec<String>,
 }
 
 impl<T: Debug, R: Debug> Default for ILTaskBuilder<T, R> {
@@ -66,6 +166,8 @@ impl<T: Debug, R: Debug> Default for ILTaskBuilder<T, R> {
             neg_examples: Default::default(),
             search_space: Default::default(),
             background: Default::default(),
+            bias: Default::default(),
+            bias_directives: Default::default(),
         }
     }
 }
@@ -75,12 +177,21 @@ where
     T: Debug + Ord,
     R: Debug,
 {
-    pub fn push_pos_example(&mut self, incl: Vec<T>, excl: Vec<T>, ctx: Vec<T>) {
-        self.pos_examples.push(ILExample::new(incl, excl, ctx));
+    pub fn push_pos_example(&mut self, incl: Vec<T>, excl: Vec<T>, ctx: Vec<T>, rules: Vec<String>) {
+        self.pos_examples.push(ILExample::new(incl, excl, ctx).with_rules(rules));
     }
 
-    pub fn push_neg_example(&mut self, incl: Vec<T>, excl: Vec<T>, ctx: Vec<T>) {
-        self.neg_examples.push(ILExample::new(incl, excl, ctx));
+    pub fn push_neg_example(&mut self, incl: Vec<T>, excl: Vec<T>, ctx: Vec<T>, rules: Vec<String>) {
+        self.neg_examples.push(ILExample::new(incl, excl, ctx).with_rules(rules));
+    }
+
+    /// add a positive or negative example, e.g., one with an id
+    pub fn push_example(&mut self, positive: bool, example: ILExample<T>) {
+        if positive {
+            self.pos_examples.push(example);
+        } else {
+            self.neg_examples.push(example);
+        }
     }
     pub fn push_background(&mut self, r: R) {
         self.background.push(r);
@@ -94,6 +205,30 @@ where
     pub fn push_head(&mut self, r: T) {
         self.search_space.head.push(r);
     }
+    pub fn heads(&self) -> &[T] {
+        &self.search_space.head
+    }
+    pub fn modes(&self) -> &[ModeDeclaration] {
+        &self.search_space.modes
+    }
+    /// Add the declared modes, constants and biases.
+    /// Unless `merge` is set, declared modes replace the inferred ones.
+    pub fn declare(&mut self, declared: &ILSearchSpace<T>, merge: bool) {
+        if declared.is_declared() && !merge {
+            self.search_space.positive_body.clear();
+            self.search_space.general_body.clear();
+            self.search_space.head.clear();
+        }
+        let search_space = &mut self.search_space;
+        search_space.modes.extend(declared.modes.iter().cloned());
+        search_space.constants.extend(declared.constants.iter().cloned());
+        search_space.biases.extend(declared.biases.iter().cloned());
+    }
+    /// set the limits of the hypothesis space and their rendering as ILASP directives
+    pub fn set_bias(&mut self, bias: BiasConfig, directives: Vec<String>) {
+        self.bias = bias;
+        self.bias_directives = directives;
+    }
 
     pub fn build(mut self) -> ILTask<T, R> {
         self.neg_examples.sort();
@@ -106,15 +241,21 @@ where
             neg_examples: self.neg_examples,
             search_space: self.search_space,
             background: self.background,
+            bias: self.bias,
+            bias_directives: self.bias_directives,
         }
     }
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ILExample<T> {
+    /// the id with an optional penalty, e.g., `p1@10`
+    id: Option<String>,
     incl: Vec<T>,
     excl: Vec<T>,
     ctx: Vec<T>,
+    /// rules of the context program besides the facts in `ctx`
+    rules: Vec<String>,
 }
 
 impl<T: ToString> serde::ser::Serialize for ILExample<T> {
@@ -122,10 +263,18 @@ impl<T: ToString> serde::ser::Serialize for ILExample<T> {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(3))?;
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("id", &self.id)?;
         serialize_seq_field!(map, self.incl, "incl");
         serialize_seq_field!(map, self.excl, "excl");
-        serialize_seq_field!(map, self.ctx, "ctx");
+        // the context program, every fact and rule terminated by a period
+        let ctx: Vec<String> = self
+            .ctx
+            .iter()
+            .map(|x| format!("{}.", x.to_string()))
+            .chain(self.rules.iter().cloned())
+            .collect();
+        map.serialize_entry("ctx", &ctx)?;
 
         map.end()
     }
@@ -133,7 +282,23 @@ impl<T: ToString> serde::ser::Serialize for ILExample<T> {
 
 impl<T> ILExample<T> {
     pub fn new(incl: Vec<T>, excl: Vec<T>, ctx: Vec<T>) -> ILExample<T> {
-        ILExample { incl, excl, ctx }
+        ILExample {
+            id: None,
+            incl,
+            excl,
+            ctx,
+            rules: vec![],
+        }
+    }
+
+    pub fn with_rules(mut self, rules: Vec<String>) -> ILExample<T> {
+        self.rules = rules;
+        self
+    }
+
+    pub fn with_id(mut self, id: String) -> ILExample<T> {
+        self.id = Some(id);
+        self
     }
 }
 
@@ -143,6 +308,8 @@ pub struct ILTask<T, R> {
     neg_examples: Vec<ILExample<T>>,
     search_space: ILSearchSpace<T>,
     background: Vec<R>,
+    bias: BiasConfig,
+    bias_directives: Vec<String>,
 }
 
 impl<T: ToString, R: ToString> serde::ser::Serialize for ILTask<T, R> {
@@ -150,15 +317,23 @@ impl<T: ToString, R: ToString> serde::ser::Serialize for ILTask<T, R> {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(4))?;
+        let mut map = serializer.serialize_map(Some(6))?;
         map.serialize_entry("pos_examples", &self.pos_examples)?;
         map.serialize_entry("neg_examples", &self.neg_examples)?;
         map.serialize_entry("search_space", &self.search_space)?;
         serialize_seq_field!(map, self.background, "background");
+        map.serialize_entry("bias", &self.bias)?;
+        map.serialize_entry("bias_directives", &self.bias_directives)?;
         map.end()
     }
 }
 
+impl<T, R> ILTask<T, R> {
+    pub fn bias(&self) -> &BiasConfig {
+        &self.bias
+    }
+}
+
 impl<T: ToString, R: ToString> ILTask<T, R> {
     pub fn to_progam_with_template(&self, templete: &str) -> Result<String, IlnlpError> {
         let mut tera = tera::Tera::default();
@@ -172,3 +347,47 @@ impl<T: ToString, R: ToString> ILTask<T, R> {
         self.to_progam_with_template(include_str!("../templates/defalut.tpl"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mode(
+        head: bool,
+        recall: Option<usize>,
+        atom: &str,
+        options: Option<&str>,
+    ) -> ModeDeclaration {
+        ModeDeclaration {
+            head,
+            recall,
+            atom: atom.to_string(),
+            options: options.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn signatures_of_modes() {
+        let signature = |atom| {
+            mode(true, None, atom, None)
+                .signature()
+                .map(|s| s.to_string())
+        };
+        assert_eq!(signature("p(var(t), const(c))").as_deref(), Some("p/2"));
+        assert_eq!(signature("p(f(var(t), var(t)))").as_deref(), Some("p/1"));
+        assert_eq!(signature("q").as_deref(), Some("q/0"));
+        assert_eq!(signature("var(t) < var(t)"), None);
+    }
+
+    #[test]
+    fn renders_modes() {
+        assert_eq!(
+            mode(false, Some(2), "e(var(n), var(n))", Some("(positive)")).to_string(),
+            "#modeb(2, e(var(n), var(n)), (positive))."
+        );
+        assert_eq!(
+            mode(true, None, "p(var(n))", None).to_string(),
+            "#modeh(p(var(n)))."
+        );
+    }
+}
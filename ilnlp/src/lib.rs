@@ -1,12 +1,15 @@
 pub mod asp;
+pub mod bias;
 pub mod error;
+pub mod generate;
 pub mod ilasp;
 pub mod parser;
 pub mod set;
 pub mod stat;
 
+use crate::bias::BiasConfig;
 use crate::error::IlnlpError;
-use crate::ilasp::{ILTask, ILTaskBuilder};
+use crate::ilasp::{ILSearchSpace, ILTask, ILTaskBuilder};
 use crate::set::Set;
 use crate::stat::Stat; 
 use itertools::Itertools;
@@ -68,6 +71,29 @@ impl Literal {
     }
 }
 
+/// the signature `name/arity` of a predicate
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Signature {
+    pub name: String,
+    pub arity: usize,
+}
+
+impl Signature {
+    pub fn new(name: String, arity: usize) -> Self {
+        Signature { name, arity }
+    }
+
+    pub fn matches(&self, literal: &Literal) -> bool {
+        self.name == literal.predicate && self.arity == literal.args.len()
+    }
+}
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.name, self.arity)
+    }
+}
+
 impl std::fmt::Display for Literal {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if self.args.is_empty() {
@@ -95,7 +121,7 @@ impl std::fmt::Display for Comparison {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rule {
     pub head: Option<Literal>,
     pub body: Vec<BodyLiteral>,
@@ -122,7 +148,47 @@ impl std::fmt::Display for Rule {
     }
 }
 
-#[derive(Debug, Clone)]
+/// a statement of the background knowledge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+    /// a rule that fits the rule model of ilnlp
+    Rule(Rule),
+    /// a rule using constructs like aggregates, intervals or pools, kept as written
+    Verbatim(String),
+    /// a directive like `#const` or `#show t : b.`, kept as written
+    Directive(String),
+    /// `#show p/n.`, projecting the outputs onto the shown predicates
+    Show(Signature),
+}
+
+impl Statement {
+    /// whether the statement is a rule without negation
+    pub fn is_definite(&self) -> bool {
+        match self {
+            Statement::Rule(rule) => rule.body.iter().all(|literal| {
+                if let BodyLiteral::Literal { negated, .. } = literal {
+                    !negated
+                } else {
+                    true
+                }
+            }),
+            Statement::Verbatim(_) => false,
+            Statement::Directive(_) | Statement::Show(_) => true,
+        }
+    }
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Statement::Rule(rule) => rule.fmt(f),
+            Statement::Verbatim(text) | Statement::Directive(text) => write!(f, "{}", text),
+            Statement::Show(signature) => write!(f, "#show {}.", signature),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BodyLiteral {
     Literal { literal: Literal, negated: bool },
     Comparison(Comparison),
@@ -143,18 +209,42 @@ impl ToString for BodyLiteral {
     }
 }
 
+/// a partial interpretation `{a b | c}` of partial outputs, some answer set contains `a` and `b`
+/// but not `c`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialOutput {
+    pub included: LitSet,
+    pub excluded: LitSet,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Example {
     pub input: LitSet,
+    /// rules of the input besides the facts in `input`
+    pub context: Vec<Statement>,
     pub output: Vec<LitSet>,
+    /// partial interpretations of answer sets, only in partial examples
+    pub partial_outputs: Vec<PartialOutput>,
+    /// whether `output` lists only some of the answer sets (`O?:`)
+    pub partial: bool,
 }
 
 impl Example {
+    /// the rules of the context program, rendered for the ILASP examples
+    fn context_rules(&self) -> Vec<String> {
+        self.context.iter().map(|r| r.to_string()).collect()
+    }
+
+    /// the background extended by the rules of the input
+    fn program<'a>(&'a self, background: &'a [Statement]) -> Vec<&'a Statement> {
+        background.iter().chain(&self.context).collect()
+    }
+
     fn compute_neg_example(
         &self,
         global_literals: &LitSet,
         literals: &LitSet,
-        iltask: &mut ILTaskBuilder<Rc<Literal>, Rule>,
+        iltask: &mut ILTaskBuilder<Rc<Literal>, Statement>,
         builder: &LitBuilder,
     ) -> Result<(), IlnlpError> {
         let input = &self.input;
@@ -165,6 +255,7 @@ impl Example {
                 vec![builder.get_literal(*a)?],
                 vec![],
                 builder.get_literals(input.iter()),
+                self.context_rules(),
             );
         }
 
@@ -203,6 +294,7 @@ impl Example {
                     builder.get_literals(a.union(input).iter()),
                     builder.get_literals(global_literals.difference(&input.union(b)).iter()),
                     builder.get_literals(input.iter()),
+                    self.context_rules(),
                 );
             }
         }
@@ -210,14 +302,22 @@ impl Example {
         Ok(())
     }
 
+    /// Add the example to the task.
+    ///
+    /// Partial examples only yield positive examples, i.e., their outputs are brave.
+    /// Only literals of the declared `outputs` are added as heads and only literals of the
+    /// declared `inputs` as positive bodies, if any are declared.
     pub fn compute_example(
         &self,
         global_literals: &LitSet,
-        iltask: &mut ILTaskBuilder<Rc<Literal>, Rule>,
+        iltask: &mut ILTaskBuilder<Rc<Literal>, Statement>,
         builder: &LitBuilder,
+        inputs: &[Signature],
+        outputs: &[Signature],
     ) -> Result<(), IlnlpError> {
         let output = self.output.iter().collect::<Vec<_>>();
-        let literals = output.iter().fold(Vec::default(), |mut acc, x| {
+        let included = self.partial_outputs.iter().map(|p| &p.included);
+        let literals = output.iter().copied().chain(included).fold(Vec::default(), |mut acc, x| {
             acc.extend(x.iter().cloned());
             acc
         });
@@ -226,25 +326,50 @@ impl Example {
         let input_literals = builder.get_literals(input.iter());
 
         if self.output.is_empty() {
-            iltask.push_neg_example(vec![], vec![], input_literals.clone());
+            if !self.partial {
+                iltask.push_neg_example(vec![], vec![], input_literals.clone(), self.context_rules());
+            }
         } else {
             for s in output {
                 iltask.push_pos_example(
                     builder.get_literals(s.iter()),
                     builder.get_literals(global_literals.difference(s).iter()),
                     input_literals.clone(),
+                    self.context_rules(),
                 );
             }
-            self.compute_neg_example(global_literals, &literals, iltask, builder)?;
+            if !self.partial {
+                self.compute_neg_example(global_literals, &literals, iltask, builder)?;
+            }
+        }
+        for p in &self.partial_outputs {
+            iltask.push_pos_example(
+                builder.get_literals(p.included.iter()),
+                builder.get_literals(p.excluded.iter()),
+                input_literals.clone(),
+                self.context_rules(),
+            );
         }
 
+        let declared = |signatures: &[Signature], lit: &Literal| {
+            signatures.iter().any(|s| s.matches(lit))
+        };
         let less_out = builder.get_literals(literals.difference(input).iter());
         less_out.into_iter().for_each(|lit| {
-            iltask.push_head(lit.clone());
+            // input atoms derived in the outputs are no candidates for heads
+            if declared(inputs, &lit) {
+                iltask.push_positive_body(lit);
+                return;
+            }
+            if outputs.is_empty() || declared(outputs, &lit) {
+                iltask.push_head(lit.clone());
+            }
             iltask.push_general_body(lit);
         });
         input_literals.into_iter().for_each(|lit| {
-            iltask.push_positive_body(lit);
+            if inputs.is_empty() || declared(inputs, &lit) {
+                iltask.push_positive_body(lit);
+            }
         });
 
         Ok(())
@@ -253,11 +378,82 @@ impl Example {
 
 #[derive(Debug, Default)]
 pub struct Task {
-    background: Vec<Rule>,
+    background: Vec<Statement>,
     examples: Vec<Example>,
+    /// predicates declared by `#input`
+    inputs: Vec<Signature>,
+    /// predicates declared by `#output`
+    outputs: Vec<Signature>,
+    /// predicates shown by `#show p/n.`, the outputs are compared on these only
+    shown: Vec<Signature>,
+    bias: BiasConfig,
+    /// mode declarations, constants and biases of the task file
+    search_space: ILSearchSpace<Rc<Literal>>,
+    /// whether declared modes are added to the inferred ones instead of replacing them
+    merge_modes: bool,
     lit_builder: LitBuilder,
 }
 
+/// the task in the syntax accepted by [`parser::parse_task`]
+impl std::fmt::Display for Task {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let signatures = |signatures: &[Signature]| signatures.iter().join(", ");
+        if !self.inputs.is_empty() {
+            writeln!(f, "#input {}.", signatures(&self.inputs))?;
+        }
+        if !self.outputs.is_empty() {
+            writeln!(f, "#output {}.", signatures(&self.outputs))?;
+        }
+        write!(f, "{}", self.bias)?;
+        for mode in self.search_space.modes() {
+            writeln!(f, "{}", mode)?;
+        }
+        for (type_, value) in self.search_space.constants() {
+            writeln!(f, "#constant({}, {}).", type_, value)?;
+        }
+        for bias in self.search_space.biases() {
+            writeln!(f, "#bias({}).", bias)?;
+        }
+        for signature in &self.shown {
+            writeln!(f, "#show {}.", signature)?;
+        }
+        for statement in &self.background {
+            writeln!(f, "{}", statement)?;
+        }
+        for example in &self.examples {
+            write!(f, "\nI:")?;
+            for literal in self.lit_builder.get_literals(example.input.iter()) {
+                write!(f, " {}", literal)?;
+            }
+            for statement in &example.context {
+                write!(f, " {}", statement)?;
+            }
+            write!(f, " {}", if example.partial { "O?:" } else { "O:" })?;
+            for output in &example.output {
+                let atoms = self.lit_builder.get_literals(output.iter());
+                write!(f, " {{{}}}", atoms.iter().join(" "))?;
+            }
+            for p in &example.partial_outputs {
+                let included = self.lit_builder.get_literals(p.included.iter());
+                let excluded = self.lit_builder.get_literals(p.excluded.iter());
+                write!(f, " {{")?;
+                for atom in included {
+                    write!(f, "{} ", atom)?;
+                }
+                write!(f, "|")?;
+                for atom in excluded {
+                    write!(f, " {}", atom)?;
+                }
+                write!(f, "}}")?;
+            }
+        }
+        if !self.examples.is_empty() {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
 impl Task {
     pub fn create_literal(&self, literal: Literal) -> Lit {
         self.lit_builder.create_literal(literal)
@@ -271,31 +467,120 @@ impl Task {
         self.examples.push(example);
     }
 
-    pub fn push_background(&mut self, rule: Rule) {
-        self.background.push(rule);
+    pub fn push_background(&mut self, statement: Statement) {
+        match statement {
+            Statement::Show(signature) => {
+                if !self.shown.contains(&signature) {
+                    self.shown.push(signature);
+                }
+            }
+            statement => self.background.push(statement),
+        }
+    }
+
+    pub fn push_input(&mut self, signature: Signature) {
+        if !self.inputs.contains(&signature) {
+            self.inputs.push(signature);
+        }
+    }
+
+    pub fn push_output(&mut self, signature: Signature) {
+        if !self.outputs.contains(&signature) {
+            self.outputs.push(signature);
+        }
+    }
+
+    pub fn inputs(&self) -> &[Signature] {
+        &self.inputs
     }
 
-    fn get_definite_rules(&self) -> Vec<&Rule> {
+    pub fn outputs(&self) -> &[Signature] {
+        &self.outputs
+    }
+
+    pub fn shown(&self) -> &[Signature] {
+        &self.shown
+    }
+
+    pub fn bias(&self) -> &BiasConfig {
+        &self.bias
+    }
+
+    pub fn bias_mut(&mut self) -> &mut BiasConfig {
+        &mut self.bias
+    }
+
+    pub fn search_space(&self) -> &ILSearchSpace<Rc<Literal>> {
+        &self.search_space
+    }
+
+    pub fn search_space_mut(&mut self) -> &mut ILSearchSpace<Rc<Literal>> {
+        &mut self.search_space
+    }
+
+    pub fn set_merge_modes(&mut self, merge: bool) {
+        self.merge_modes = merge;
+    }
+
+    /// the literals of `set` with a shown predicate, all if nothing is shown
+    fn project(&self, set: &LitSet) -> Result<LitSet, IlnlpError> {
+        if self.shown.is_empty() {
+            return Ok(set.clone());
+        }
+        let mut projected = Vec::new();
+        for lit in set.iter() {
+            let literal = self.lit_builder.get_literal(*lit)?;
+            if self.shown.iter().any(|s| s.matches(&literal)) {
+                projected.push(*lit);
+            }
+        }
+        Ok(LitSet::new(projected))
+    }
+
+    /// the example with its outputs projected onto the shown predicates
+    fn project_example(&self, example: &Example) -> Result<Example, IlnlpError> {
+        let mut output = Vec::new();
+        for set in &example.output {
+            let set = self.project(set)?;
+            // answer sets may only differ in hidden atoms
+            if !output.contains(&set) {
+                output.push(set);
+            }
+        }
+        let mut partial_outputs = Vec::new();
+        for p in &example.partial_outputs {
+            let p = PartialOutput {
+                included: self.project(&p.included)?,
+                excluded: self.project(&p.excluded)?,
+            };
+            if !partial_outputs.contains(&p) {
+                partial_outputs.push(p);
+            }
+        }
+        Ok(Example {
+            input: example.input.clone(),
+            context: example.context.clone(),
+            output,
+            partial_outputs,
+            partial: example.partial,
+        })
+    }
+
+    fn get_definite_rules(&self) -> Vec<&Statement> {
         self.background
             .iter()
-            .filter(|rule| {
-                rule.body.iter().all(|literal| {
-                    if let BodyLiteral::Literal { negated, .. } = literal {
-                        !negated
-                    } else {
-                        true
-                    }
-                })
-            })
+            .filter(|statement| statement.is_definite())
             .collect()
     }
 
     fn compute_universe(&self) -> Result<LitSet, IlnlpError> {
         let mut universe = Vec::new();
         for example in &self.examples {
-            for output in &example.output {
+            let program = example.program(&self.background);
+            let included = example.partial_outputs.iter().map(|p| &p.included);
+            for output in example.output.iter().chain(included) {
                 asp::ground_literals(
-                    &self.background,
+                    &program,
                     &example.input,
                     output,
                     &self.lit_builder,
@@ -319,32 +604,46 @@ impl Task {
         Ok((size, unique_predicates))
     }
 
-    pub fn check_compatibility(&mut self) -> Result<(), IlnlpError> {
+    pub fn check_compatibility(&mut self, stat: Arc<Mutex<Stat>>) -> Result<(), IlnlpError> {
         if self.examples.len() < 2 {
             return Ok(());
         }
         let definite = self.get_definite_rules();
-        let examples = self.examples.iter();
+        // the outputs are compared on the shown predicates, like the inputs contained in them
+        let examples = self
+            .examples
+            .iter()
+            .map(|e| Ok((e, self.project_example(e)?, self.project(&e.input)?)))
+            .collect::<Result<Vec<_>, IlnlpError>>()?;
         let lit_builder = &self.lit_builder;
-        for e in examples.combinations(2) {
-            let e1 = unsafe { e.get_unchecked(0) };
-            let e2 = unsafe { e.get_unchecked(1) };
-            let o1 = e1.output.iter().collect::<HashSet<_>>();
-            let o2 = e2.output.iter().collect::<HashSet<_>>();
+        for e in examples.iter().combinations(2) {
+            let (e1, p1, i1) = unsafe { e.get_unchecked(0) };
+            let (e2, p2, i2) = unsafe { e.get_unchecked(1) };
+            // the conditions rely on the answer sets of `e2` being complete
+            if e2.partial {
+                continue;
+            }
+            let o1 = p1.output.iter().collect::<HashSet<_>>();
+            let o2 = p2.output.iter().collect::<HashSet<_>>();
             for s1 in o1.difference(&o2) {
-                if e2.input.is_subset(s1) {
-                    for s2 in &e2.output {
-                        if e1.input.is_subset(s2)
+                if i2.is_subset(s1) {
+                    for s2 in &p2.output {
+                        if i1.is_subset(s2)
                             && ((s1.is_subset(s2)) || (s2.is_subset(s1)) && s1.len() != s2.len())
                         {
                             return Err(IlnlpError::IncompatibleOne);
                         }
                     }
-                    let least_model = asp::compute_models(&definite, &e2.input, lit_builder, 1)?;
+                    let definite = definite
+                        .iter()
+                        .copied()
+                        .chain(e2.context.iter().filter(|r| r.is_definite()))
+                        .collect::<Vec<_>>();
+                    let least_model = asp::compute_models(&definite, &e2.input, lit_builder, 1, &stat)?;
                     if e1.input.is_subset(least_model.get(0).unwrap()) {
                         return Err(IlnlpError::IncompatibleTwo);
                     }
-                    let answer_sets = asp::compute_models(&definite, &e2.input, lit_builder, 1)?;
+                    let answer_sets = asp::compute_models(&definite, &e2.input, lit_builder, 1, &stat)?;
                     for m in &answer_sets {
                         if e1.input.is_subset(m) {
                             return Err(IlnlpError::IncompatibleThree);
@@ -356,18 +655,283 @@ impl Task {
         Ok(())
     }
 
-    pub fn ilas(&mut self, stat: Arc<Mutex<Stat>>) -> anyhow::Result<ILTask<Rc<Literal>, Rule>> {
+    /// every output of an example has to be a model of the background,
+    /// otherwise no hypothesis can cover the example.
+    /// If the bias allows no rules, the outputs have to be answer sets of the background.
+    pub fn check_coverable(&self, stat: Arc<Mutex<Stat>>) -> Result<(), IlnlpError> {
+        for (i, example) in self.examples.iter().enumerate() {
+            let program = example.program(&self.background);
+            if self.bias.forbids_rules() {
+                self.check_answer_sets(i, example, &program, &stat)?;
+            }
+            for output in &example.output {
+                let core = asp::explain_uncoverable(
+                    &program,
+                    &example.input,
+                    output,
+                    &self.shown,
+                    &self.lit_builder,
+                    &stat,
+                )?;
+                if let Some(core) = core {
+                    let output = self
+                        .lit_builder
+                        .get_literals(output.iter())
+                        .iter()
+                        .map(|l| l.to_string())
+                        .join(", ");
+                    let core = core
+                        .iter()
+                        .map(|(l, value)| if *value { l.to_string() } else { format!("not {}", l) })
+                        .join(", ");
+                    return Err(IlnlpError::Uncoverable(format!(
+                        "output {{{}}} of example {} violates the background, conflicting literals: {}",
+                        output,
+                        i + 1,
+                        core
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// the outputs of an example have to be answer sets of `program`
+    fn check_answer_sets(
+        &self,
+        i: usize,
+        example: &Example,
+        program: &[&Statement],
+        stat: &Mutex<Stat>,
+    ) -> Result<(), IlnlpError> {
+        let models = asp::compute_models(
+            program,
+            &example.input,
+            &self.lit_builder,
+            usize::MAX,
+            stat,
+        );
+        let models = match models {
+            Ok(models) => models,
+            Err(IlnlpError::NoModel) => vec![],
+            Err(e) => return Err(e),
+        };
+        let models = models
+            .iter()
+            .map(|m| self.project(m))
+            .collect::<Result<Vec<_>, _>>()?;
+        for output in &example.output {
+            let output = self.project(output)?;
+            if !models.contains(&output) {
+                let output = self
+                    .lit_builder
+                    .get_literals(output.iter())
+                    .iter()
+                    .map(|l| l.to_string())
+                    .join(", ");
+                return Err(IlnlpError::Uncoverable(format!(
+                    "output {{{}}} of example {} is no answer set of the background, but the bias allows no rules",
+                    output,
+                    i + 1
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ilas(&mut self, stat: Arc<Mutex<Stat>>) -> anyhow::Result<ILTask<Rc<Literal>, Statement>> {
         let mut iltask = ILTaskBuilder::default();
         self.background.iter().for_each(|r| {
             iltask.push_background(r.clone());
         });
-        let global_literals = self.compute_universe()?;
+        let global_literals = self.project(&self.compute_universe()?)?;
         // 记录 universe 统计信息
         let (universe_size, unique_predicates) = self.compute_universe_stats()?;
         stat.lock().unwrap().record_universe_stats(universe_size, unique_predicates);
         for e in self.examples.iter() {
-            e.compute_example(&global_literals, &mut iltask, &self.lit_builder)?;
+            let e = self.project_example(e)?;
+            e.compute_example(
+                &global_literals,
+                &mut iltask,
+                &self.lit_builder,
+                &self.inputs,
+                &self.outputs,
+            )?;
         }
+        iltask.declare(&self.search_space, self.merge_modes);
+        let heads = iltask
+            .heads()
+            .iter()
+            .map(|l| Signature::new(l.predicate.clone(), l.args.len()))
+            .chain(
+                iltask
+                    .modes()
+                    .iter()
+                    .filter(|mode| mode.head)
+                    .filter_map(|mode| mode.signature()),
+            )
+            .unique()
+            .collect::<Vec<_>>();
+        iltask.set_bias(self.bias.clone(), self.bias.directives(&heads));
         Ok(iltask.build())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat() -> Arc<Mutex<Stat>> {
+        Arc::new(Mutex::new(Stat::new()))
+    }
+
+    /// the ILASP task of `source`
+    fn ilasp(source: &str) -> String {
+        let mut task = parser::parse_task(source).unwrap();
+        task.ilas(stat()).unwrap().to_progam().unwrap()
+    }
+
+    #[test]
+    fn adds_context_rules_to_the_examples() {
+        let program = ilasp("I: e(2,1). e(X,Y) :- e(Y,X). O: {e(2,1) e(1,2) r}");
+        assert!(
+            program.contains("#pos({e(2, 1),e(1, 2),r  },{   },{ e(2, 1). e(X, Y) :- e(Y, X). }).")
+        );
+        assert!(program.contains("#modeh(r)."));
+    }
+
+    #[test]
+    fn restricts_the_bias_to_declared_predicates() {
+        let program = ilasp("#input q/1.\n#output p/1.\nI: q(1) O: {q(1) q(2) p(1) r}");
+        assert!(program.contains("#modeh(p(1))."));
+        assert!(!program.contains("#modeh(r)."));
+        // input atoms are only positive bodies, even if derived in the outputs
+        assert!(program.contains("#modeb(q(1),(positive))."));
+        assert!(program.contains("#modeb(q(2),(positive))."));
+        assert!(!program.contains("#modeh(q(2))."));
+        assert!(program.contains("#modeb(r)."));
+    }
+
+    #[test]
+    fn partial_outputs_only_yield_positive_examples() {
+        let program = ilasp("I: a O?: {a p} {a q}\nI: b O?:\n");
+        assert_eq!(program.matches("#pos(").count(), 2);
+        assert!(program.contains("#pos({a,p  },{ q  },{ a. })."));
+        assert!(!program.contains("#neg("));
+        // an input without answer sets is a negative example if the outputs are complete
+        let program = ilasp("I: a O: {a p} {a q}\nI: b O:\n");
+        assert!(program.contains("#neg({  },{   },{ b. })."));
+    }
+
+    #[test]
+    fn partial_interpretations_keep_their_exclusions() {
+        // unlike answer sets, partial interpretations need not contain the input
+        let program = ilasp("I: a O?: {p | q r} {q |}");
+        assert!(program.contains("#pos({p  },{ q,r  },{ a. })."));
+        assert!(program.contains("#pos({q  },{   },{ a. })."));
+        // atoms that are only excluded need not be derived
+        assert!(!program.contains("#modeh(r)."));
+        assert!(!program.contains("#neg("));
+    }
+
+    #[test]
+    fn written_tasks_parse_to_the_same_task() {
+        let source = "#maxv(2).\n#no_recursion.\n#input e/2.\n#show p/1.\n\
+            #modeh(p(var(t))).\n#constant(t, 1).\n\
+            q(X) :- e(X, Y).\n\
+            I: e(1, 2). e(Y, X) :- e(X, Y). O: {e(1, 2) q(1) p(1)} {e(1, 2) q(1)}\n\
+            I: e(2, 2) O?: {p(2) | q(1)} {e(2, 2) |} {p(2)}\n\
+            I: O:\n";
+        let task = parser::parse_task(source).unwrap();
+        let written = task.to_string();
+        let reparsed = parser::parse_task(&written).unwrap();
+        assert_eq!(reparsed.to_string(), written);
+        assert_eq!(reparsed.examples, task.examples);
+        assert_eq!(reparsed.bias(), task.bias());
+    }
+
+    #[test]
+    fn partial_outputs_are_skipped_by_the_compatibility_check() {
+        // `{a}` may be an answer set of the second example
+        assert!(compatibility("I: a O: {a}\nI: a O?: {a p}\n").is_ok());
+        assert!(matches!(
+            compatibility("I: a O?: {a p}\nI: a O: {a}\n"),
+            Err(IlnlpError::IncompatibleOne)
+        ));
+    }
+
+    #[test]
+    fn declared_modes_replace_inferred_ones() {
+        let source = "#modeh(p(var(t))).\n#constant(t, 1).\nI: q(1) O: {q(1) p(1)}";
+        let program = ilasp(source);
+        assert!(program.contains("#modeh(p(var(t)))."));
+        assert!(program.contains("#constant(t, 1)."));
+        assert!(!program.contains("#modeh(p(1))."));
+        assert!(!program.contains("#modeb(q(1),(positive))."));
+
+        let mut task = parser::parse_task(source).unwrap();
+        task.set_merge_modes(true);
+        let program = task.ilas(stat()).unwrap().to_progam().unwrap();
+        assert!(program.contains("#modeh(p(var(t)))."));
+        assert!(program.contains("#modeh(p(1))."));
+        assert!(program.contains("#modeb(q(1),(positive))."));
+    }
+
+    fn compatibility(source: &str) -> Result<(), IlnlpError> {
+        parser::parse_task(source)
+            .unwrap()
+            .check_compatibility(stat())
+    }
+
+    fn coverability(source: &str) -> Result<(), IlnlpError> {
+        parser::parse_task(source).unwrap().check_coverable(stat())
+    }
+
+    #[test]
+    fn checks_coverability_with_the_input() {
+        // rules and constraints on the input hold in the outputs
+        assert!(coverability(":- not a.\nq :- a.\nI: a O: {a q p}").is_ok());
+        assert!(coverability(":- not a.\nq :- a.\n#show p/0.\nI: a O: {p}").is_ok());
+        // input atoms are given even if an output omits them
+        assert!(coverability(":- not a.\nI: a O: {p}").is_ok());
+
+        let Err(IlnlpError::Uncoverable(message)) = coverability(":- a, p.\nI: a O: {a p}") else {
+            panic!("expected an uncoverable output");
+        };
+        assert!(message.contains("output {a, p} of example 1"));
+        assert!(message.ends_with("conflicting literals: p"));
+        assert!(matches!(
+            coverability(":- a, p.\n#show p/0.\nI: a O: {p}"),
+            Err(IlnlpError::Uncoverable(_))
+        ));
+        // atoms derived from the input have to be in the output
+        assert!(matches!(
+            coverability("q :- a.\nI: a O: {a p}"),
+            Err(IlnlpError::Uncoverable(_))
+        ));
+    }
+
+    #[test]
+    fn projects_outputs_onto_shown_predicates() {
+        let program = ilasp("#show p/1.\nI: a O: {a p(1) h(1)} {a p(1) h(2)}");
+        // both outputs are {p(1)} on the shown predicates
+        assert_eq!(program.matches("#pos(").count(), 1);
+        assert!(program.contains("#pos({p(1)  },{   },{ a. })."));
+        assert!(!program.contains("h(1)"));
+    }
+
+    #[test]
+    fn compares_projected_outputs_for_compatibility() {
+        let examples = "I: a O: {a p h}\nI: a O: {a p}\n";
+        assert!(matches!(
+            compatibility(examples),
+            Err(IlnlpError::IncompatibleOne)
+        ));
+        // the outputs only differ in the hidden atom `h`
+        assert!(compatibility(&format!("#show p/0.\n#show a/0.\n{examples}")).is_ok());
+        // inputs of hidden predicates are not part of the compared outputs
+        assert!(matches!(
+            compatibility("#show p/0.\nI: a O: {a p}\nI: a O: {a}\n"),
+            Err(IlnlpError::IncompatibleOne)
+        ));
+    }
+}
@@ -1,7 +1,12 @@
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use ilnlp::{ilasp::ILTask, stat::Stat};
+use ilnlp::{
+    bias::BiasConfig,
+    generate::{self, GenConfig},
+    ilasp::ILTask,
+    stat::Stat,
+};
 use std::{
     fmt::Display,
     fs::File,
@@ -16,8 +21,10 @@ use sysinfo::{Pid, ProcessesToUpdate, System};
 use clap::Parser;
 
 #[derive(Parser)]
-#[command(about, long_about = None, disable_version_flag = true)]
+#[command(about, long_about = None, disable_version_flag = true, args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Subcommand>,
     input: Option<PathBuf>,
     #[arg(short, long)]
     output: Option<PathBuf>,
@@ -31,9 +38,162 @@ struct Cli {
     run: bool,
     #[arg(long, action = clap::ArgAction::Append, value_parser = clap::builder::NonEmptyStringValueParser::new(), allow_hyphen_values = true)]
     ilasp_args: Vec<String>, // 直接接受完整参数，允许带连字符
+    /// maximum number of variables in a rule, overrides `#maxv`
+    #[arg(long)]
+    maxv: Option<usize>,
+    /// maximum number of body literals in a rule, overrides `#max_body`
+    #[arg(long)]
+    max_body: Option<usize>,
+    /// maximum penalty of the hypothesis, overrides `#max_penalty`
+    #[arg(long)]
+    max_penalty: Option<usize>,
+    /// maximum number of rules in the hypothesis, overrides `#max_rules`,
+    /// only 0 is supported by ILASP
+    #[arg(long)]
+    max_rules: Option<usize>,
+    /// forbid rules using their head predicate in the body, like `#no_recursion`
+    #[arg(long)]
+    no_recursion: bool,
+    /// add the `#modeh`/`#modeb` declarations of the task file to the inferred ones
+    /// instead of replacing them
+    #[arg(long)]
+    merge_modes: bool,
+}
+
+#[derive(clap::Subcommand)]
+enum Subcommand {
+    /// convert an ILASP task (`.las`) into a task file with I/O examples
+    Import {
+        input: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// write the imported ILASP task as well, e.g., to apply a template to it
+        #[arg(long)]
+        las: Option<PathBuf>,
+        /// the template rendering the ILASP task written by `--las`
+        #[arg(long, requires = "las")]
+        template: Option<PathBuf>,
+    },
+    /// generate a task file with the answer sets of a reference program for sampled inputs
+    Gen {
+        /// the reference program computing the outputs
+        #[arg(long)]
+        program: PathBuf,
+        /// instances `I: ...`, or a generator program whose answer sets are the inputs
+        #[arg(long)]
+        inputs: PathBuf,
+        /// number of sampled inputs, all if unset
+        #[arg(long)]
+        count: Option<usize>,
+        /// seed of the sampling
+        #[arg(long)]
+        seed: Option<u64>,
+        /// maximum number of answer sets per input, fewer ones are written as `O?:`
+        #[arg(long)]
+        max_outputs: Option<usize>,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn read(path: &PathBuf) -> anyhow::Result<String> {
+    let mut buf = String::new();
+    BufReader::new(File::open(path)?).read_to_string(&mut buf)?;
+    Ok(buf)
+}
+
+/// the ILASP program of `iltask`, rendered with the `template` file or the default one
+fn render<T: Display, R: Display>(
+    iltask: &ILTask<T, R>,
+    template: Option<&PathBuf>,
+) -> anyhow::Result<String> {
+    match template {
+        Some(p) => {
+            let file =
+                File::open(p).map_err(|e| anyhow::anyhow!("open template file failed: {}", e))?;
+            let mut tpl = String::new();
+            BufReader::new(file)
+                .read_to_string(&mut tpl)
+                .map_err(|e| anyhow::anyhow!("read template file failed: {}", e))?;
+            Ok(iltask.to_progam_with_template(&tpl)?)
+        }
+        None => Ok(iltask.to_progam()?),
+    }
+}
+
+impl Subcommand {
+    fn run(&self) -> anyhow::Result<()> {
+        let (task, output) = match self {
+            Subcommand::Import {
+                input,
+                output,
+                las: las_path,
+                template,
+            } => {
+                let las = ilnlp::parser::las::parse_las(&read(input)?)?;
+                if !las.skipped.is_empty() {
+                    for diagnostic in &las.skipped {
+                        eprintln!("{}\n", diagnostic.warning());
+                    }
+                    eprintln!(
+                        "Warning: {} examples cannot be written as I/O examples and are left out",
+                        las.skipped.len()
+                    );
+                }
+                if let Some(path) = las_path {
+                    let program = render(&las.iltask, template.as_ref())?;
+                    BufWriter::new(File::create(path)?).write_all(program.as_bytes())?;
+                }
+                (las.task, output)
+            }
+            Subcommand::Gen {
+                program,
+                inputs,
+                count,
+                seed,
+                max_outputs,
+                output,
+            } => {
+                let program = ilnlp::parser::parse_program(&read(program)?)?;
+                let config = GenConfig {
+                    count: *count,
+                    max_outputs: *max_outputs,
+                    seed: *seed,
+                };
+                let stat = Mutex::new(Stat::new());
+                let task = generate::generate(&program, &read(inputs)?, &config, &stat)?;
+                (task, output)
+            }
+        };
+        let task = task.to_string();
+        // the task file must be accepted by the parser
+        ilnlp::parser::parse_task(&task)?;
+        match output {
+            Some(path) => BufWriter::new(File::create(path)?).write_all(task.as_bytes())?,
+            None => stdout().write_all(task.as_bytes())?,
+        }
+        Ok(())
+    }
 }
 
 impl Cli {
+    fn bias(&self) -> BiasConfig {
+        BiasConfig {
+            max_vars: self.maxv,
+            max_body: self.max_body,
+            max_penalty: self.max_penalty,
+            max_rules: self.max_rules,
+            recursion: self.no_recursion.then_some(false),
+        }
+    }
+
+    /// the ILASP version given by `--version=` in the ILASP arguments
+    fn ilasp_version(&self) -> Option<&str> {
+        self.ilasp_args
+            .iter()
+            .find_map(|arg| arg.trim().strip_prefix("--version="))
+    }
+
     fn run_ilasp(&self, outpath: &PathBuf, stat: Arc<Mutex<Stat>>) -> anyhow::Result<Duration> {
         let ilasp = self.ilasp.clone();
         println!("Running ILASP... ");
@@ -133,18 +293,7 @@ impl Cli {
         iltask: &ILTask<T, R>,
         temp_file: &mut NamedTempFile,
     ) -> anyhow::Result<Option<PathBuf>> {
-        let program = match &self.template {
-            Some(p) => {
-                let file = File::open(p)
-                    .map_err(|e| anyhow::anyhow!("open template file failed: {}", e))?;
-                let mut tpl = String::new();
-                BufReader::new(file)
-                    .read_to_string(&mut tpl)
-                    .map_err(|e| anyhow::anyhow!("read template file failed: {}", e))?;
-                iltask.to_progam_with_template(&tpl)?
-            }
-            None => iltask.to_progam()?,
-        };
+        let program = render(iltask, self.template.as_ref())?;
         match self.output.as_ref() {
             Some(path) => {
                 BufWriter::new(File::create(path)?).write(program.as_bytes())?;
@@ -194,7 +343,11 @@ impl Cli {
         println!("Parsing...");
         let mut c = ilnlp::parser::parse_task(&buf)?;
         stat.lock().unwrap().parse();
-        c.check_compatibility()?;
+        c.bias_mut().merge(&self.bias());
+        c.set_merge_modes(self.merge_modes);
+        c.bias().validate(self.ilasp_version())?;
+        c.check_compatibility(stat.clone())?;
+        c.check_coverable(stat.clone())?;
         println!("Converting...");
         let iltask = c.ilas(stat.clone())?;
         stat.lock().unwrap().convert();
@@ -212,13 +365,23 @@ impl Cli {
 
 fn main() {
     let cli = Cli::parse();
+    if let Some(command) = &cli.command {
+        if let Err(e) = command.run() {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
     let stat = Arc::new(Mutex::new(Stat::new()));
     let result = cli.run(stat.clone());
 
     stat.lock().unwrap().finish();
-    if let Err(e) = result {
+    if let Err(e) = &result {
         eprintln!("{}", e);
     }
     println!("\n\n===== Statistics ====");
     println!("{}", stat.lock().unwrap());
+    if result.is_err() {
+        std::process::exit(1);
+    }
 }
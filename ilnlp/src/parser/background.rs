@@ -0,0 +1,370 @@
+//! parse the background knowledge with clingo's parser
+//!
+//! Rules that fit the rule model of ilnlp are converted into [`Rule`]s,
+//! all other statements are kept as written in the source.
+
+use clingo::{
+    ClingoError, Logger, SymbolType, Warning,
+    ast::{
+        self, AtomIsA, BodyLiteralIsA, ComparisonOperator, HeadIsA, Sign, StatementHandler,
+        StatementIsA, Term, TermIsA,
+    },
+};
+
+use crate::{
+    BodyLiteral, Comparison, Literal, Rule, Signature, Statement,
+    error::{Diagnostic, IlnlpError},
+};
+
+/// maximum number of syntax errors reported by clingo
+const MESSAGE_LIMIT: u32 = 20;
+
+struct Collector<'s> {
+    source: &'s str,
+    statements: Vec<Statement>,
+    error: Option<ClingoError>,
+}
+
+impl StatementHandler for Collector<'_> {
+    fn on_statement(&mut self, statement: &ast::Statement) -> bool {
+        match self.convert(statement) {
+            Ok(Some(statement)) => {
+                self.statements.push(statement);
+                true
+            }
+            Ok(None) => true,
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        }
+    }
+}
+
+impl Collector<'_> {
+    fn convert(&self, statement: &ast::Statement) -> Result<Option<Statement>, ClingoError> {
+        match statement.clone().is_a()? {
+            // clingo starts every program with `#program base.`
+            StatementIsA::Program(program)
+                if program.name()? == "base" && program.parameters()?.is_empty() =>
+            {
+                Ok(None)
+            }
+            StatementIsA::Comment(_) => Ok(None),
+            StatementIsA::ShowSignature(show) if show.positive()? => Ok(Some(Statement::Show(
+                Signature::new(show.name()?, show.arity()? as usize),
+            ))),
+            StatementIsA::Rule(rule) => Ok(Some(match convert_rule(&rule)? {
+                Some(rule) => Statement::Rule(rule),
+                None => Statement::Verbatim(self.verbatim(statement)?),
+            })),
+            _ => Ok(Some(Statement::Directive(self.verbatim(statement)?))),
+        }
+    }
+
+    /// the source text of a statement, or clingo's rendering if the location does not fit
+    fn verbatim(&self, statement: &ast::Statement) -> Result<String, ClingoError> {
+        let location = statement.location()?;
+        let begin = offset(self.source, location.begin_line(), location.begin_column());
+        let end = offset(self.source, location.end_line(), location.end_column());
+        match (begin, end) {
+            (Some(begin), Some(end)) if begin < end => Ok(self.source[begin..end].to_string()),
+            _ => statement.to_string(),
+        }
+    }
+}
+
+/// byte offset of a 1-based line and column
+fn offset(source: &str, line: usize, column: usize) -> Option<usize> {
+    let start: usize = source
+        .split_inclusive('\n')
+        .take(line.checked_sub(1)?)
+        .map(str::len)
+        .sum();
+    let offset = start + column.checked_sub(1)?;
+    (offset <= source.len() && source.is_char_boundary(offset)).then_some(offset)
+}
+
+fn convert_rule(rule: &ast::Rule) -> Result<Option<Rule>, ClingoError> {
+    let head = match rule.head()?.is_a()? {
+        HeadIsA::Literal(literal) if literal.sign()? == Sign::NoSign => {
+            match literal.atom()?.is_a()? {
+                AtomIsA::SymbolicAtom(atom) => match convert_atom(atom.symbol()?)? {
+                    Some(atom) => Some(atom),
+                    None => return Ok(None),
+                },
+                AtomIsA::BooleanConstant(constant) if !constant.value()? => None,
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+    let mut body = vec![];
+    for literal in rule.body() {
+        let literal = match literal.is_a()? {
+            BodyLiteralIsA::Literal(literal) => literal,
+            _ => return Ok(None),
+        };
+        let negated = match literal.sign()? {
+            Sign::NoSign => false,
+            Sign::Negation => true,
+            Sign::DoubleNegation => return Ok(None),
+        };
+        match literal.atom()?.is_a()? {
+            AtomIsA::SymbolicAtom(atom) => match convert_atom(atom.symbol()?)? {
+                Some(atom) => body.push(BodyLiteral::Literal {
+                    literal: atom,
+                    negated,
+                }),
+                None => return Ok(None),
+            },
+            AtomIsA::Comparison(comparison) if !negated => {
+                let guards = comparison.guards()?;
+                let [guard] = guards.as_slice() else {
+                    return Ok(None);
+                };
+                let (Some(left), Some(right)) =
+                    (convert_argument(comparison.term()?)?, convert_argument(guard.term()?)?)
+                else {
+                    return Ok(None);
+                };
+                body.push(BodyLiteral::Comparison(match guard.comparison()? {
+                    ComparisonOperator::NotEqual => Comparison::NotEqual(left, right),
+                    ComparisonOperator::GreaterThan => Comparison::Greater(left, right),
+                    ComparisonOperator::LessThan => Comparison::Less(left, right),
+                    _ => return Ok(None),
+                }));
+            }
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(Rule { head, body }))
+}
+
+/// convert `p` or `p(t1, ..., tn)` where the arguments are variables or symbols
+fn convert_atom(term: Term) -> Result<Option<Literal>, ClingoError> {
+    match term.is_a()? {
+        TermIsA::Function(function) if !function.external()? => {
+            let mut args = vec![];
+            for argument in function.arguments()? {
+                match convert_argument(argument)? {
+                    Some(argument) => args.push(argument),
+                    None => return Ok(None),
+                }
+            }
+            Ok(Some(Literal::new(function.name()?, args)))
+        }
+        TermIsA::SymbolicTerm(term) => {
+            let symbol = term.symbol()?;
+            if symbol.symbol_type()? == SymbolType::Function
+                && symbol.is_positive()?
+                && symbol.arguments()?.is_empty()
+            {
+                Ok(Some(Literal::new(symbol.name()?.to_string(), vec![])))
+            } else {
+                Ok(None)
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+fn convert_argument(term: Term) -> Result<Option<String>, ClingoError> {
+    match term.is_a()? {
+        TermIsA::Variable(variable) => Ok(Some(variable.name()?)),
+        TermIsA::SymbolicTerm(term) => Ok(Some(term.symbol()?.to_string())),
+        _ => Ok(None),
+    }
+}
+
+/// collects the syntax errors reported by clingo as diagnostics
+struct Messages<'s> {
+    source: &'s str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Logger for Messages<'_> {
+    fn log(&mut self, _code: Warning, message: &str) {
+        match self.diagnostic(message.trim_end()) {
+            Some(diagnostic) => self.diagnostics.push(diagnostic),
+            None => eprintln!("{}", message.trim_end()),
+        }
+    }
+}
+
+impl Messages<'_> {
+    /// convert `<string>:L:C1-C2: error: message` or `<string>:L1:C1-L2:C2: error: message`
+    fn diagnostic(&self, message: &str) -> Option<Diagnostic> {
+        let (location, message) = message.strip_prefix("<string>:")?.split_once(": error: ")?;
+        let (begin, end) = location.split_once('-').unwrap_or((location, location));
+        let (line, column) = begin.split_once(':')?;
+        let (line, column): (usize, usize) = (line.parse().ok()?, column.parse().ok()?);
+        let length = match end.split_once(':') {
+            Some(_) => 1,
+            None => end.parse::<usize>().ok()?.saturating_sub(column),
+        };
+        let message = message
+            .trim_start_matches("syntax error, ")
+            .trim_start_matches("lexer error, ");
+        let Some(begin) = offset(self.source, line, column) else {
+            // clingo reports the end of input after the last line
+            return Some(self.missing_period(self.source.len()));
+        };
+        let line_start = self.source[..begin].rfind('\n').map_or(0, |i| i + 1);
+        let token = self
+            .source
+            .get(begin..begin + length)
+            .filter(|token| !token.is_empty() && !token.contains('\n'));
+        // a statement continued on the next line is usually missing its period
+        if message.starts_with("unexpected EOF")
+            || (self.source[line_start..begin].trim().is_empty()
+                && !self.source[..line_start].trim_end().is_empty()
+                && !self.source[..line_start].trim_end().ends_with('.')
+                && !message.contains("expecting"))
+        {
+            return Some(self.missing_period(begin));
+        }
+        let (unexpected, expected) = match message.split_once(", expecting ") {
+            Some((unexpected, expected)) => (unexpected, Some(expected)),
+            None => (message, None),
+        };
+        let mut message = match (unexpected.strip_prefix("unexpected "), token) {
+            (Some(_), Some(token)) => format!("unexpected `{}`", token),
+            _ => unexpected.to_string(),
+        };
+        if let Some(expected) = expected {
+            let expected: Vec<_> = expected.split(" or ").map(|t| format!("`{}`", t)).collect();
+            message = format!("{}, expected {}", message, expected.join(" or "));
+        }
+        // clingo counts bytes, diagnostics count characters
+        let column = self.source[line_start..begin].chars().count() + 1;
+        let length = token.map_or(1, |token| token.chars().count());
+        Some(Diagnostic::new(
+            self.source,
+            line as u32,
+            column,
+            length,
+            message,
+        ))
+    }
+
+    /// point right after the last statement before `offset`, which is not terminated
+    fn missing_period(&self, offset: usize) -> Diagnostic {
+        let preceding = &self.source[..offset];
+        let (line, code) = preceding
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.split('%').next().unwrap_or_default().trim_end()))
+            .filter(|(_, code)| !code.is_empty())
+            .last()
+            .unwrap_or((1, ""));
+        let column = code.chars().count() + 1;
+        Diagnostic::new(self.source, line as u32, column, 1, "missing `.`")
+    }
+}
+
+/// parse the background knowledge into statements
+///
+/// Syntax errors are reported as [`IlnlpError::Parse`], clingo recovers at the end of each statement
+/// so that all of them are reported at once.
+pub fn parse_background(source: &str) -> Result<Vec<Statement>, IlnlpError> {
+    let mut collector = Collector {
+        source,
+        statements: vec![],
+        error: None,
+    };
+    let mut messages = Messages {
+        source,
+        diagnostics: vec![],
+    };
+    let result =
+        ast::parse_string_with_logger(source, &mut collector, &mut messages, MESSAGE_LIMIT);
+    if let Some(e) = collector.error {
+        return Err(e.into());
+    }
+    if !messages.diagnostics.is_empty() {
+        return Err(IlnlpError::Parse(messages.diagnostics));
+    }
+    result?;
+    Ok(collector.statements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(source: &str) -> Rule {
+        match parse_background(source).unwrap().as_slice() {
+            [Statement::Rule(rule)] => rule.clone(),
+            statements => panic!("expected a rule, got {:?}", statements),
+        }
+    }
+
+    #[test]
+    fn converts_rules() {
+        let rule = rule("p(X, a) :- q(X), not r(X, 1), X != Y, X < 3.");
+        assert_eq!(
+            rule.head,
+            Some(Literal::new("p".into(), vec!["X".into(), "a".into()]))
+        );
+        assert_eq!(
+            rule.body,
+            vec![
+                BodyLiteral::Literal {
+                    literal: Literal::new("q".into(), vec!["X".into()]),
+                    negated: false
+                },
+                BodyLiteral::Literal {
+                    literal: Literal::new("r".into(), vec!["X".into(), "1".into()]),
+                    negated: true
+                },
+                BodyLiteral::Comparison(Comparison::NotEqual("X".into(), "Y".into())),
+                BodyLiteral::Comparison(Comparison::Less("X".into(), "3".into())),
+            ]
+        );
+        assert_eq!(
+            rule.to_string(),
+            "p(X, a) :- q(X), not r(X, 1), X != Y, X < 3."
+        );
+    }
+
+    #[test]
+    fn converts_facts_and_constraints() {
+        assert_eq!(rule("a.").to_string(), "a.");
+        let constraint = rule(":- a, not b.");
+        assert_eq!(constraint.head, None);
+        assert_eq!(constraint.to_string(), " :- a, not b.");
+    }
+
+    #[test]
+    fn keeps_other_statements_as_written() {
+        let statements = parse_background(
+            "{ p(1..3) }.\n:- #count { X : p(X) } > 2.\n#const n = 3.\n#show q : p(1).\n#show p/1.\na :- not not b.",
+        )
+        .unwrap();
+        assert_eq!(
+            statements,
+            vec![
+                Statement::Verbatim("{ p(1..3) }.".into()),
+                Statement::Verbatim(":- #count { X : p(X) } > 2.".into()),
+                Statement::Directive("#const n = 3.".into()),
+                Statement::Directive("#show q : p(1).".into()),
+                Statement::Show(Signature::new("p".into(), 1)),
+                Statement::Verbatim("a :- not not b.".into()),
+            ]
+        );
+        assert!(!statements[5].is_definite());
+    }
+
+    #[test]
+    fn reports_all_syntax_errors() {
+        let Err(IlnlpError::Parse(diagnostics)) = parse_background("a :- b\nc.\nd :- ).\ne.")
+        else {
+            panic!("expected syntax errors");
+        };
+        let positions: Vec<_> = diagnostics.iter().map(|d| (d.line, d.column)).collect();
+        assert_eq!(positions, vec![(1, 7), (3, 6)]);
+        assert_eq!(diagnostics[0].message, "missing `.`");
+        assert!(diagnostics[1].message.starts_with("unexpected `)`"));
+    }
+}
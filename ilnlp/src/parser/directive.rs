@@ -0,0 +1,295 @@
+//! directives of ilnlp in the background knowledge
+//!
+//! Directives like `#input p/2.`, `#maxv(3).` or `#modeh(p(var(t))).` are no clingo syntax, they are parsed by the
+//! grammar below and removed from the background before it is passed to clingo.
+
+use std::{cell::RefCell, rc::Rc};
+
+use nom::{
+    Input, Parser,
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0},
+    multi::separated_list1,
+    sequence::{delimited, preceded},
+};
+
+use super::{Expected, PResult, Span, expect, parse_constant};
+use crate::{Signature, Task, error::Diagnostic, ilasp::ModeDeclaration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    Input,
+    Output,
+    MaxVars,
+    MaxBody,
+    MaxPenalty,
+    MaxRules,
+    NoRecursion,
+    ModeH,
+    ModeB,
+    Constant,
+    Bias,
+}
+
+const DIRECTIVES: [(&str, Directive); 11] = [
+    ("#input", Directive::Input),
+    ("#output", Directive::Output),
+    ("#max_penalty", Directive::MaxPenalty),
+    ("#max_rules", Directive::MaxRules),
+    ("#max_body", Directive::MaxBody),
+    ("#maxv", Directive::MaxVars),
+    ("#no_recursion", Directive::NoRecursion),
+    ("#modeh", Directive::ModeH),
+    ("#modeb", Directive::ModeB),
+    ("#constant", Directive::Constant),
+    ("#bias", Directive::Bias),
+];
+
+/// `name/arity`
+fn parse_signature(input: Span) -> PResult<Signature> {
+    let (input, name) = preceded(multispace0, parse_constant).parse(input)?;
+    let (input, _) = expect("expected `/` followed by the arity", tag("/")).parse(input)?;
+    let (input, arity) = expect("expected the arity", digit1).parse(input)?;
+    let arity = arity.parse().unwrap_or(usize::MAX);
+    Ok((input, Signature::new(name, arity)))
+}
+
+/// `#input p/1, q/2.` or `#output r/1.`
+fn parse_declaration(input: Span, directive: Directive) -> PResult<()> {
+    let (input, signatures) = separated_list1(
+        (multispace0, tag(",")),
+        expect("expected a signature `name/arity`", parse_signature),
+    )
+    .parse(input)?;
+    let (input, _) = expect("expected `,` or `.`", tag(".")).parse(input)?;
+    let task = input.extra.clone();
+    let mut task = task.borrow_mut();
+    for signature in signatures {
+        match directive {
+            Directive::Input => task.push_input(signature),
+            _ => task.push_output(signature),
+        }
+    }
+    Ok((input, ()))
+}
+
+/// `#maxv(n).` and the other limits of the hypothesis space
+fn parse_limit(input: Span, directive: Directive) -> PResult<()> {
+    let (input, n) = delimited(
+        expect("expected `(`", tag("(")),
+        expect("expected a number", digit1),
+        expect("expected `)`", tag(")")),
+    )
+    .parse(input)?;
+    let (input, _) = expect("missing `.`", tag(".")).parse(input)?;
+    let n = Some(n.parse().unwrap_or(usize::MAX));
+    let task = input.extra.clone();
+    let mut task = task.borrow_mut();
+    let bias = task.bias_mut();
+    match directive {
+        Directive::MaxVars => bias.max_vars = n,
+        Directive::MaxBody => bias.max_body = n,
+        Directive::MaxPenalty => bias.max_penalty = n,
+        _ => bias.max_rules = n,
+    }
+    Ok((input, ()))
+}
+
+/// `(a, f(b, c), "x, y", {d, e})` split at the top-level commas, the arguments are kept as written
+pub(super) fn parse_raw_arguments(input: Span) -> PResult<Vec<String>> {
+    let (input, _) = expect("expected `(`", tag("(")).parse(input)?;
+    let fragment = *input.fragment();
+    let mut arguments = vec![];
+    let mut start = 0;
+    let mut depth = 0;
+    let mut chars = fragment.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '(' | '{' => depth += 1,
+            ')' | '}' if depth > 0 => depth -= 1,
+            ',' | ')' if depth == 0 => {
+                arguments.push(fragment[start..i].trim().to_string());
+                start = i + 1;
+                if c == ')' {
+                    return Ok((input.take_from(i + 1), arguments));
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(Expected::failure(input, "expected `)`"))
+}
+
+/// `#modeh(recall, atom, options).`, `#modeb(...)`, `#constant(type, value).` or `#bias("...").`
+///
+/// Errors of the arguments are reported at the `start` of the directive.
+fn parse_search_space<'a>(
+    start: Span<'a>,
+    input: Span<'a>,
+    directive: Directive,
+) -> PResult<'a, ()> {
+    let (input, mut arguments) = parse_raw_arguments(input)?;
+    let (input, _) = expect("missing `.`", tag(".")).parse(input)?;
+    let task = input.extra.clone();
+    let mut task = task.borrow_mut();
+    let search_space = task.search_space_mut();
+    let invalid = |message| Err(Expected::failure(start.clone(), message));
+    match directive {
+        Directive::ModeH | Directive::ModeB => {
+            let recall = match arguments.first() {
+                Some(recall)
+                    if recall.chars().all(|c| c.is_ascii_digit()) && !recall.is_empty() =>
+                {
+                    let recall = recall.parse().ok();
+                    arguments.remove(0);
+                    recall
+                }
+                _ => None,
+            };
+            if arguments.is_empty() || arguments.len() > 2 || arguments[0].is_empty() {
+                return invalid(
+                    "expected `(recall, atom, options)` with optional recall and options",
+                );
+            }
+            let options = arguments.get(1).cloned();
+            search_space.push_mode(ModeDeclaration {
+                head: directive == Directive::ModeH,
+                recall,
+                atom: arguments.swap_remove(0),
+                options,
+            });
+        }
+        Directive::Constant => match <[String; 2]>::try_from(arguments) {
+            Ok([type_, value]) => search_space.push_constant(type_, value),
+            Err(_) => return invalid("expected `(type, value)`"),
+        },
+        _ => match arguments.as_slice() {
+            [bias] if bias.starts_with('"') && bias.ends_with('"') && bias.len() > 1 => {
+                search_space.push_bias(bias.clone())
+            }
+            _ => return invalid("expected a string `(\"...\")`"),
+        },
+    }
+    Ok((input, ()))
+}
+
+fn parse_directive(input: Span) -> PResult<()> {
+    let (name, directive) = DIRECTIVES
+        .iter()
+        .find(|(name, _)| input.fragment().starts_with(name))
+        .copied()
+        .expect("found by find_statements");
+    let start = input.clone();
+    let (input, _) = tag(name).parse(input)?;
+    match directive {
+        Directive::Input | Directive::Output => parse_declaration(input, directive),
+        Directive::NoRecursion => {
+            let (input, _) = expect("missing `.`", tag(".")).parse(input)?;
+            input.extra.borrow_mut().bias_mut().recursion = Some(false);
+            Ok((input, ()))
+        }
+        Directive::ModeH | Directive::ModeB | Directive::Constant | Directive::Bias => {
+            parse_search_space(start, input, directive)
+        }
+        _ => parse_limit(input, directive),
+    }
+}
+
+/// Offsets of the statements starting with one of `names` outside of comments and strings
+pub(super) fn find_statements(source: &str, names: &[&str]) -> Vec<usize> {
+    let mut offsets = vec![];
+    let mut rest = source;
+    while let Some(i) = rest.find(['%', '"', '#']) {
+        let offset = source.len() - rest.len() + i;
+        rest = &rest[i..];
+        let end = if rest.starts_with("%*") {
+            rest.find("*%").map_or(rest.len(), |end| end + 2)
+        } else if rest.starts_with('%') {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if rest.starts_with('"') {
+            let mut chars = rest.char_indices().skip(1);
+            let mut end = rest.len();
+            while let Some((j, c)) = chars.next() {
+                match c {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '"' => {
+                        end = j + 1;
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            end
+        } else {
+            let directive = names.iter().any(|name| {
+                rest.starts_with(name)
+                    && !rest[name.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+            });
+            if directive {
+                offsets.push(offset);
+            }
+            1
+        };
+        rest = &rest[end..];
+    }
+    offsets
+}
+
+/// Parse the directives of ilnlp in the background and replace them by spaces,
+/// keeping the positions of the remaining statements.
+///
+/// Errors are reported as diagnostics and the erroneous directive is skipped up to its `.`.
+pub(super) fn extract_directives(
+    background: &str,
+    task: &Rc<RefCell<Task>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> String {
+    let names = DIRECTIVES.map(|(name, _)| name);
+    let mut result = background.to_string();
+    for offset in find_statements(background, &names) {
+        let line = background[..offset].matches('\n').count() as u32 + 1;
+        // SAFETY: the fragment is the suffix of `background` starting at `offset` on line `line`
+        let input =
+            unsafe { Span::new_from_raw_offset(offset, line, &background[offset..], task.clone()) };
+        let end = match parse_directive(input) {
+            Ok((rest, _)) => rest.location_offset(),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                diagnostics.push(e.diagnostic(background));
+                background[offset..]
+                    .find('.')
+                    .map_or(background.len(), |i| offset + i + 1)
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers"),
+        };
+        blank(&mut result, offset..end);
+    }
+    result
+}
+
+/// replace the `range` of `text` by spaces, keeping the line breaks and byte offsets
+pub(super) fn blank(text: &mut String, range: std::ops::Range<usize>) {
+    let blank: String = text[range.clone()]
+        .chars()
+        .map(|c| {
+            if c == '\n' {
+                c.to_string()
+            } else {
+                " ".repeat(c.len_utf8())
+            }
+        })
+        .collect();
+    text.replace_range(range, &blank);
+}
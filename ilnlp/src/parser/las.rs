@@ -0,0 +1,587 @@
+//! import of ILASP learning tasks
+//!
+//! A `.las` file is read into an [`ILTask`]: the examples `#pos(id, {incl}, {excl}, {ctx}).` and
+//! `#neg(...)` with optional id and context, the directives like `#modeh` or `#maxv` and the
+//! remaining statements as background.
+//!
+//! The examples with identical contexts are grouped into the I/O examples of a [`Task`]: the facts
+//! of the context are the input, its other rules the rules of the input, and the positive examples
+//! are the partial interpretations `{incl | excl}` of the outputs, the inclusions extended by the
+//! input. The answer sets are not known exhaustively, so the outputs are partial (`O?:`), unless
+//! the only examples of a group are negative ones `#neg({}, {}, ctx)`, i.e., the input has no
+//! answer set (`O:`). Other negative examples cannot be written as I/O examples, neither can
+//! examples with a penalty, e.g., `#pos(p1@10, ...)`, they are kept in the ILASP task only.
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use nom::{Parser, bytes::complete::tag};
+
+use super::{PResult, Span, background::parse_background, directive, expect};
+use crate::{
+    BodyLiteral, Example, Literal, PartialOutput, Rule, Signature, Statement, Task,
+    error::{Diagnostic, IlnlpError},
+    ilasp::{ILExample, ILTask, ILTaskBuilder},
+};
+
+const EXAMPLES: [&str; 2] = ["#pos", "#neg"];
+
+/// an ILASP task and the I/O examples reconstructed from it
+#[derive(Debug)]
+pub struct LasTask {
+    pub iltask: ILTask<Rc<Literal>, Statement>,
+    pub task: Task,
+    /// the examples left out of `task` with the reason, e.g., their atoms or rules have no I/O
+    /// syntax or they have a penalty
+    pub skipped: Vec<Diagnostic>,
+}
+
+/// an example `#pos(...)` or `#neg(...)`
+struct LasExample {
+    /// byte offset of the example in the source
+    start: usize,
+    /// the id with an optional penalty, e.g., `p1@10`, and its byte offset
+    id: Option<(usize, String)>,
+    positive: bool,
+    incl: Vec<Literal>,
+    excl: Vec<Literal>,
+    /// the facts of the context
+    facts: Vec<Literal>,
+    /// the other rules of the context
+    rules: Vec<Statement>,
+}
+
+impl LasExample {
+    /// whether the example can be written in the examples of a task file
+    fn is_writable(&self) -> bool {
+        self.incl
+            .iter()
+            .chain(&self.excl)
+            .chain(&self.facts)
+            .all(is_writable)
+            && self.rules.iter().all(|rule| {
+                // an input rule starts with its head and neck
+                let text = rule.to_string();
+                text.split_once(":-").is_some_and(|(head, _)| {
+                    head.chars()
+                        .all(|c| c.is_alphanumeric() || "_'(), ".contains(c))
+                })
+            })
+    }
+}
+
+/// the examples with the same context
+#[derive(Default)]
+struct Group {
+    facts: Vec<Literal>,
+    rules: Vec<Statement>,
+    /// the inclusions and exclusions of the positive examples
+    outputs: Vec<(Vec<Literal>, Vec<Literal>)>,
+    /// the negative examples `#neg({}, {}, ctx)` excluding all answer sets
+    unsatisfiable: Vec<usize>,
+    /// the other negative examples
+    negative: Vec<usize>,
+    /// all examples of the group
+    examples: Vec<usize>,
+    writable: bool,
+}
+
+/// whether the arguments of `literal` are constants or integers
+fn is_writable(literal: &Literal) -> bool {
+    literal.args.iter().all(|arg| {
+        arg.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && arg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '\'')
+    })
+}
+
+/// 1-based line and column in characters of a byte offset
+fn location(source: &str, offset: usize) -> (u32, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() as u32 + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn invalid(source: &str, offset: usize, message: impl Into<String>) -> IlnlpError {
+    let (line, column) = location(source, offset);
+    IlnlpError::Parse(vec![Diagnostic::new(source, line, column, 1, message)])
+}
+
+/// Parse the `text` at byte `offset` of `source` with clingo, enclosed in `prefix` and `suffix`.
+///
+/// The prefix is put on a line of its own and the text is indented to its column, so that the
+/// diagnostics point into `source`.
+fn parse_part(
+    source: &str,
+    offset: usize,
+    text: &str,
+    (prefix, suffix): (&str, &str),
+) -> Result<Vec<Statement>, IlnlpError> {
+    let (line, column) = location(source, offset);
+    let program = format!("{}\n{}{}{}", prefix, " ".repeat(column - 1), text, suffix);
+    parse_background(&program).map_err(|e| match e {
+        IlnlpError::Parse(diagnostics) => IlnlpError::Parse(
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    Diagnostic::new(
+                        source,
+                        (line + diagnostic.line).saturating_sub(2).max(line),
+                        diagnostic.column,
+                        diagnostic.length,
+                        diagnostic.message,
+                    )
+                })
+                .collect(),
+        ),
+        e => e,
+    })
+}
+
+/// the atoms `a, b(1, 2)` of an inclusion or exclusion, parsed as the body of a constraint
+fn parse_atoms(source: &str, offset: usize, text: &str) -> Result<Vec<Literal>, IlnlpError> {
+    if text.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    let atoms = match parse_part(source, offset, text, (":-", "."))?.as_slice() {
+        [Statement::Rule(Rule { head: None, body })] => body
+            .iter()
+            .map(|literal| match literal {
+                BodyLiteral::Literal {
+                    literal,
+                    negated: false,
+                } => Some(literal.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    };
+    atoms.ok_or_else(|| invalid(source, offset - 1, "expected a set of atoms"))
+}
+
+/// the facts and the other rules of a context
+fn parse_context(
+    source: &str,
+    offset: usize,
+    text: &str,
+) -> Result<(Vec<Literal>, Vec<Statement>), IlnlpError> {
+    let mut facts = vec![];
+    let mut rules = vec![];
+    for statement in parse_part(source, offset, text, ("", ""))? {
+        match statement {
+            Statement::Rule(Rule {
+                head: Some(head),
+                body,
+            }) if body.is_empty() => facts.push(head),
+            statement => rules.push(statement),
+        }
+    }
+    facts.sort();
+    facts.dedup();
+    Ok((facts, rules))
+}
+
+/// move the diagnostics of `result` into `diagnostics`
+fn collect<T>(
+    result: Result<T, IlnlpError>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Option<T>, IlnlpError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(IlnlpError::Parse(errors)) => {
+            diagnostics.extend(errors);
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// `#pos(id, {incl}, {excl}, {ctx}).` or `#neg(...)` at the start of `input`
+///
+/// Returns the end offset of the example, and the example unless it has errors.
+fn parse_example(
+    input: Span,
+    source: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(usize, Option<LasExample>), IlnlpError> {
+    let start = input.location_offset();
+    let positive = input.fragment().starts_with("#pos");
+    let structure: PResult<_> = (
+        tag(if positive { "#pos" } else { "#neg" }),
+        directive::parse_raw_arguments,
+        expect("missing `.`", tag(".")),
+    )
+        .parse(input);
+    let (end, arguments) = match structure {
+        Ok((rest, (_, arguments, _))) => (rest.location_offset(), arguments),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            diagnostics.push(e.diagnostic(source));
+            let end = source[start..]
+                .find(").")
+                .map_or(source.len(), |i| start + i + 2);
+            return Ok((end, None));
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers"),
+    };
+    // the sets are kept as written, find them in order to locate their errors
+    let mut position = start;
+    let mut sets = vec![];
+    for argument in &arguments {
+        let at = position + source[position..end].find(argument.as_str()).unwrap_or(0);
+        position = at + argument.len();
+        sets.push((at, argument.as_str()));
+    }
+    // the optional id, e.g., `p1@10`
+    let id = match sets.first() {
+        Some(&(at, id)) if !id.starts_with('{') => {
+            sets.remove(0);
+            Some((at, id.to_string()))
+        }
+        _ => None,
+    };
+    let sets: Option<Vec<_>> = sets
+        .into_iter()
+        .map(|(at, set)| {
+            let inner = set.strip_prefix('{')?.strip_suffix('}')?;
+            Some((at + 1, inner))
+        })
+        .collect();
+    let sets = match sets {
+        Some(sets) if (2..=3).contains(&sets.len()) => sets,
+        _ => {
+            let (line, column) = location(source, start);
+            diagnostics.push(Diagnostic::new(
+                source,
+                line,
+                column,
+                4,
+                "expected `({incl}, {excl}, {ctx})` with optional id and context",
+            ));
+            return Ok((end, None));
+        }
+    };
+    let incl = collect(parse_atoms(source, sets[0].0, sets[0].1), diagnostics)?;
+    let excl = collect(parse_atoms(source, sets[1].0, sets[1].1), diagnostics)?;
+    let context = match sets.get(2) {
+        Some(&(at, text)) => collect(parse_context(source, at, text), diagnostics)?,
+        None => Some(Default::default()),
+    };
+    let example = match (incl, excl, context) {
+        (Some(incl), Some(excl), Some((facts, rules))) => Some(LasExample {
+            start,
+            id,
+            positive,
+            incl,
+            excl,
+            facts,
+            rules,
+        }),
+        _ => None,
+    };
+    Ok((end, example))
+}
+
+/// group the examples by their contexts into I/O examples of `task`
+///
+/// Returns the diagnostics of the examples that cannot be written in the I/O syntax.
+fn group_examples(task: &mut Task, examples: &[LasExample], source: &str) -> Vec<Diagnostic> {
+    let skip = |i: usize, message: &str| {
+        let (line, column) = location(source, examples[i].start);
+        Diagnostic::new(source, line, column, 4, message)
+    };
+    let mut skipped = vec![];
+    let mut groups: Vec<Group> = vec![];
+    let mut index = HashMap::new();
+    for (i, example) in examples.iter().enumerate() {
+        if let Some((at, id)) = &example.id
+            && id.contains('@')
+        {
+            let (line, column) = location(source, *at);
+            skipped.push(Diagnostic::new(
+                source,
+                line,
+                column,
+                id.chars().count(),
+                "the penalty of the example cannot be written in I/O examples",
+            ));
+            continue;
+        }
+        let key = (
+            example.facts.clone(),
+            example
+                .rules
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>(),
+        );
+        let g = *index.entry(key).or_insert_with(|| {
+            groups.push(Group {
+                facts: example.facts.clone(),
+                rules: example.rules.clone(),
+                writable: true,
+                ..Default::default()
+            });
+            groups.len() - 1
+        });
+        let group = &mut groups[g];
+        group.examples.push(i);
+        group.writable &= example.is_writable();
+        if example.positive {
+            let mut included: Vec<_> = example.incl.iter().chain(&example.facts).cloned().collect();
+            included.sort();
+            included.dedup();
+            let mut excluded = example.excl.clone();
+            excluded.sort();
+            excluded.dedup();
+            let output = (included, excluded);
+            if !group.outputs.contains(&output) {
+                group.outputs.push(output);
+            }
+        } else if example.incl.is_empty() && example.excl.is_empty() {
+            group.unsatisfiable.push(i);
+        } else {
+            group.negative.push(i);
+        }
+    }
+    for group in groups {
+        if !group.writable {
+            skipped.extend(group.examples.iter().map(|&i| {
+                skip(i, "the atoms or rules of the examples of this context have no I/O syntax")
+            }));
+            continue;
+        }
+        // the outputs are complete only if there are no answer sets
+        let complete = !group.unsatisfiable.is_empty() && group.outputs.is_empty();
+        if !complete {
+            skipped.extend(group.unsatisfiable.iter().map(|&i| {
+                skip(i, "the input has answer sets by the positive examples of this context")
+            }));
+        }
+        skipped.extend(group.negative.iter().map(|&i| {
+            skip(i, "negative examples of partial interpretations cannot be written in I/O examples")
+        }));
+        if !complete && group.outputs.is_empty() {
+            continue;
+        }
+        let lits = |literals: Vec<Literal>| {
+            crate::LitSet::new(
+                literals
+                    .into_iter()
+                    .map(|literal| task.create_literal(literal))
+                    .collect(),
+            )
+        };
+        let example = Example {
+            input: lits(group.facts),
+            context: group.rules,
+            output: vec![],
+            partial_outputs: group
+                .outputs
+                .into_iter()
+                .map(|(included, excluded)| PartialOutput {
+                    included: lits(included),
+                    excluded: lits(excluded),
+                })
+                .collect(),
+            partial: !complete,
+        };
+        task.push_example(example);
+    }
+    skipped.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    skipped
+}
+
+/// Parse an ILASP task
+///
+/// All syntax errors are collected into a single [`IlnlpError::Parse`].
+pub fn parse_las(source: &str) -> Result<LasTask, IlnlpError> {
+    let task = Rc::new(RefCell::new(Task::default()));
+    let mut diagnostics = vec![];
+    let mut background = source.to_string();
+    let mut examples = vec![];
+    for offset in directive::find_statements(source, &EXAMPLES) {
+        let line = source[..offset].matches('\n').count() as u32 + 1;
+        // SAFETY: the fragment is the suffix of `source` starting at `offset` on line `line`
+        let input =
+            unsafe { Span::new_from_raw_offset(offset, line, &source[offset..], task.clone()) };
+        let (end, example) = parse_example(input, source, &mut diagnostics)?;
+        examples.extend(example);
+        directive::blank(&mut background, offset..end);
+    }
+    let background = directive::extract_directives(&background, &task, &mut diagnostics);
+    let statements = match parse_background(&background) {
+        Ok(statements) => statements,
+        Err(IlnlpError::Parse(errors)) => {
+            // show the source lines with the examples and directives
+            diagnostics.extend(errors.into_iter().map(|diagnostic| {
+                Diagnostic::new(
+                    source,
+                    diagnostic.line,
+                    diagnostic.column,
+                    diagnostic.length,
+                    diagnostic.message,
+                )
+            }));
+            vec![]
+        }
+        Err(e) => return Err(e),
+    };
+    if !diagnostics.is_empty() {
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+        return Err(IlnlpError::Parse(diagnostics));
+    }
+
+    let mut task = task.take();
+    let mut iltask = ILTaskBuilder::default();
+    for statement in statements {
+        iltask.push_background(statement.clone());
+        task.push_background(statement);
+    }
+    for example in &examples {
+        let literals = |literals: &[Literal]| literals.iter().cloned().map(Rc::new).collect();
+        let rules = example.rules.iter().map(|r| r.to_string()).collect();
+        let (incl, excl, ctx) = (
+            literals(&example.incl),
+            literals(&example.excl),
+            literals(&example.facts),
+        );
+        let mut ilexample = ILExample::new(incl, excl, ctx).with_rules(rules);
+        if let Some((_, id)) = &example.id {
+            ilexample = ilexample.with_id(id.clone());
+        }
+        iltask.push_example(example.positive, ilexample);
+    }
+    iltask.declare(task.search_space(), false);
+    let heads: Vec<Signature> = task
+        .search_space()
+        .modes()
+        .iter()
+        .filter(|mode| mode.head)
+        .filter_map(|mode| mode.signature())
+        .collect();
+    iltask.set_bias(task.bias().clone(), task.bias().directives(&heads));
+    let skipped = group_examples(&mut task, &examples, source);
+    Ok(LasTask {
+        iltask: iltask.build(),
+        task,
+        skipped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "node(1..3).
+#modeh(col(var(node), const(color))).
+#maxv(2).
+#pos(p1, {col(1, red)}, {col(1, blue)}, {e(1,2). e(2,3).}).
+#pos({col(2, red)}, {}, {
+  e(2,3).
+  e(1,2).
+}).
+#neg({col(1, red), col(2, red)}, {}, {e(1,2). e(2,3).}).
+#neg({}, {}, {e(3,3).}).
+#pos({q(1)}, {}, {r(1). q(X) :- r(X).}).
+";
+
+    fn examples(task: &Task) -> Vec<String> {
+        let task = task.to_string();
+        task.lines()
+            .filter(|line| line.starts_with("I:"))
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn parses_the_ilasp_task() {
+        let las = parse_las(SOURCE).unwrap();
+        let program = las.iltask.to_progam().unwrap();
+        assert!(program.contains("node(1..3)."));
+        assert!(
+            program.contains("#pos(p1,{col(1, red)  },{ col(1, blue)  },{ e(1, 2). e(2, 3). }).")
+        );
+        assert!(program.contains("#neg({col(1, red),col(2, red)  },{   },{ e(1, 2). e(2, 3). })."));
+        assert!(program.contains("#pos({q(1)  },{   },{ r(1). q(X) :- r(X). })."));
+        assert!(program.contains("#modeh(col(var(node), const(color)))."));
+        assert!(program.contains("#maxv(2)."));
+        assert_eq!(las.task.bias().max_vars, Some(2));
+    }
+
+    #[test]
+    fn groups_examples_by_context() {
+        let las = parse_las(SOURCE).unwrap();
+        assert_eq!(
+            examples(&las.task),
+            vec![
+                "I: e(1, 2) e(2, 3) O?: {e(1, 2) e(2, 3) col(1, red) | col(1, blue)} {e(1, 2) e(2, 3) col(2, red) |}",
+                "I: e(3, 3) O:",
+                "I: r(1) q(X) :- r(X). O?: {r(1) q(1) |}",
+            ]
+        );
+        // the negative example with inclusions cannot be written as I/O example
+        assert_eq!(las.skipped.len(), 1);
+        assert_eq!((las.skipped[0].line, las.skipped[0].column), (9, 1));
+    }
+
+    #[test]
+    fn keeps_penalties_in_the_ilasp_task_only() {
+        let las = parse_las(
+            "#pos(p1@10, {p}, {q}, {a.}).\n#pos(p2, {q}, {}, {a.}).\n#neg(@3, {}, {}, {b.}).",
+        )
+        .unwrap();
+        let program = las.iltask.to_progam().unwrap();
+        assert!(program.contains("#pos(p1@10,{p  },{ q  },{ a. })."));
+        assert!(program.contains("#pos(p2,{q  },{   },{ a. })."));
+        assert!(program.contains("#neg(@3,{  },{   },{ b. })."));
+        assert_eq!(examples(&las.task), vec!["I: a O?: {a q |}"]);
+        let skipped: Vec<_> = las
+            .skipped
+            .iter()
+            .map(|d| (d.line, d.column, d.length, d.message.as_str()))
+            .collect();
+        let message = "the penalty of the example cannot be written in I/O examples";
+        assert_eq!(skipped, vec![(1, 6, 5, message), (3, 6, 2, message)]);
+    }
+
+    #[test]
+    fn keeps_groups_with_answer_sets_partial() {
+        let las =
+            parse_las("#neg({}, {}, {a.}).\n#pos({b}, {c}, {a.}).\n#neg({}, {}, {a.}).").unwrap();
+        assert_eq!(examples(&las.task), vec!["I: a O?: {a b | c}"]);
+        assert_eq!(las.skipped.len(), 2);
+    }
+
+    #[test]
+    fn skips_groups_without_io_syntax() {
+        let las =
+            parse_las("#pos({s(\"x\")}, {}, {a.}).\n#pos({b}, {}, {a.}).\n#pos({b}, {}).").unwrap();
+        assert_eq!(examples(&las.task), vec!["I: O?: {b |}"]);
+        assert_eq!(las.skipped.len(), 2);
+    }
+
+    #[test]
+    fn imported_tasks_parse() {
+        let las = parse_las(SOURCE).unwrap();
+        let task = crate::parser::parse_task(&las.task.to_string()).unwrap();
+        assert_eq!(task.to_string(), las.task.to_string());
+    }
+
+    #[test]
+    fn reports_errors_of_examples() {
+        let Err(IlnlpError::Parse(diagnostics)) = parse_las(
+            "#pos({a}, {b c}, {}).\n#neg({a}).\n#pos({a}, {}, {c :- d.}).\nd :- ).\n#pos({a}, {})",
+        ) else {
+            panic!("expected syntax errors");
+        };
+        let positions: Vec<_> = diagnostics.iter().map(|d| (d.line, d.column)).collect();
+        assert_eq!(positions, vec![(1, 14), (2, 1), (4, 6), (5, 14)]);
+        assert_eq!(
+            diagnostics[1].message,
+            "expected `({incl}, {excl}, {ctx})` with optional id and context"
+        );
+    }
+}
@@ -1,25 +1,91 @@
 use std::{cell::RefCell,  rc::Rc};
 
 use nom::{
-    IResult, Parser,
+    IResult, Input, Parser,
     branch::alt,
     bytes::complete::{tag, take_while},
     character::complete::{
          digit1, multispace0, multispace1, one_of,
     },
-    combinator::{map, opt, recognize},
-    multi::{many0, separated_list0, separated_list1},
+    combinator::{map, opt, recognize, value},
+    error::{ErrorKind, ParseError},
+    multi::{many0, separated_list0},
     sequence::{delimited, preceded},
 };
 use nom_locate::LocatedSpan;
 
 use crate::{
-    BodyLiteral, Comparison, Example, Lit, LitSet, Literal, Rule, Task, error::IlnlpError,
+    Example, Lit, LitSet, Literal, PartialOutput, Rule, Statement, Task,
+    error::{Diagnostic, IlnlpError},
 };
 
+mod background;
+mod directive;
+pub mod las;
+
 type Span<'a> = LocatedSpan<&'a str, Rc<RefCell<Task>>>;
+type PResult<'a, O> = IResult<Span<'a>, O, Expected<'a>>;
+
+/// Error of the example grammar: the position and, once committed, what was expected there
+#[derive(Debug)]
+pub struct Expected<'a> {
+    at: Span<'a>,
+    message: Option<String>,
+}
+
+impl<'a> ParseError<Span<'a>> for Expected<'a> {
+    fn from_error_kind(input: Span<'a>, _kind: ErrorKind) -> Self {
+        Expected {
+            at: input,
+            message: None,
+        }
+    }
+    fn append(_input: Span<'a>, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> Expected<'a> {
+    /// fail without backtracking
+    fn failure(at: Span<'a>, message: impl Into<String>) -> nom::Err<Self> {
+        nom::Err::Failure(Expected {
+            at,
+            message: Some(message.into()),
+        })
+    }
 
-fn parse_variable(input: Span) -> IResult<Span, String> {
+    fn diagnostic(&self, source: &str) -> Diagnostic {
+        let token = self.at.fragment().split(char::is_whitespace).next();
+        Diagnostic::new(
+            source,
+            self.at.location_line(),
+            self.at.get_utf8_column(),
+            token.map_or(1, |token| token.chars().count()),
+            self.message.as_deref().unwrap_or("invalid example"),
+        )
+    }
+}
+
+/// Skip whitespace and run `parser`, failing without backtracking if it does not match.
+///
+/// The error points at the next token if it is on the same line, otherwise right after the
+/// previous one.
+fn expect<'a, O>(
+    message: &'static str,
+    mut parser: impl Parser<Span<'a>, Output = O, Error = Expected<'a>>,
+) -> impl Parser<Span<'a>, Output = O, Error = Expected<'a>> {
+    move |input: Span<'a>| {
+        let (next, space) = multispace0(input.clone())?;
+        parser.parse(next.clone()).map_err(|e| match e {
+            nom::Err::Error(_) => {
+                Expected::failure(if space.contains('\n') { input } else { next }, message)
+            }
+            e => e,
+        })
+    }
+}
+
+fn parse_variable(input: Span) -> PResult<String> {
     let identifier = recognize((
         take_while(|c: char| c == '_'),
         one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
@@ -28,10 +94,10 @@ fn parse_variable(input: Span) -> IResult<Span, String> {
 
     map(identifier, |s: Span| s.to_string()).parse(input)
 }
-fn parse_integer(input: Span) -> IResult<Span, String> {
+fn parse_integer(input: Span) -> PResult<String> {
     map(digit1, |s: Span| s.to_string()).parse(input)
 }
-fn parse_constant(input: Span) -> IResult<Span, String> {
+fn parse_constant(input: Span) -> PResult<String> {
     let identifier = recognize((
         take_while(|c: char| c == '_'),
         one_of("abcdefghijklmnopqrstuvwxyz"),
@@ -41,181 +107,685 @@ fn parse_constant(input: Span) -> IResult<Span, String> {
     map(identifier, |s: Span| s.to_string()).parse(input)
 }
 
-fn parse_anonymous(input: Span) -> IResult<Span, String> {
+fn parse_anonymous(input: Span) -> PResult<String> {
     map(tag("_"), |s: Span| s.to_string()).parse(input)
 }
 
-fn parse_term(input: Span) -> IResult<Span, Literal> {
-    let (input, predicate) = preceded(multispace0, parse_constant).parse(input)?;
-    let (input, args) = opt(delimited(
-        (multispace0, tag("("), multispace0),
-        separated_list0(
-            (multispace0, tag(","), multispace0),
-            alt((
-                parse_constant,
-                parse_variable,
-                parse_integer,
-                parse_anonymous,
-            )),
-        ),
-        (multispace0, tag(")")),
+fn parse_argument(input: Span) -> PResult<String> {
+    alt((
+        parse_constant,
+        parse_variable,
+        parse_integer,
+        parse_anonymous,
     ))
-    .parse(input)?;
-    Ok((input, Literal::new(predicate, args.unwrap_or_default())))
+    .parse(input)
 }
 
+const ARGUMENT: &str = "expected a constant, variable or integer";
 
-fn parse_comparison(input: Span) -> IResult<Span, BodyLiteral> {
-    let (input, left) = preceded(multispace0, alt((parse_variable, parse_integer))).parse(input)?;
-    let (input, op) = preceded(multispace0, alt((tag("!="), tag(">"), tag("<")))).parse(input)?;
-    let (input, right) =
-        preceded(multispace0, alt((parse_variable, parse_integer))).parse(input)?;
-    let comparison = match op.trim() {
-        "!=" => Comparison::NotEqual(left, right),
-        ">" => Comparison::Greater(left, right),
-        "<" => Comparison::Less(left, right),
-        _ => unreachable!(),
-    };
-    Ok((input, BodyLiteral::Comparison(comparison)))
+/// `(t1, ..., tn)`, committed after the opening parenthesis
+fn parse_arguments(input: Span) -> PResult<Vec<String>> {
+    let (input, _) = (multispace0, tag("(")).parse(input)?;
+    let closed: PResult<_> = (multispace0, tag(")")).parse(input.clone());
+    if let Ok((input, _)) = closed {
+        return Ok((input, vec![]));
+    }
+    let (input, first) = expect(ARGUMENT, parse_argument).parse(input)?;
+    let (input, rest) = many0(preceded(
+        (multispace0, tag(",")),
+        expect(ARGUMENT, parse_argument),
+    ))
+    .parse(input)?;
+    let (input, _) = expect("expected `,` or `)`", tag(")")).parse(input)?;
+    Ok((input, [vec![first], rest].concat()))
 }
 
-fn parse_body_literal(input: Span) -> IResult<Span, BodyLiteral> {
-    alt((
-        map(
-            (opt(preceded(multispace0, tag("not "))), parse_term),
-            |(not, term)| BodyLiteral::Literal {
-                literal: term,
-                negated: not.is_some(),
-            },
-        ),
-        parse_comparison,
-    ))
-    .parse(input)
+fn parse_term(input: Span) -> PResult<Literal> {
+    let (input, predicate) = preceded(multispace0, parse_constant).parse(input)?;
+    let (input, args) = opt(parse_arguments).parse(input)?;
+    Ok((input, Literal::new(predicate, args.unwrap_or_default())))
 }
 
-//  :- p(X), not q(Y), X!=Y.
-fn parse_rule(input: Span) -> IResult<Span, ()> {
-    let (input, head) = opt(parse_term).parse(input)?;
-    let (input, imp) = preceded(multispace0, opt(tag(":-"))).parse(input)?;
-
-    if imp.is_none() {
-        // check fact
-        let head = head.ok_or_else(||nom::Err::Error(nom::error::Error::new(input.clone(), nom::error::ErrorKind::Verify)))?;
-        
-        let (input, _) = preceded(multispace0, tag(".")).parse(input)?;
-        
-        let task = input.extra.clone();
-        task.borrow_mut().push_background(Rule { head: Some(head), body: vec![] });
-        Ok((input, ()))
-    } else {
-        // parse body
-        let (input, body) = separated_list1(
-            (multispace0, tag(","), multispace0), 
-            parse_body_literal
-        ).parse(input)?;
-        
-        let (input, _) = preceded(multispace0, tag(".")).parse(input)?;
-        
-        let task = input.extra.clone();
-        task.borrow_mut().push_background(Rule { head, body });
-        Ok((input, ()))
-    }
-}
-
-fn parse_lit(input: Span) -> IResult<Span, Lit> {
+fn parse_lit(input: Span) -> PResult<Lit> {
     let (input, term) = parse_term(input)?;
-    // let task = input.extra.clone();
-    // let lit = task.borrow().create_literal(term);
     let task = input.extra.clone();
     let task = task.borrow_mut();
     let lit = task.create_literal(term);
     Ok((input, lit))
 }
 
+/// an answer set `{a b}` or a partial interpretation `{a b | c}`
+enum Output {
+    AnswerSet(LitSet),
+    Partial(PartialOutput),
+}
 
-fn parse_answer_set(input: Span) -> IResult<Span, LitSet> {
-    let (input, terms) = delimited(
-        (multispace0, tag("{"), multispace0),
-        separated_list0((multispace0,), parse_lit),
-        (multispace0, tag("}")),
+/// an answer set, or a partial interpretation if the outputs are `partial`
+fn parse_answer_set(input: Span, partial: bool) -> PResult<Output> {
+    let (input, _) = (multispace0, tag("{")).parse(input)?;
+    let (input, included) = separated_list0(multispace0, parse_lit).parse(input)?;
+    let (input, bar) = opt(preceded(multispace0, tag("|"))).parse(input)?;
+    let Some(bar) = bar else {
+        let message = if partial {
+            "expected an atom, `|` or `}`"
+        } else {
+            "expected an atom or `}`"
+        };
+        let (input, _) = expect(message, tag("}")).parse(input)?;
+        return Ok((input, Output::AnswerSet(LitSet::new(included))));
+    };
+    if !partial {
+        return Err(Expected::failure(
+            bar,
+            "partial interpretations `{... | ...}` are only allowed after `O?:`",
+        ));
+    }
+    let (input, excluded) = delimited(
+        multispace0,
+        separated_list0(multispace0, parse_lit),
+        expect("expected an atom or `}`", tag("}")),
     )
     .parse(input)?;
-    Ok((input, LitSet::new(terms)))
+    let output = PartialOutput {
+        included: LitSet::new(included),
+        excluded: LitSet::new(excluded),
+    };
+    Ok((input, Output::Partial(output)))
+}
+
+/// Byte length of a rule up to and including its period.
+///
+/// Fails with the end of the rule if it is not terminated before the next `O:`, `O?:` or `I:`.
+fn rule_length(rule: &str) -> Result<usize, usize> {
+    for (i, c) in rule.char_indices() {
+        let (before, after) = (&rule[..i], &rule[i + c.len_utf8()..]);
+        match c {
+            // a period not being part of an interval `1..n`
+            '.' if !before.ends_with('.') && !after.starts_with('.') => return Ok(i + 1),
+            _ if is_marker(rule, i) => return Err(before.trim_end().len()),
+            _ => {}
+        }
+    }
+    Err(rule.trim_end().len())
+}
+
+/// whether an `O:`, `O?:` or `I:` after whitespace starts at byte `i` of `text`
+fn is_marker(text: &str, i: usize) -> bool {
+    let (before, after) = text.split_at(i);
+    ["O:", "O?:", "I:"].iter().any(|marker| after.starts_with(marker))
+        && before.ends_with(char::is_whitespace)
+}
+
+/// byte offset of a 1-based line and column in characters
+fn offset(text: &str, line: u32, column: usize) -> usize {
+    let start: usize = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1) as usize)
+        .map(str::len)
+        .sum();
+    let column: usize = text[start..]
+        .chars()
+        .take(column.saturating_sub(1))
+        .map(char::len_utf8)
+        .sum();
+    (start + column).min(text.len())
+}
+
+/// the head `p` or `p(...)` of a rule, the arguments may use any syntax of clingo, e.g., `p(1..3)`
+fn parse_rule_head(input: Span) -> PResult<()> {
+    let (input, _) = parse_constant(input)?;
+    if !input.fragment().starts_with('(') {
+        return Ok((input, ()));
+    }
+    let mut depth = 0;
+    for (i, c) in input.fragment().char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Ok((input.take_from(i + 1), ())),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    Err(nom::Err::Error(Expected::from_error_kind(input, ErrorKind::Char)))
+}
+
+/// a rule `h :- b1, ..., bn.` or a constraint `:- b1, ..., bn.` of an input, parsed by clingo
+fn parse_context_rule(input: Span) -> PResult<Vec<Statement>> {
+    let (input, _) = multispace0(input)?;
+    // commit once the neck of the rule is found
+    (opt(parse_rule_head), multispace0, tag(":-")).parse(input.clone())?;
+    let length = rule_length(input.fragment())
+        .map_err(|end| Expected::failure(input.take_from(end), "missing `.` after rule"))?;
+    let (rest, rule) = input.take_split(length);
+    match background::parse_background(rule.fragment()) {
+        Ok(statements) => {
+            let task = input.extra.borrow();
+            for statement in &statements {
+                if let Statement::Rule(Rule { head: Some(head), .. }) = statement {
+                    undeclared_input(&task, &rule, head)?;
+                }
+            }
+            Ok((rest, statements))
+        }
+        Err(IlnlpError::Parse(diagnostics)) => {
+            let diagnostic = &diagnostics[0];
+            let at = offset(rule.fragment(), diagnostic.line, diagnostic.column);
+            Err(Expected::failure(rule.take_from(at), diagnostic.message.clone()))
+        }
+        Err(e) => Err(Expected::failure(rule, e.to_string())),
+    }
 }
 
+/// fail at `at` if `#input` declarations exist and the predicate of `literal` is not declared
+fn undeclared_input<'a>(
+    task: &Task,
+    at: &Span<'a>,
+    literal: &Literal,
+) -> Result<(), nom::Err<Expected<'a>>> {
+    if task.inputs().is_empty() || task.inputs().iter().any(|s| s.matches(literal)) {
+        return Ok(());
+    }
+    Err(Expected::failure(
+        at.clone(),
+        format!(
+            "`{}/{}` is not declared as input predicate by `#input`",
+            literal.predicate,
+            literal.args.len()
+        ),
+    ))
+}
 
-fn parse_example(input: Span) -> IResult<Span, ()> {
-    let (input, facts) = preceded(
-        (multispace0, tag("I:"), multispace0),
-        separated_list0(multispace1, parse_lit),
+/// a fact of an input, optionally terminated by a period
+///
+fn parse_input_fact(input: Span) -> PResult<Lit> {
+    let (input, _) = multispace0(input)?;
+    let (rest, term) = parse_term(input.clone())?;
+    let task = input.extra.clone();
+    let task = task.borrow();
+    undeclared_input(&task, &input, &term)?;
+    let lit = task.create_literal(term);
+    let (rest, _) = opt(tag(".")).parse(rest)?;
+    Ok((rest, lit))
+}
+
+enum InputElement {
+    Fact(Lit),
+    Rules(Vec<Statement>),
+}
+
+/// `I:` followed by the facts and rules of an input
+fn parse_input(input: Span) -> PResult<(LitSet, Vec<Statement>)> {
+    preceded((tag("I:"), multispace0), parse_input_elements).parse(input)
+}
+
+/// the facts and rules of an input
+fn parse_input_elements(input: Span) -> PResult<(LitSet, Vec<Statement>)> {
+    let (input, elements) = separated_list0(
+        multispace1,
+        alt((
+            map(parse_context_rule, InputElement::Rules),
+            map(parse_input_fact, InputElement::Fact),
+        )),
     )
     .parse(input)?;
-    let (input, outputs) = preceded(
-        (multispace0, tag("O:"), multispace0),
-        separated_list0(multispace1, parse_answer_set),
+    let mut facts = vec![];
+    let mut context = vec![];
+    for element in elements {
+        match element {
+            InputElement::Fact(lit) => facts.push(lit),
+            InputElement::Rules(rules) => context.extend(rules),
+        }
+    }
+    Ok((input, (LitSet::new(facts), context)))
+}
+
+/// `O:` or `O?:` followed by the answer sets, and the partial interpretations of `O?:`
+///
+/// Returns whether the outputs are partial, the answer sets and the partial interpretations.
+fn parse_outputs(input: Span) -> PResult<(bool, Vec<LitSet>, Vec<PartialOutput>)> {
+    let (input, partial) = expect(
+        "expected `O:` or `O?:` after the input",
+        alt((value(false, tag("O:")), value(true, tag("O?:")))),
     )
     .parse(input)?;
+    let (input, sets) =
+        separated_list0(multispace0, |input| parse_answer_set(input, partial)).parse(input)?;
+    let mut outputs = vec![];
+    let mut partial_outputs = vec![];
+    for set in sets {
+        match set {
+            Output::AnswerSet(set) => outputs.push(set),
+            Output::Partial(set) => partial_outputs.push(set),
+        }
+    }
+    Ok((input, (partial, outputs, partial_outputs)))
+}
+
+fn parse_example(input: Span) -> PResult<()> {
+    let (input, (facts, context)) = parse_input(input)?;
+    let (input, (partial, outputs, partial_outputs)) = parse_outputs(input)?;
     let task = input.extra.clone();
     let mut task = task.borrow_mut();
     task.push_example(Example {
-        input: crate::LitSet::new(facts),
+        input: facts,
+        context,
         output: outputs.into_iter().collect(),
+        partial_outputs,
+        partial,
     });
 
     Ok((input, ()))
 }
 
-fn parse_examples(input: Span) -> IResult<Span, ()> {
-    let (input, _) = many0(preceded(parse_ignore, parse_example)).parse(input)?;
-    let (input, _) = parse_ignore(input)?;
+/// an input without outputs, which are computed by [`crate::generate`]
+fn parse_instance(input: Span) -> PResult<()> {
+    let (input, (facts, context)) = parse_input(input)?;
+    let task = input.extra.clone();
+    task.borrow_mut().push_example(Example {
+        input: facts,
+        context,
+        output: vec![],
+        partial_outputs: vec![],
+        partial: false,
+    });
     Ok((input, ()))
 }
 
-fn parse_background(input: Span) -> IResult<Span, ()> {
-    let (input, _) = many0(preceded(parse_ignore, parse_rule)).parse(input)?;
-    let (input, _) = parse_ignore(input)?;
+/// the rest of an example after an erroneous element of its input, checked but not added to the task
+fn parse_remainder(input: Span, instances: bool) -> PResult<()> {
+    let (input, _) = preceded(multispace0, parse_input_elements).parse(input)?;
+    if instances {
+        return Ok((input, ()));
+    }
+    let (input, _) = parse_outputs(input)?;
     Ok((input, ()))
 }
 
-pub fn parse_input(input: Span) -> IResult<Span, ()> {
-    let (input, _) = parse_background(input)?;
-    let (input, _) = parse_examples(input)?;
-    Ok((input, ()))
+/// Parse the examples, reporting an error for each example that does not parse
+///
+/// After an error in the facts and rules of an input, parsing continues after the next `.`
+/// of the input, so that the errors of the other elements are reported as well.
+/// Otherwise, parsing continues with the next line starting with `I:`.
+/// If `instances` is set, the examples are inputs without outputs.
+fn parse_examples(
+    mut input: Span,
+    source: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+    instances: bool,
+) {
+    // whether parsing recovered within an input
+    let mut recovered = false;
+    loop {
+        if let Ok((rest, _)) = parse_ignore(input.clone()) {
+            input = rest;
+        }
+        if input.is_empty() {
+            return;
+        }
+        let example = if recovered {
+            parse_remainder(input.clone(), instances)
+        } else if instances {
+            expect("expected `I:`", parse_instance).parse(input.clone())
+        } else {
+            expect("expected `{` or `I:`", parse_example).parse(input.clone())
+        };
+        let at = match example {
+            Ok((rest, _)) => {
+                input = rest;
+                recovered = false;
+                continue;
+            }
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e,
+            Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers"),
+        };
+        diagnostics.push(at.diagnostic(source));
+        // errors of context rules point into the rule, continue on the whole input
+        let (rest, parsed) = input.take_split(at.at.location_offset() - input.location_offset());
+        // the error is in an input if no marker precedes it, besides the `I:` of the example
+        recovered = (recovered || parsed.starts_with("I:"))
+            && !parsed.char_indices().any(|(i, _)| is_marker(&parsed, i));
+        input = match rule_length(rest.fragment()) {
+            Ok(length) if recovered => rest.take_from(length),
+            _ => {
+                recovered = false;
+                next_example(rest)
+            }
+        };
+    }
 }
-pub fn parse_ignore(input: Span) -> IResult<Span, ()> {
-    let (input, _) = multispace0(input)?;
-    let (i, is_comment) = opt(tag("%")).parse(input.clone())?;
-    if is_comment.is_some() {
-        let (input, _) = take_while(|c: char| c != '\n' && c != '\r')(i)?;
-        let (input, _) = multispace0(input)?;
-        return Ok((input, ()));
+
+/// skip to the next line starting with `I:` after the line of `input`
+fn next_example(input: Span) -> Span {
+    let fragment = *input.fragment();
+    let mut offset = fragment.find('\n').map_or(fragment.len(), |i| i + 1);
+    for line in fragment[offset..].split_inclusive('\n') {
+        if line.trim_start().starts_with("I:") {
+            break;
+        }
+        offset += line.len();
     }
+    input.take_from(offset)
+}
+
+/// skip whitespace and `%` comments
+pub fn parse_ignore(input: Span) -> PResult<()> {
+    let comment = (tag("%"), take_while(|c: char| c != '\n' && c != '\r'));
+    let (input, _) = many0(alt((multispace1, recognize(comment)))).parse(input)?;
     Ok((input, ()))
 }
+
+/// Offset and line of the first example, i.e., the first line starting with `I:`
+/// outside of block comments.
+fn examples_start(input: &str) -> (usize, u32) {
+    let mut offset = 0;
+    let mut in_comment = false;
+    for (line_number, line) in input.split_inclusive('\n').enumerate() {
+        let mut rest = line;
+        if !in_comment && line.trim_start().starts_with("I:") {
+            return (offset, line_number as u32 + 1);
+        }
+        // track block comments `%* ... *%` in the background
+        loop {
+            let marker = if in_comment { "*%" } else { "%*" };
+            match rest.find(marker) {
+                Some(i) => {
+                    in_comment = !in_comment;
+                    rest = &rest[i + 2..];
+                }
+                None => break,
+            }
+        }
+        offset += line.len();
+    }
+    (input.len(), input.lines().count() as u32 + 1)
+}
+
 /// Parse a task
+///
+/// The background knowledge is parsed by clingo, the examples by the grammar of this module.
+/// All syntax errors are collected into a single [`IlnlpError::Parse`].
 pub fn parse_task(input: &str) -> Result<Task, IlnlpError> {
-    let task = Rc::new(RefCell::new(Task::default()));
-    let input = Span::new_extra(input, task.clone());
-    let (input, _) = parse_input(input)?;
-    let (input, _) = parse_ignore(input)?;
-    if !input.is_empty() {
-        // Use Span's location information to generate detailed error message
-        let line = input.location_line();
-        let column = input.get_utf8_column();
-        let fragment = input.fragment();
-
-        // Truncate the unparsed content for display
-        let error_content = if fragment.len() > 20 {
-            format!("{}...", &fragment[..20])
-        } else {
-            fragment.to_string()
-        };
+    parse(input, false)
+}
 
-        return Err(IlnlpError::ParserError(format!(
-            "Unparsable content at line {}, column {}: '{}'",
-            line, column, error_content
-        )));
+/// Parse a file of instances, a task whose examples are inputs `I: ...` without outputs
+pub fn parse_instances(input: &str) -> Result<Task, IlnlpError> {
+    parse(input, true)
+}
+
+/// whether `input` has examples, i.e., a line starting with `I:`
+pub fn has_examples(input: &str) -> bool {
+    examples_start(input).0 < input.len()
+}
+
+/// Parse a program without examples, keeping `#show p/n.` as statement
+pub fn parse_program(input: &str) -> Result<Vec<Statement>, IlnlpError> {
+    background::parse_background(input)
+}
+
+fn parse(input: &str, instances: bool) -> Result<Task, IlnlpError> {
+    let task = Rc::new(RefCell::new(Task::default()));
+    let (offset, line) = examples_start(input);
+    let mut diagnostics = vec![];
+    let background = directive::extract_directives(&input[..offset], &task, &mut diagnostics);
+    match background::parse_background(&background) {
+        Ok(statements) => {
+            for statement in statements {
+                task.borrow_mut().push_background(statement);
+            }
+        }
+        Err(IlnlpError::Parse(errors)) => {
+            // show the source lines with the directives
+            diagnostics.extend(errors.into_iter().map(|diagnostic| {
+                Diagnostic::new(
+                    input,
+                    diagnostic.line,
+                    diagnostic.column,
+                    diagnostic.length,
+                    diagnostic.message,
+                )
+            }))
+        }
+        Err(e) => return Err(e),
+    }
+    // SAFETY: the fragment is the suffix of `input` starting at `offset` on line `line`
+    let examples =
+        unsafe { Span::new_from_raw_offset(offset, line, &input[offset..], task.clone()) };
+    parse_examples(examples, input, &mut diagnostics, instances);
+    if !diagnostics.is_empty() {
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+        return Err(IlnlpError::Parse(diagnostics));
     }
     let c = task.take();
     Ok(c)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Signature;
+
+    /// line, column and message of the errors of `input`
+    fn errors(input: &str) -> Vec<(u32, usize, String)> {
+        match parse_task(input) {
+            Err(IlnlpError::Parse(diagnostics)) => diagnostics
+                .into_iter()
+                .map(|d| (d.line, d.column, d.message))
+                .collect(),
+            result => panic!("expected syntax errors, got {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn reports_an_error_per_example() {
+        let errors = errors("p :- q.\nI: q O {p}\nI: q O: {p\nI: q O: {p}\n");
+        assert_eq!(
+            errors,
+            vec![
+                (2, 6, "expected `O:` or `O?:` after the input".to_string()),
+                (3, 11, "expected an atom or `}`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn recovers_at_periods_of_inputs() {
+        let errors = errors("I: q(1 . r :- ). s(2). 1x. O: {p(1)}\nI: q(1 O: {p(1)}\n");
+        let positions: Vec<_> = errors.iter().map(|(l, c, _)| (*l, *c)).collect();
+        assert_eq!(positions, vec![(1, 8), (1, 15), (1, 24), (2, 8)]);
+        assert_eq!(errors[0].2, "expected `,` or `)`");
+        assert_eq!(errors[1].2, "unexpected `)`");
+    }
+
+    #[test]
+    fn does_not_recover_at_periods_after_outputs() {
+        let errors = errors("I: q O: {p} r. s.\nI: q O: {p}\n");
+        assert_eq!(errors, vec![(1, 13, "expected `{` or `I:`".to_string())]);
+    }
+
+    #[test]
+    fn sorts_errors_of_background_directives_and_examples() {
+        let errors = errors("p :- q\n#maxv(x).\nI: q O {p}\n");
+        let positions: Vec<_> = errors.iter().map(|(l, c, _)| (*l, *c)).collect();
+        assert_eq!(positions, vec![(1, 7), (2, 7), (3, 6)]);
+    }
+
+    #[test]
+    fn parses_context_rules() {
+        let task = parse_task(
+            "I: e(2,1). e(X,Y) :- e(Y,X). p(1..2) :- e(1,2).\n:- q. O: {e(2,1) e(1,2) p(1) p(2)}",
+        )
+        .unwrap();
+        let example = &task.examples[0];
+        assert_eq!(example.input.len(), 1);
+        let context: Vec<_> = example.context.iter().map(|r| r.to_string()).collect();
+        assert_eq!(
+            context,
+            vec!["e(X, Y) :- e(Y, X).", "p(1..2) :- e(1,2).", " :- q."]
+        );
+        assert!(matches!(example.context[1], Statement::Verbatim(_)));
+    }
+
+    #[test]
+    fn reports_errors_of_context_rules() {
+        assert_eq!(
+            errors("I: a :- b O: {a}\nI: a :- b, . O: {a}\n"),
+            vec![
+                (1, 10, "missing `.` after rule".to_string()),
+                (2, 12, "unexpected `.`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_declarations() {
+        let task =
+            parse_task("#input q/1, r/0.\n#output p/1.\n#input q/1.\nI: q(1) r O: {p(1)}").unwrap();
+        let signatures = |s: &[Signature]| s.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(signatures(task.inputs()), vec!["q/1", "r/0"]);
+        assert_eq!(signatures(task.outputs()), vec!["p/1"]);
+        // the directives are no statements of the background
+        assert!(task.background.is_empty());
+    }
+
+    #[test]
+    fn rejects_undeclared_inputs() {
+        assert_eq!(
+            errors("#input q/1.\nI: q(1) s(1) O: {p(1)}\nI: q(1). s(X) :- q(X). O: {p(1)}\n"),
+            vec![
+                (
+                    2,
+                    9,
+                    "`s/1` is not declared as input predicate by `#input`".to_string()
+                ),
+                (
+                    3,
+                    10,
+                    "`s/1` is not declared as input predicate by `#input`".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_errors_of_declarations() {
+        assert_eq!(
+            errors("#input q.\n#output p/1 r/1.\nI: q O: {p}\n"),
+            vec![
+                (1, 9, "expected `/` followed by the arity".to_string()),
+                (2, 13, "expected `,` or `.`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_partial_outputs() {
+        let task = parse_task("I: a O?: {b} {c}\nI: a O?:\nI: a O: {b}").unwrap();
+        let partial: Vec<_> = task
+            .examples
+            .iter()
+            .map(|e| (e.partial, e.output.len()))
+            .collect();
+        assert_eq!(partial, vec![(true, 2), (true, 0), (false, 1)]);
+    }
+
+    #[test]
+    fn parses_partial_interpretations() {
+        let task = parse_task("I: a O?: {b | c d} {e |} {f}").unwrap();
+        let example = &task.examples[0];
+        assert_eq!(example.output.len(), 1);
+        let sets: Vec<_> = example
+            .partial_outputs
+            .iter()
+            .map(|o| (o.included.len(), o.excluded.len()))
+            .collect();
+        assert_eq!(sets, vec![(1, 2), (1, 0)]);
+        assert_eq!(
+            errors("I: a O: {b | c}\nI: a O?: {b |"),
+            vec![
+                (
+                    1,
+                    12,
+                    "partial interpretations `{... | ...}` are only allowed after `O?:`"
+                        .to_string()
+                ),
+                (2, 14, "expected an atom or `}`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_limits() {
+        let task = parse_task("#maxv(3).\n#max_body(2).\n#max_penalty(8).\n#max_rules(0).\n#no_recursion.\nI: a O: {a}").unwrap();
+        assert_eq!(
+            task.bias(),
+            &crate::bias::BiasConfig {
+                max_vars: Some(3),
+                max_body: Some(2),
+                max_penalty: Some(8),
+                max_rules: Some(0),
+                recursion: Some(false),
+            }
+        );
+        assert_eq!(
+            errors("#maxv(3)\n#max_body 2.\nI: a O: {a}"),
+            vec![
+                (1, 9, "missing `.`".to_string()),
+                (2, 11, "expected `(`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_search_space() {
+        let task = parse_task(
+            "#modeh(p(var(t))).\n#modeb(2, q(var(t), const(c)), (positive)).\n#constant(c, \"a, b\").\n#bias(\":- in_head(p(_)).\").\nI: q(1,a) O: {p(1)}",
+        )
+        .unwrap();
+        let modes: Vec<_> = task
+            .search_space()
+            .modes()
+            .iter()
+            .map(|m| m.to_string())
+            .collect();
+        assert_eq!(
+            modes,
+            vec![
+                "#modeh(p(var(t))).",
+                "#modeb(2, q(var(t), const(c)), (positive))."
+            ]
+        );
+        assert_eq!(
+            task.search_space().constants(),
+            &[("c".to_string(), "\"a, b\"".to_string())]
+        );
+        assert_eq!(
+            task.search_space().biases(),
+            &["\":- in_head(p(_)).\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn reports_errors_of_search_space() {
+        assert_eq!(
+            errors("#modeh(1, p, x, y).\n#constant(c).\n#bias(x).\n#modeb(p(var(t))\nI: a O: {a}"),
+            vec![
+                (
+                    1,
+                    1,
+                    "expected `(recall, atom, options)` with optional recall and options"
+                        .to_string()
+                ),
+                (2, 1, "expected `(type, value)`".to_string()),
+                (3, 1, "expected a string `(\"...\")`".to_string()),
+                (4, 8, "expected `)`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_comments() {
+        let task = parse_task("%* I: a O: {b}\n*%\n% I: x\nI: a O: {b}\n% I: y\n").unwrap();
+        assert_eq!(task.examples.len(), 1);
+    }
+}
@@ -5,6 +5,7 @@ use std::{
     fmt::Display,
     time::{Duration, Instant},
 };
+use clingo::StatsValue;
 use cpu_time::ProcessTime;
 use sysinfo::{Pid, ProcessesToUpdate, System};
 
@@ -47,6 +48,8 @@ pub struct Stat {
     ilasp_memory: Option<u64>,
     universe_size: Option<usize>,
     unique_predicates: Option<usize>,
+    clingo_calls: usize,
+    clingo_stats: Option<StatsValue>,
 }
 
 unsafe impl Send for Stat {}
@@ -78,6 +81,20 @@ impl Display for Stat {
         } else {
             writeln!(f, "Unique Predicates: Not available")?;
         }
+        if let Some(stats) = &self.clingo_stats {
+            writeln!(f, "Clingo calls: {}", self.clingo_calls)?;
+            // values accumulated over all solve calls of a control are only available with --stats
+            let value = |accu: &str, path: &str| stats.value(accu).or_else(|| stats.value(path));
+            if let Some(time) = value("accu.times.total", "summary.times.total") {
+                writeln!(f, "Clingo time: {:?}", Duration::from_secs_f64(time))?;
+            }
+            if let Some(choices) = value("accu.solving.solvers.choices", "solving.solvers.choices") {
+                writeln!(f, "Clingo choices: {}", choices)?;
+            }
+            if let Some(conflicts) = value("accu.solving.solvers.conflicts", "solving.solvers.conflicts") {
+                writeln!(f, "Clingo conflicts: {}", conflicts)?;
+            }
+        }
         Ok(())
     }
 }
@@ -128,6 +145,46 @@ impl Stat {
         self.universe_size = Some(size);
         self.unique_predicates = Some(unique_predicates);
     }
+
+    /// add the statistics of a clingo control object, values are summed up over all calls
+    pub fn record_clingo_stats(&mut self, stats: StatsValue) {
+        self.clingo_calls += 1;
+        match &mut self.clingo_stats {
+            Some(total) => accumulate(total, stats),
+            None => self.clingo_stats = Some(stats),
+        }
+    }
+
+    /// the statistics of all clingo calls, e.g., `clingo_stats()?.value("summary.times.total")`
+    pub fn clingo_stats(&self) -> Option<&StatsValue> {
+        self.clingo_stats.as_ref()
+    }
+}
+
+fn accumulate(total: &mut StatsValue, stats: StatsValue) {
+    match (total, stats) {
+        (StatsValue::Value(total), StatsValue::Value(value)) => *total += value,
+        (StatsValue::Array(total), StatsValue::Array(array)) => {
+            for (i, value) in array.into_iter().enumerate() {
+                match total.get_mut(i) {
+                    Some(t) => accumulate(t, value),
+                    None => total.push(value),
+                }
+            }
+        }
+        (StatsValue::Map(total), StatsValue::Map(map)) => {
+            for (key, value) in map {
+                match total.get_mut(&key) {
+                    Some(t) => accumulate(t, value),
+                    None => {
+                        total.insert(key, value);
+                    }
+                }
+            }
+        }
+        // the structure differs, keep the first one
+        _ => {}
+    }
 }
 
 pub fn get_memory() -> Option<u64> {
@@ -5,12 +5,12 @@
 {%- endfor -%}
 
 {% for e in pos_examples %}
-#pos{%- raw -%}({ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | concat(with='') | join(sep=". ")  }} {% raw %}}{% endraw %}).
+#pos({% if e.id %}{{ e.id }},{% endif %}{%- raw -%}{ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | join(sep=" ")  }} {% raw %}}{% endraw %}).
 
 {%- endfor -%}
 
 {% for e in neg_examples %}
-#neg{%- raw -%}({ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | concat(with='') | join(sep=". ")  }} {% raw %}}{% endraw %}).
+#neg({% if e.id %}{{ e.id }},{% endif %}{%- raw -%}{ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | join(sep=" ")  }} {% raw %}}{% endraw %}).
 
 {%- endfor -%}
 
@@ -23,3 +23,7 @@
 #modeb(v(var(node)), (positive)).
 #modeb(c(var(node),var(color))).
 #modeb(var(color) != var(color), (positive)).
+
+{% for d in bias_directives %}
+{{d}}
+{%- endfor -%}
@@ -5,12 +5,12 @@
 {%- endfor -%}
 
 {% for e in pos_examples %}
-#pos{%- raw -%}({ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | concat(with='') | join(sep=". ")  }} {% raw %}}{% endraw %}).
+#pos({% if e.id %}{{ e.id }},{% endif %}{%- raw -%}{ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | join(sep=" ")  }} {% raw %}}{% endraw %}).
 
 {%- endfor -%}
 
 {% for e in neg_examples %}
-#neg{%- raw -%}({ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | concat(with='') | join(sep=". ")  }} {% raw %}}{% endraw %}).
+#neg({% if e.id %}{{ e.id }},{% endif %}{%- raw -%}{ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | join(sep=" ")  }} {% raw %}}{% endraw %}).
 
 {%- endfor -%}
 
@@ -24,3 +24,7 @@
 #modeb( node(var(node)), (positive)).
 #modeb( in(var(node),var(node))).
 #modeb( initial(var(node)), (positive)).
+
+{% for d in bias_directives %}
+{{d}}
+{%- endfor -%}
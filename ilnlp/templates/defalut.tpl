@@ -5,12 +5,12 @@
 {%- endfor -%}
 
 {% for e in pos_examples %}
-#pos{%- raw -%}({ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | concat(with='') | join(sep=". ")  }} {% raw %}}{% endraw %}).
+#pos({% if e.id %}{{ e.id }},{% endif %}{%- raw -%}{ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | join(sep=" ")  }} {% raw %}}{% endraw %}).
 
 {%- endfor -%}
 
 {% for e in neg_examples %}
-#neg{%- raw -%}({ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | concat(with='') | join(sep=". ")  }} {% raw %}}{% endraw %}).
+#neg({% if e.id %}{{ e.id }},{% endif %}{%- raw -%}{ {%- endraw -%} {{ e.incl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.excl | join(sep=",")  }} {% raw %} },{{% endraw %} {{ e.ctx | join(sep=" ")  }} {% raw %}}{% endraw %}).
 
 {%- endfor -%}
 
@@ -25,3 +25,19 @@
 {% for a in search_space.head %}
 #modeh({{a}}).
 {%- endfor -%}
+
+{% for c in search_space.constants %}
+{{c}}
+{%- endfor -%}
+
+{% for m in search_space.modes %}
+{{m}}
+{%- endfor -%}
+
+{% for b in search_space.biases %}
+{{b}}
+{%- endfor -%}
+
+{% for d in bias_directives %}
+{{d}}
+{%- endfor -%}